@@ -0,0 +1,41 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use polars::prelude::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::io::Cursor;
+
+fn create_boolean_file(size: usize, null_percentage: f32) -> Vec<u8> {
+    let mut rng = StdRng::seed_from_u64(0);
+    let ca: BooleanChunked = (0..size)
+        .map(|_| {
+            if rng.gen::<f32>() < null_percentage {
+                None
+            } else {
+                Some(rng.gen::<bool>())
+            }
+        })
+        .collect();
+    let mut s = ca.into_series();
+    s.rename("values");
+    let mut df = DataFrame::new(vec![s]).unwrap();
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df).unwrap();
+    buf.into_inner()
+}
+
+fn read_boolean_benchmark(c: &mut Criterion) {
+    for null_percentage in [0.0, 0.1] {
+        let bytes = create_boolean_file(10_000_000, null_percentage);
+        c.bench_function(
+            &format!("read boolean column with {} nulls", null_percentage),
+            |b| {
+                b.iter(|| {
+                    ParquetReader::new(Cursor::new(&bytes)).finish().unwrap();
+                })
+            },
+        );
+    }
+}
+
+criterion_group!(benches, read_boolean_benchmark);
+criterion_main!(benches);
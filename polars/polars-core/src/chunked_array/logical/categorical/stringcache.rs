@@ -38,6 +38,55 @@ pub(crate) fn use_string_cache() -> bool {
     USE_STRING_CACHE.load(Ordering::Acquire)
 }
 
+/// The number of [`StringCacheHolder`]s that are alive, and whether they enabled the
+/// string cache.
+static HOLDERS: Lazy<Mutex<(usize, bool)>> = Lazy::new(Default::default);
+
+/// Keeps the global string cache enabled for as long as it is alive, so that all
+/// categoricals created in that time share their categories and can be combined without
+/// remapping them.
+///
+/// Holders can be cloned and nested. When the last holder is dropped, the string cache
+/// is disabled again, unless it was already enabled when the first holder was created.
+pub struct StringCacheHolder {
+    _private: (),
+}
+
+impl StringCacheHolder {
+    pub fn new() -> Self {
+        let mut holders = HOLDERS.lock().unwrap();
+        if holders.0 == 0 {
+            holders.1 = !use_string_cache();
+            toggle_string_cache(true);
+        }
+        holders.0 += 1;
+        StringCacheHolder { _private: () }
+    }
+}
+
+impl Default for StringCacheHolder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for StringCacheHolder {
+    fn clone(&self) -> Self {
+        HOLDERS.lock().unwrap().0 += 1;
+        StringCacheHolder { _private: () }
+    }
+}
+
+impl Drop for StringCacheHolder {
+    fn drop(&mut self) {
+        let mut holders = HOLDERS.lock().unwrap();
+        holders.0 -= 1;
+        if holders.0 == 0 && holders.1 {
+            toggle_string_cache(false);
+        }
+    }
+}
+
 pub(crate) struct SCacheInner {
     pub(crate) map: PlHashMap<StrHashGlobal, u32>,
     pub(crate) uuid: u128,
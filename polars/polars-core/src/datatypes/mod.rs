@@ -314,6 +314,8 @@ impl<'a> AnyValue<'a> {
             Datetime(v, _, _) => NumCast::from(*v),
             #[cfg(feature = "dtype-duration")]
             Duration(v, _) => NumCast::from(*v),
+            #[cfg(feature = "dtype-time")]
+            Time(v) => NumCast::from(*v),
             _ => unimplemented!(),
         }
     }
@@ -333,8 +335,17 @@ impl<'a> Hash for AnyValue<'a> {
             UInt32(v) => state.write_u32(*v),
             UInt64(v) => state.write_u64(*v),
             Utf8(s) => state.write(s.as_bytes()),
+            Utf8Owned(s) => state.write(s.as_bytes()),
             Boolean(v) => state.write_u8(*v as u8),
             List(v) => Hash::hash(&Wrap(v.clone()), state),
+            #[cfg(feature = "dtype-date")]
+            Date(v) => state.write_i32(*v),
+            #[cfg(feature = "dtype-datetime")]
+            Datetime(v, _, _) => state.write_i64(*v),
+            #[cfg(feature = "dtype-duration")]
+            Duration(v, _) => state.write_i64(*v),
+            #[cfg(feature = "dtype-time")]
+            Time(v) => state.write_i64(*v),
             _ => unimplemented!(),
         }
     }
@@ -480,6 +491,13 @@ impl<'a> AnyValue<'a> {
             Date(v) => AnyValue::Date(v),
             #[cfg(feature = "dtype-time")]
             Time(v) => AnyValue::Time(v),
+            #[cfg(feature = "dtype-datetime")]
+            Datetime(v, tu, None) => {
+                static NO_TIME_ZONE: Option<TimeZone> = None;
+                AnyValue::Datetime(v, tu, &NO_TIME_ZONE)
+            }
+            #[cfg(feature = "dtype-duration")]
+            Duration(v, tu) => AnyValue::Duration(v, tu),
             List(v) => AnyValue::List(v),
             Utf8(s) => AnyValue::Utf8Owned(s.to_string()),
             dt => {
@@ -550,6 +568,8 @@ impl PartialEq for AnyValue<'_> {
         use AnyValue::*;
         match (self, other) {
             (Utf8(l), Utf8(r)) => l == r,
+            (Utf8Owned(l), Utf8Owned(r)) => l == r,
+            (Utf8(l), Utf8Owned(r)) | (Utf8Owned(r), Utf8(l)) => *l == r.as_str(),
             (UInt8(l), UInt8(r)) => l == r,
             (UInt16(l), UInt16(r)) => l == r,
             (UInt32(l), UInt32(r)) => l == r,
@@ -562,9 +582,9 @@ impl PartialEq for AnyValue<'_> {
             (Float64(l), Float64(r)) => l == r,
             #[cfg(feature = "dtype-time")]
             (Time(l), Time(r)) => l == r,
-            #[cfg(all(feature = "dtype-datetime", feature = "dtype-date"))]
+            #[cfg(feature = "dtype-date")]
             (Date(l), Date(r)) => l == r,
-            #[cfg(all(feature = "dtype-datetime", feature = "dtype-date"))]
+            #[cfg(feature = "dtype-datetime")]
             (Datetime(l, tul, tzl), Datetime(r, tur, tzr)) => l == r && tul == tur && tzl == tzr,
             (Boolean(l), Boolean(r)) => l == r,
             (List(_), List(_)) => panic!("eq between list series not supported"),
@@ -605,6 +625,14 @@ impl PartialOrd for AnyValue<'_> {
             (Float32(l), Float32(r)) => l.partial_cmp(r),
             (Float64(l), Float64(r)) => l.partial_cmp(r),
             (Utf8(l), Utf8(r)) => l.partial_cmp(r),
+            #[cfg(feature = "dtype-date")]
+            (Date(l), Date(r)) => l.partial_cmp(r),
+            #[cfg(feature = "dtype-datetime")]
+            (Datetime(l, tu_l, _), Datetime(r, tu_r, _)) if tu_l == tu_r => l.partial_cmp(r),
+            #[cfg(feature = "dtype-duration")]
+            (Duration(l, tu_l), Duration(r, tu_r)) if tu_l == tu_r => l.partial_cmp(r),
+            #[cfg(feature = "dtype-time")]
+            (Time(l), Time(r)) => l.partial_cmp(r),
             _ => None,
         }
     }
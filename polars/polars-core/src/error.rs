@@ -24,6 +24,8 @@ pub enum PolarsError {
     Io(#[from] std::io::Error),
     #[error("DuplicateError: {0}")]
     Duplicate(ErrString),
+    #[error("Timeout: {0}")]
+    Timeout(ErrString),
 }
 
 impl From<ArrowError> for PolarsError {
@@ -131,7 +131,6 @@ impl Series {
                 if tz.as_deref() == Some("") {
                     tz = None;
                 }
-                // we still drop timezone for now
                 let chunks = cast_chunks(&chunks, &DataType::Int64, false).unwrap();
                 let s = Int64Chunked::from_chunks(name, chunks)
                     .into_datetime(tu.into(), tz)
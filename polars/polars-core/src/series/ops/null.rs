@@ -35,7 +35,7 @@ impl Series {
                     .collect::<Vec<_>>();
                 StructChunked::new(name, &fields).unwrap().into_series()
             }
-            DataType::Null => ChunkedArray::new_null("", size).into_series(),
+            DataType::Null => ChunkedArray::new_null(name, size).into_series(),
             _ => {
                 macro_rules! primitive {
                     ($type:ty) => {{
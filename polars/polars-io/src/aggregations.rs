@@ -24,6 +24,18 @@ pub enum ScanAggregation {
 }
 
 impl ScanAggregation {
+    /// The column that is aggregated.
+    pub fn column(&self) -> &str {
+        use ScanAggregation::*;
+        match self {
+            Sum { column, .. }
+            | Min { column, .. }
+            | Max { column, .. }
+            | First { column, .. }
+            | Last { column, .. } => column,
+        }
+    }
+
     /// Evaluate the aggregations per batch.
     #[cfg(any(
         feature = "ipc",
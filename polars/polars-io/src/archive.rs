@@ -0,0 +1,388 @@
+//! Read a member of a zip or tar archive without extracting it to disk.
+//!
+//! The archive must be in memory, for instance as a `Vec<u8>` or a memory mapped file.
+//! An [`ArchiveMember`] is a seekable view of the bytes of a single member, which can be
+//! passed to any reader that takes a [`MmapBytesReader`], such as the `ParquetReader`.
+use crate::mmap::MmapBytesReader;
+use polars_core::prelude::*;
+use std::io::{Read, Seek, SeekFrom};
+
+const ZIP_END_OF_CENTRAL_DIRECTORY: u32 = 0x06054b50;
+const ZIP_CENTRAL_DIRECTORY_HEADER: u32 = 0x02014b50;
+const ZIP_LOCAL_FILE_HEADER: u32 = 0x04034b50;
+const ZIP_STORED: u16 = 0;
+const ZIP_DEFLATED: u16 = 8;
+const TAR_BLOCK: usize = 512;
+
+enum MemberBytes<T> {
+    /// A member that is stored as is, which is the common case for parquet files.
+    Range {
+        archive: T,
+        start: usize,
+        end: usize,
+    },
+    #[cfg_attr(
+        not(any(feature = "decompress", feature = "decompress-fast")),
+        allow(dead_code)
+    )]
+    Decompressed(Vec<u8>),
+}
+
+/// A seekable view of a member of a zip or tar archive.
+///
+/// Members that are stored without compression are not copied. Deflated zip
+/// members are decompressed into memory, which requires the `decompress` feature.
+pub struct ArchiveMember<T: AsRef<[u8]>> {
+    bytes: MemberBytes<T>,
+    position: u64,
+}
+
+fn malformed(kind: &str, msg: &str) -> PolarsError {
+    PolarsError::ComputeError(format!("invalid {} archive: {}", kind, msg).into())
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    bytes
+        .get(offset..offset + 2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    bytes
+        .get(offset..offset + 4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+/// The `(method, start, compressed length)` of the zip member `name`.
+fn find_zip_member(bytes: &[u8], name: &str) -> Result<(u16, usize, usize)> {
+    let err = |msg| malformed("zip", msg);
+    // the end of central directory record is at least 22 bytes and may be followed by
+    // a comment of at most `u16::MAX` bytes
+    let search_start = bytes.len().saturating_sub(22 + u16::MAX as usize);
+    let eocd = (search_start..bytes.len().saturating_sub(21))
+        .rev()
+        .find(|i| read_u32(bytes, *i) == Some(ZIP_END_OF_CENTRAL_DIRECTORY))
+        .ok_or_else(|| err("the end of central directory record is missing"))?;
+    let n_entries = read_u16(bytes, eocd + 10).unwrap();
+    let mut offset = read_u32(bytes, eocd + 16).unwrap() as usize;
+
+    for _ in 0..n_entries {
+        if read_u32(bytes, offset) != Some(ZIP_CENTRAL_DIRECTORY_HEADER) {
+            return Err(err("a central directory header is invalid"));
+        }
+        let field = |pos| read_u16(bytes, offset + pos).ok_or_else(|| err("truncated header"));
+        let method = field(10)?;
+        let name_len = field(28)? as usize;
+        let extra_len = field(30)? as usize;
+        let comment_len = field(32)? as usize;
+        let compressed_len = read_u32(bytes, offset + 20).ok_or_else(|| err("truncated header"))?;
+        let local_offset = read_u32(bytes, offset + 42).ok_or_else(|| err("truncated header"))?;
+        let member_name = bytes
+            .get(offset + 46..offset + 46 + name_len)
+            .ok_or_else(|| err("truncated header"))?;
+
+        if member_name == name.as_bytes() {
+            if compressed_len == u32::MAX || local_offset == u32::MAX {
+                return Err(PolarsError::ComputeError(
+                    "zip64 archives are not supported".into(),
+                ));
+            }
+            let local_offset = local_offset as usize;
+            if read_u32(bytes, local_offset) != Some(ZIP_LOCAL_FILE_HEADER) {
+                return Err(err("a local file header is invalid"));
+            }
+            // the name and extra field lengths of the local header may differ from
+            // those in the central directory
+            let local_name_len =
+                read_u16(bytes, local_offset + 26).ok_or_else(|| err("truncated header"))?;
+            let local_extra_len =
+                read_u16(bytes, local_offset + 28).ok_or_else(|| err("truncated header"))?;
+            let start = local_offset + 30 + local_name_len as usize + local_extra_len as usize;
+            return Ok((method, start, compressed_len as usize));
+        }
+        offset += 46 + name_len + extra_len + comment_len;
+    }
+    Err(PolarsError::NotFound(format!(
+        "member '{}' is not in the zip archive",
+        name
+    )))
+}
+
+/// Parse a tar size field, which is an octal number padded with NUL or spaces.
+fn parse_octal(field: &[u8]) -> Option<usize> {
+    let digits = std::str::from_utf8(field)
+        .ok()?
+        .trim_matches(|c: char| c == '\0' || c == ' ');
+    if digits.is_empty() {
+        Some(0)
+    } else {
+        usize::from_str_radix(digits, 8).ok()
+    }
+}
+
+fn nul_terminated(field: &[u8]) -> &[u8] {
+    let end = field.iter().position(|b| *b == 0).unwrap_or(field.len());
+    &field[..end]
+}
+
+/// The `(start, length)` of the tar member `name`.
+fn find_tar_member(bytes: &[u8], name: &str) -> Result<(usize, usize)> {
+    let err = |msg| malformed("tar", msg);
+    let mut offset = 0;
+    while let Some(header) = bytes.get(offset..offset + TAR_BLOCK) {
+        // the archive ends with blocks of zeros
+        if header.iter().all(|b| *b == 0) {
+            break;
+        }
+        let size = parse_octal(&header[124..136]).ok_or_else(|| err("a size is invalid"))?;
+        let member_name = nul_terminated(&header[..100]);
+        // ustar archives store long paths in a prefix
+        let prefix = if &header[257..262] == b"ustar" {
+            nul_terminated(&header[345..500])
+        } else {
+            &[][..]
+        };
+        let is_file = matches!(header[156], b'0' | 0);
+        let matches = if prefix.is_empty() {
+            member_name == name.as_bytes()
+        } else {
+            name.as_bytes()
+                .strip_prefix(prefix)
+                .and_then(|rest| rest.strip_prefix(b"/"))
+                == Some(member_name)
+        };
+
+        let start = offset + TAR_BLOCK;
+        if is_file && matches {
+            if start + size > bytes.len() {
+                return Err(err("a member exceeds the archive"));
+            }
+            return Ok((start, size));
+        }
+        offset = start + (size + TAR_BLOCK - 1) / TAR_BLOCK * TAR_BLOCK;
+    }
+    Err(PolarsError::NotFound(format!(
+        "member '{}' is not in the tar archive",
+        name
+    )))
+}
+
+impl<T: AsRef<[u8]>> ArchiveMember<T> {
+    /// Find the member `name` in a zip archive.
+    pub fn zip(archive: T, name: &str) -> Result<Self> {
+        let (method, start, len) = find_zip_member(archive.as_ref(), name)?;
+        let end = start
+            .checked_add(len)
+            .filter(|end| *end <= archive.as_ref().len())
+            .ok_or_else(|| malformed("zip", "a member exceeds the archive"))?;
+        let bytes = match method {
+            ZIP_STORED => MemberBytes::Range {
+                archive,
+                start,
+                end,
+            },
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            ZIP_DEFLATED => {
+                let mut out = vec![];
+                flate2::read::DeflateDecoder::new(&archive.as_ref()[start..end])
+                    .read_to_end(&mut out)?;
+                MemberBytes::Decompressed(out)
+            }
+            #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
+            ZIP_DEFLATED => {
+                return Err(PolarsError::ComputeError(
+                    "reading deflated zip members requires the 'decompress' feature".into(),
+                ))
+            }
+            method => {
+                return Err(PolarsError::ComputeError(
+                    format!("zip compression method {} is not supported", method).into(),
+                ))
+            }
+        };
+        Ok(ArchiveMember { bytes, position: 0 })
+    }
+
+    /// Find the member `name` in an uncompressed tar archive.
+    pub fn tar(archive: T, name: &str) -> Result<Self> {
+        let (start, len) = find_tar_member(archive.as_ref(), name)?;
+        Ok(ArchiveMember {
+            bytes: MemberBytes::Range {
+                archive,
+                start,
+                end: start + len,
+            },
+            position: 0,
+        })
+    }
+
+    /// The bytes of the member.
+    pub fn as_slice(&self) -> &[u8] {
+        match &self.bytes {
+            MemberBytes::Range {
+                archive,
+                start,
+                end,
+            } => &archive.as_ref()[*start..*end],
+            MemberBytes::Decompressed(bytes) => bytes,
+        }
+    }
+}
+
+impl<T: AsRef<[u8]>> Read for ArchiveMember<T> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let bytes = self.as_slice();
+        let start = std::cmp::min(self.position, bytes.len() as u64) as usize;
+        let n = std::cmp::min(buf.len(), bytes.len() - start);
+        buf[..n].copy_from_slice(&bytes[start..start + n]);
+        self.position += n as u64;
+        Ok(n)
+    }
+}
+
+impl<T: AsRef<[u8]>> Seek for ArchiveMember<T> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let (base, offset) = match pos {
+            SeekFrom::Start(offset) => {
+                self.position = offset;
+                return Ok(offset);
+            }
+            SeekFrom::End(offset) => (self.as_slice().len() as u64, offset),
+            SeekFrom::Current(offset) => (self.position, offset),
+        };
+        let position = if offset >= 0 {
+            base.checked_add(offset as u64)
+        } else {
+            base.checked_sub(offset.unsigned_abs())
+        };
+        match position {
+            Some(position) => {
+                self.position = position;
+                Ok(position)
+            }
+            None => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
+
+impl<T: AsRef<[u8]> + Send + Sync> MmapBytesReader for ArchiveMember<T> {
+    fn to_bytes(&self) -> Option<&[u8]> {
+        Some(self.as_slice())
+    }
+}
+
+#[cfg(all(test, feature = "parquet"))]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use polars_core::df;
+    use std::io::Cursor;
+
+    /// A zip archive that stores the members without compression.
+    fn stored_zip(members: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut out = vec![];
+        let mut central = vec![];
+        for (name, data) in members {
+            let local_offset = out.len() as u32;
+            let header = |sig: u32, out: &mut Vec<u8>, central: bool| {
+                out.extend_from_slice(&sig.to_le_bytes());
+                if central {
+                    // version made by
+                    out.extend_from_slice(&20u16.to_le_bytes());
+                }
+                // version needed, flags, method, time, date, crc
+                out.extend_from_slice(&[20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+                out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+                out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+                out.extend_from_slice(&(name.len() as u16).to_le_bytes());
+                // extra field length
+                out.extend_from_slice(&0u16.to_le_bytes());
+                if central {
+                    // comment length, disk, internal and external attributes
+                    out.extend_from_slice(&[0; 10]);
+                    out.extend_from_slice(&local_offset.to_le_bytes());
+                }
+                out.extend_from_slice(name.as_bytes());
+            };
+            header(ZIP_LOCAL_FILE_HEADER, &mut out, false);
+            out.extend_from_slice(data);
+            header(ZIP_CENTRAL_DIRECTORY_HEADER, &mut central, true);
+        }
+        let central_offset = out.len() as u32;
+        out.extend_from_slice(&central);
+        out.extend_from_slice(&ZIP_END_OF_CENTRAL_DIRECTORY.to_le_bytes());
+        out.extend_from_slice(&[0; 4]);
+        out.extend_from_slice(&(members.len() as u16).to_le_bytes());
+        out.extend_from_slice(&(members.len() as u16).to_le_bytes());
+        out.extend_from_slice(&(central.len() as u32).to_le_bytes());
+        out.extend_from_slice(&central_offset.to_le_bytes());
+        out.extend_from_slice(&0u16.to_le_bytes());
+        out
+    }
+
+    fn tar(members: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut out = vec![];
+        for (name, data) in members {
+            let mut header = [0u8; TAR_BLOCK];
+            header[..name.len()].copy_from_slice(name.as_bytes());
+            let size = format!("{:011o}", data.len());
+            header[124..135].copy_from_slice(size.as_bytes());
+            header[156] = b'0';
+            header[257..263].copy_from_slice(b"ustar\0");
+            out.extend_from_slice(&header);
+            out.extend_from_slice(data);
+            out.resize((out.len() + TAR_BLOCK - 1) / TAR_BLOCK * TAR_BLOCK, 0);
+        }
+        out.resize(out.len() + 2 * TAR_BLOCK, 0);
+        out
+    }
+
+    #[test]
+    fn test_read_parquet_archive_member() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3], "b" => ["x", "y", "z"])?;
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+        let parquet = buf.into_inner();
+        let members: &[(&str, &[u8])] = &[
+            ("readme.txt", &b"not parquet"[..]),
+            ("data.parquet", parquet.as_slice()),
+        ];
+
+        let member = ArchiveMember::zip(stored_zip(members), "data.parquet")?;
+        assert_eq!(member.as_slice(), parquet.as_slice());
+        assert!(ParquetReader::new(member).finish()?.frame_equal(&df));
+
+        let member = ArchiveMember::tar(tar(members), "data.parquet")?;
+        assert_eq!(member.as_slice(), parquet.as_slice());
+        assert!(ParquetReader::new(member).finish()?.frame_equal(&df));
+
+        assert!(matches!(
+            ArchiveMember::zip(stored_zip(members), "missing.parquet"),
+            Err(PolarsError::NotFound(_))
+        ));
+        assert!(matches!(
+            ArchiveMember::tar(tar(members), "missing.parquet"),
+            Err(PolarsError::NotFound(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_archive_member_read_seek() -> std::io::Result<()> {
+        let archive = tar(&[("a", &b"0123456789"[..])]);
+        let mut member = ArchiveMember::tar(archive.as_slice(), "a").unwrap();
+        let mut buf = [0u8; 4];
+        member.seek(SeekFrom::End(-3))?;
+        assert_eq!(member.read(&mut buf)?, 3);
+        assert_eq!(&buf[..3], b"789");
+        member.seek(SeekFrom::Start(2))?;
+        member.seek(SeekFrom::Current(1))?;
+        member.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"3456");
+        assert!(member.seek(SeekFrom::Current(-10)).is_err());
+        Ok(())
+    }
+}
@@ -166,7 +166,7 @@ where
     }
 
     fn finish(self) -> Result<DataFrame> {
-        let mmap_read: ReaderBytes = (&self.reader).into();
+        let mmap_read = ReaderBytes::try_from(&self.reader)?;
         let bytes = mmap_read.deref();
 
         let out = match self.json_format {
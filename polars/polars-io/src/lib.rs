@@ -4,6 +4,8 @@
 pub mod aggregations;
 #[cfg(not(feature = "private"))]
 pub(crate) mod aggregations;
+#[cfg(any(feature = "csv-file", feature = "parquet"))]
+pub mod archive;
 
 #[cfg(feature = "avro")]
 #[cfg_attr(docsrs, doc(cfg(feature = "avro")))]
@@ -1,3 +1,6 @@
+#[cfg(feature = "parquet")]
+use crate::parquet::ParquetSource;
+use polars_core::error::{PolarsError, Result};
 use std::fs::File;
 use std::io::{BufReader, Cursor, Read, Seek};
 
@@ -11,6 +14,13 @@ pub trait MmapBytesReader: Read + Seek + Send + Sync {
     fn to_bytes(&self) -> Option<&[u8]> {
         None
     }
+
+    /// A source that the parquet reader reads ranges of bytes from, see
+    /// [`ParquetSource`].
+    #[cfg(feature = "parquet")]
+    fn to_source(&self) -> Option<&dyn ParquetSource> {
+        None
+    }
 }
 
 impl MmapBytesReader for File {
@@ -42,6 +52,11 @@ impl<T: MmapBytesReader + ?Sized> MmapBytesReader for Box<T> {
     fn to_bytes(&self) -> Option<&[u8]> {
         T::to_bytes(self)
     }
+
+    #[cfg(feature = "parquet")]
+    fn to_source(&self) -> Option<&dyn ParquetSource> {
+        T::to_source(self)
+    }
 }
 
 // Handle various forms of input bytes
@@ -62,15 +77,28 @@ impl std::ops::Deref for ReaderBytes<'_> {
     }
 }
 
-impl<'a, T: 'a + MmapBytesReader> From<&'a T> for ReaderBytes<'a> {
-    fn from(m: &'a T) -> Self {
-        match m.to_bytes() {
-            Some(s) => ReaderBytes::Borrowed(s),
-            None => {
-                let f = m.to_file().unwrap();
-                let mmap = unsafe { memmap::Mmap::map(f).unwrap() };
-                ReaderBytes::Mapped(mmap)
+/// Borrow the bytes of a reader, memory map its file or, for a [`ParquetSource`], read
+/// the whole source. Readers that only need some ranges of a source read those instead.
+impl<'a, T: 'a + MmapBytesReader> TryFrom<&'a T> for ReaderBytes<'a> {
+    type Error = PolarsError;
+
+    fn try_from(m: &'a T) -> Result<Self> {
+        if let Some(s) = m.to_bytes() {
+            return Ok(ReaderBytes::Borrowed(s));
+        }
+        #[cfg(feature = "parquet")]
+        if let Some(source) = m.to_source() {
+            let bytes = source.read_range(0, source.file_len()?)?;
+            return Ok(ReaderBytes::Owned(bytes));
+        }
+        match m.to_file() {
+            Some(f) => {
+                let mmap = unsafe { memmap::Mmap::map(f)? };
+                Ok(ReaderBytes::Mapped(mmap))
             }
+            None => Err(PolarsError::ComputeError(
+                "the reader has no bytes, file or source to read from".into(),
+            )),
         }
     }
 }
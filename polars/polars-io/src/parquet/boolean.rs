@@ -0,0 +1,199 @@
+use crate::parquet::mmap::{self, FileBytes};
+use crate::parquet::pool::DecompressionPool;
+use arrow::array::{ArrayRef, BooleanArray};
+use arrow::bitmap::utils::count_zeros;
+use arrow::bitmap::MutableBitmap;
+use arrow::error::Error as ArrowError;
+use arrow::io::parquet::read::{
+    get_field_columns, DataPage, FallibleStreamingIterator, RowGroupMetaData,
+};
+use arrow::io::parquet::write::Encoding;
+use parquet2::encoding::hybrid_rle::{Decoder, HybridEncoded};
+use parquet2::page::split_buffer;
+use polars_core::prelude::*;
+use std::convert::TryFrom;
+
+/// Whether the boolean `column` of `md` can be decoded by [`decode`]: a single column
+/// chunk that is not nested, of which the values are plain or RLE encoded.
+pub(super) fn is_supported(md: &RowGroupMetaData, column: &str) -> bool {
+    match get_field_columns(md.columns(), column).as_slice() {
+        [chunk] => {
+            let descriptor = &chunk.descriptor().descriptor;
+            descriptor.max_rep_level == 0
+                && descriptor.max_def_level <= 1
+                && chunk.column_encoding().iter().all(|encoding| {
+                    matches!(
+                        Encoding::try_from(*encoding),
+                        Ok(Encoding::Plain | Encoding::Rle)
+                    )
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Decode at most `num_rows` rows of the boolean column `field` of `md` into a `Boolean`
+/// `Series`.
+///
+/// The pages are decoded straight into the bitmaps of the array: a run of repeated
+/// values or definition levels is appended at once and a bit-packed run is copied, no
+/// value is decoded into a byte of its own.
+pub(super) fn decode(
+    md: &RowGroupMetaData,
+    field: &ArrowField,
+    num_rows: usize,
+    pool: Option<&DecompressionPool>,
+    bytes: FileBytes,
+) -> Result<Series> {
+    let columns = mmap::mmap_columns(bytes, md.columns(), &field.name)?;
+    let mut pages = mmap::column_pages(&columns, pool, bytes);
+    let pages = &mut pages[0];
+
+    let len = std::cmp::min(num_rows, md.num_rows());
+    let mut validity = MutableBitmap::with_capacity(len);
+    let mut values = MutableBitmap::with_capacity(len);
+    let mut remaining = len;
+    while remaining > 0 {
+        match pages.next().map_err(ArrowError::from)? {
+            Some(page) => {
+                remaining -= extend_from_page(page, remaining, &mut validity, &mut values)?
+            }
+            None => break,
+        }
+    }
+
+    let arr = BooleanArray::from_data(ArrowDataType::Boolean, values.into(), validity.into());
+    Series::try_from((field.name.as_str(), Box::new(arr) as ArrayRef))
+}
+
+/// Append at most `limit` rows of `page`, returns the number of rows appended.
+fn extend_from_page(
+    page: &DataPage,
+    limit: usize,
+    validity: &mut MutableBitmap,
+    values: &mut MutableBitmap,
+) -> Result<usize> {
+    let len = std::cmp::min(page.num_values(), limit);
+    let (_, def_levels, buffer) = split_buffer(page);
+
+    // only the values of the valid rows are encoded
+    let start = validity.len();
+    let n_valid = if page.descriptor.max_def_level == 1 {
+        extend_hybrid(validity, def_levels, len)?
+    } else {
+        len
+    };
+
+    let mut packed = MutableBitmap::new();
+    let target = if n_valid == len {
+        &mut *values
+    } else {
+        &mut packed
+    };
+    match page.encoding() {
+        Encoding::Plain => {
+            if buffer.len() * 8 < n_valid {
+                return Err(PolarsError::ComputeError(
+                    format!(
+                        "a boolean page holds {} values, but {} rows are valid",
+                        buffer.len() * 8,
+                        n_valid
+                    )
+                    .into(),
+                ));
+            }
+            target.extend_from_slice(buffer, 0, n_valid);
+        }
+        Encoding::Rle => {
+            // the runs are prefixed by their length in bytes
+            let length = buffer
+                .get(..4)
+                .map(|length| u32::from_le_bytes(length.try_into().unwrap()) as usize);
+            match length.and_then(|length| buffer.get(4..4 + length)) {
+                Some(runs) => {
+                    extend_hybrid(target, runs, n_valid)?;
+                }
+                None => {
+                    return Err(PolarsError::ComputeError(
+                        "a RLE encoded boolean page is truncated".into(),
+                    ))
+                }
+            }
+        }
+        encoding => {
+            return Err(PolarsError::ComputeError(
+                format!("boolean pages of encoding {:?} are not supported", encoding).into(),
+            ))
+        }
+    }
+    if n_valid < len {
+        scatter(values, validity, start, &packed);
+    }
+    Ok(len)
+}
+
+/// Append `len` bits of the RLE/bit-packed hybrid encoded `runs` of bit width 1 to
+/// `bitmap`, returns the number of set bits.
+pub(super) fn extend_hybrid(bitmap: &mut MutableBitmap, runs: &[u8], len: usize) -> Result<usize> {
+    let mut remaining = len;
+    let mut set = 0;
+    for run in Decoder::new(runs, 1) {
+        if remaining == 0 {
+            break;
+        }
+        match run {
+            HybridEncoded::Bitpacked(pack) => {
+                // the last bit-packed run is padded to a multiple of 8 values
+                let n = std::cmp::min(pack.len() * 8, remaining);
+                bitmap.extend_from_slice(pack, 0, n);
+                set += n - count_zeros(pack, 0, n);
+                remaining -= n;
+            }
+            HybridEncoded::Rle(value, n) => {
+                let n = std::cmp::min(n, remaining);
+                let is_set = value.first().map_or(false, |value| value & 1 == 1);
+                bitmap.extend_constant(n, is_set);
+                if is_set {
+                    set += n;
+                }
+                remaining -= n;
+            }
+        }
+    }
+    if remaining > 0 {
+        return Err(PolarsError::ComputeError(
+            format!(
+                "the runs of a boolean page hold {} values, expected {}",
+                len - remaining,
+                len
+            )
+            .into(),
+        ));
+    }
+    Ok(set)
+}
+
+/// Append the values of the valid rows `packed` to `values`, at the positions of the
+/// valid rows in `validity[start..]`. Null rows are false.
+fn scatter(
+    values: &mut MutableBitmap,
+    validity: &MutableBitmap,
+    start: usize,
+    packed: &MutableBitmap,
+) {
+    let mut offset = 0;
+    let mut i = start;
+    while i < validity.len() {
+        let is_valid = validity.get(i);
+        let run = (i..validity.len())
+            .take_while(|j| validity.get(*j) == is_valid)
+            .count();
+        if is_valid {
+            values.extend_from_slice(packed.as_slice(), offset, run);
+            offset += run;
+        } else {
+            values.extend_constant(run, false);
+        }
+        i += run;
+    }
+}
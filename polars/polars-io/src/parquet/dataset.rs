@@ -0,0 +1,136 @@
+use crate::parquet::read::infer_schema;
+use crate::parquet::read_impl::{evaluate_row_group, ReadDiagnostics};
+use crate::parquet::{ParquetInterval, ParquetReader};
+use crate::predicates::{arrow_schema_to_empty_df, PhysicalIoExpr};
+use crate::SerReader;
+use arrow::io::parquet::read::{self, FileMetaData};
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// A dataset of parquet files of which the metadata is known up front, for instance
+/// from a `_metadata` summary file.
+///
+/// Files of which the statistics show that no row group has to be read are not opened.
+pub struct ParquetDataset {
+    root: PathBuf,
+    files: Vec<(String, FileMetaData)>,
+}
+
+impl ParquetDataset {
+    /// Create a dataset from the metadata of every file. The paths of the files are
+    /// relative to `root`.
+    pub fn new(root: PathBuf, files: Vec<(String, FileMetaData)>) -> Self {
+        ParquetDataset { root, files }
+    }
+
+    /// Read a `_metadata` summary file, which holds the row groups of all files of the
+    /// dataset. The paths of the files are relative to the directory of the summary file.
+    pub fn from_metadata_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let metadata = read::read_metadata(&mut File::open(path)?)?;
+        let root = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        Self::from_metadata(root, &metadata)
+    }
+
+    /// Split the metadata of a `_metadata` summary file into the metadata of every file.
+    pub fn from_metadata(root: PathBuf, metadata: &FileMetaData) -> Result<Self> {
+        let mut files: Vec<(String, FileMetaData)> = vec![];
+        for md in &metadata.row_groups {
+            let path = md
+                .columns()
+                .first()
+                .and_then(|column| column.file_path().clone())
+                .ok_or_else(|| {
+                    PolarsError::ComputeError(
+                        "a row group in the summary metadata has no file path".into(),
+                    )
+                })?;
+            // the row groups of a file are stored consecutively
+            match files.last_mut() {
+                Some((last, file_md)) if *last == path => {
+                    file_md.num_rows += md.num_rows();
+                    file_md.row_groups.push(md.clone());
+                }
+                _ => {
+                    let mut file_md = metadata.clone();
+                    file_md.num_rows = md.num_rows();
+                    file_md.row_groups = vec![md.clone()];
+                    files.push((path, file_md));
+                }
+            }
+        }
+        Ok(ParquetDataset { root, files })
+    }
+
+    /// The path and the metadata of every file.
+    pub fn files(&self) -> impl Iterator<Item = (&str, &FileMetaData)> {
+        self.files.iter().map(|(path, md)| (path.as_str(), md))
+    }
+
+    /// Read all files that may have rows that pass the `predicate`.
+    ///
+    /// The footer of every file that is read is compared to the known metadata. If the
+    /// row counts differ, the metadata is stale and an error is returned.
+    pub fn read(
+        &self,
+        columns: Option<Vec<String>>,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    ) -> Result<DataFrame> {
+        let mut dfs = Vec::with_capacity(self.files.len());
+        let mut empty_schema = None;
+        for (path, md) in &self.files {
+            let schema = infer_schema(md, true, ParquetInterval::default())?;
+            let mut read_file = false;
+            for rg in 0..md.row_groups.len() {
+                if evaluate_row_group(predicate.as_ref(), rg, md, &schema, None, None)?
+                    .should_read()
+                {
+                    read_file = true;
+                    break;
+                }
+            }
+            if !read_file {
+                empty_schema.get_or_insert(schema);
+                continue;
+            }
+
+            let mut file = File::open(self.root.join(path))?;
+            let footer = read::read_metadata(&mut file)?;
+            let expected = md.row_groups.iter().map(|rg| rg.num_rows());
+            if !footer
+                .row_groups
+                .iter()
+                .map(|rg| rg.num_rows())
+                .eq(expected)
+            {
+                return Err(PolarsError::ComputeError(
+                    format!(
+                        "the metadata of '{}' is stale, the row counts of its row groups differ from the file",
+                        path
+                    )
+                    .into(),
+                ));
+            }
+            let df = ParquetReader::new(file)
+                .with_metadata(Some(footer))
+                .with_columns(columns.clone())
+                .finish_impl(predicate.clone(), None, None, ReadDiagnostics::default())?;
+            dfs.push(df);
+        }
+
+        match (dfs.is_empty(), empty_schema) {
+            (true, Some(schema)) => {
+                let df = arrow_schema_to_empty_df(&schema);
+                match columns {
+                    Some(columns) => df.select(columns),
+                    None => Ok(df),
+                }
+            }
+            (true, None) => Err(PolarsError::NoData("the dataset has no files".into())),
+            _ => accumulate_dataframes_vertical(dfs),
+        }
+    }
+}
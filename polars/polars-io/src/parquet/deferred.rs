@@ -0,0 +1,130 @@
+use crate::mmap::{MmapBytesReader, ReaderBytes};
+use crate::parquet::read_impl::{decode_column_chunk, evaluate_row_group};
+use crate::predicates::PhysicalIoExpr;
+use arrow::datatypes::ArrowSchema;
+use arrow::io::parquet::read::FileMetaData;
+use once_cell::sync::OnceCell;
+use polars_core::prelude::*;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// The columns of a parquet file that are decoded when they are first accessed.
+///
+/// The metadata is read and the row groups are pruned up front, but a column is only
+/// decoded when [`DeferredParquetFrame::column`] is called for it. The decoded column is
+/// kept, so every column is decoded at most once.
+///
+/// Created by [`ParquetReader::finish_deferred`](super::ParquetReader::finish_deferred).
+pub struct DeferredParquetFrame<R: MmapBytesReader> {
+    reader: R,
+    metadata: FileMetaData,
+    schema: ArrowSchema,
+    projection: Vec<usize>,
+    row_groups: Vec<usize>,
+    columns: Vec<OnceCell<Series>>,
+}
+
+impl<R: MmapBytesReader> DeferredParquetFrame<R> {
+    pub(super) fn new(
+        reader: R,
+        metadata: FileMetaData,
+        schema: ArrowSchema,
+        projection: Vec<usize>,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    ) -> Result<Self> {
+        let mut row_groups = Vec::with_capacity(metadata.row_groups.len());
+        for rg in 0..metadata.row_groups.len() {
+            if evaluate_row_group(predicate.as_ref(), rg, &metadata, &schema, None, None)?
+                .should_read()
+            {
+                row_groups.push(rg);
+            }
+        }
+        let columns = projection.iter().map(|_| OnceCell::new()).collect();
+        Ok(DeferredParquetFrame {
+            reader,
+            metadata,
+            schema,
+            projection,
+            row_groups,
+            columns,
+        })
+    }
+
+    /// The names of the columns, in the order of the projection.
+    pub fn get_column_names(&self) -> Vec<&str> {
+        self.projection
+            .iter()
+            .map(|i| self.schema.fields[*i].name.as_str())
+            .collect()
+    }
+
+    /// The schema of the columns, without decoding them.
+    pub fn schema(&self) -> Schema {
+        self.projection
+            .iter()
+            .map(|i| Field::from(&self.schema.fields[*i]))
+            .collect()
+    }
+
+    /// The number of rows of the row groups that survived pruning.
+    pub fn height(&self) -> usize {
+        self.row_groups
+            .iter()
+            .map(|rg| self.metadata.row_groups[*rg].num_rows())
+            .sum()
+    }
+
+    /// Whether the column `name` has been decoded.
+    pub fn is_decoded(&self, name: &str) -> bool {
+        self.find(name)
+            .map_or(false, |idx| self.columns[idx].get().is_some())
+    }
+
+    /// Get the column `name`, decoding it if it is accessed for the first time.
+    pub fn column(&self, name: &str) -> Result<&Series> {
+        let idx = self
+            .find(name)
+            .ok_or_else(|| PolarsError::NotFound(name.to_string()))?;
+        self.columns[idx].get_or_try_init(|| self.decode(self.projection[idx]))
+    }
+
+    /// Decode all columns that have not been decoded yet and collect them into a
+    /// [`DataFrame`].
+    pub fn into_df(self) -> Result<DataFrame> {
+        let columns = self
+            .columns
+            .iter()
+            .zip(&self.projection)
+            .map(|(cell, column_i)| cell.get_or_try_init(|| self.decode(*column_i)).cloned())
+            .collect::<Result<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+
+    fn find(&self, name: &str) -> Option<usize> {
+        self.projection
+            .iter()
+            .position(|i| self.schema.fields[*i].name == name)
+    }
+
+    fn decode(&self, column_i: usize) -> Result<Series> {
+        let reader_bytes = ReaderBytes::try_from(&self.reader)?;
+        let bytes = reader_bytes.deref();
+
+        let mut out: Option<Series> = None;
+        for rg in &self.row_groups {
+            let md = &self.metadata.row_groups[*rg];
+            let s = decode_column_chunk(column_i, md, &self.schema, bytes)?;
+            match &mut out {
+                Some(out) => {
+                    out.append(&s)?;
+                }
+                None => out = Some(s),
+            }
+        }
+        Ok(out.unwrap_or_else(|| {
+            let field = &self.schema.fields[column_i];
+            Series::new_empty(&field.name, &(&field.data_type).into())
+        }))
+    }
+}
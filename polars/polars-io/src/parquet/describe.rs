@@ -0,0 +1,74 @@
+use crate::parquet::interval::ParquetInterval;
+use crate::parquet::predicates::{file_bounds, FileStatistics};
+use crate::parquet::read::infer_schema;
+use arrow::io::parquet::read;
+use polars_core::prelude::*;
+use std::io::{Read, Seek};
+
+fn value_to_string(value: AnyValue) -> String {
+    match value {
+        AnyValue::Utf8(v) => v.to_string(),
+        AnyValue::Utf8Owned(v) => v,
+        v => v.to_string(),
+    }
+}
+
+/// Summarize every column of a parquet file from the statistics in its metadata,
+/// without decoding any data.
+///
+/// The summary has a row per column with its `column` name, its `dtype`, the
+/// `null_count` summed over the row groups and the `min` and `max` of all row groups,
+/// formatted as strings. The `distinct_count` of a row group doesn't add up over row
+/// groups, so it is only given for files with a single row group. A value is null if a
+/// row group lacks the statistic.
+pub fn parquet_describe<R: Read + Seek>(mut reader: R) -> Result<DataFrame> {
+    let metadata = read::read_metadata(&mut reader)?;
+    let schema = infer_schema(&metadata, true, ParquetInterval::default())?;
+    let statistics = FileStatistics::collect(&metadata, &schema)?;
+
+    let n_columns = schema.fields.len();
+    let mut names = Vec::with_capacity(n_columns);
+    let mut dtypes = Vec::with_capacity(n_columns);
+    let mut null_counts = Vec::with_capacity(n_columns);
+    let mut distinct_counts = Vec::with_capacity(n_columns);
+    let mut mins = Vec::with_capacity(n_columns);
+    let mut maxs = Vec::with_capacity(n_columns);
+
+    for field in &schema.fields {
+        let column_stats = (0..statistics.num_row_groups())
+            .map(|rg| {
+                statistics
+                    .row_group(rg)
+                    .and_then(|stats| stats.get_stats(&field.name).ok())
+            })
+            .collect::<Option<Vec<_>>>();
+
+        names.push(field.name.as_str());
+        dtypes.push(DataType::from(&field.data_type).to_string());
+        null_counts.push(column_stats.as_ref().and_then(|stats| {
+            stats
+                .iter()
+                .map(|st| st.null_count().map(|count| count as u64))
+                .sum::<Option<u64>>()
+        }));
+        distinct_counts.push(match column_stats.as_deref() {
+            Some([st]) => st.distinct_count().map(|count| count as u64),
+            _ => None,
+        });
+        let bounds = file_bounds(&metadata, &schema, &field.name)?;
+        let (min, max) = bounds.map_or((None, None), |(min, max)| {
+            (Some(value_to_string(min)), Some(value_to_string(max)))
+        });
+        mins.push(min);
+        maxs.push(max);
+    }
+
+    DataFrame::new(vec![
+        Series::new("column", names),
+        Series::new("dtype", dtypes),
+        Series::new("null_count", null_counts),
+        Series::new("distinct_count", distinct_counts),
+        Series::new("min", mins),
+        Series::new("max", maxs),
+    ])
+}
@@ -0,0 +1,51 @@
+//! Make the decoding of column chunks fail, to test how reads recover from it.
+use once_cell::sync::Lazy;
+use polars_core::prelude::*;
+use std::sync::Mutex;
+
+/// The `(row group, column)` decodes that fail.
+static FAULTS: Lazy<Mutex<Vec<(usize, String)>>> = Lazy::new(Default::default);
+
+/// Removes the faults it injected when it is dropped.
+pub(crate) struct FaultGuard(Vec<(usize, String)>);
+
+impl Drop for FaultGuard {
+    fn drop(&mut self) {
+        let mut faults = FAULTS.lock().unwrap();
+        for fault in &self.0 {
+            if let Some(idx) = faults.iter().position(|f| f == fault) {
+                faults.remove(idx);
+            }
+        }
+    }
+}
+
+/// Make decoding `column` in the row group fail for every `(row group, column)`, until
+/// the guard is dropped. The faults are global and tests run concurrently, so every test
+/// should use columns with names that other tests don't use.
+pub(crate) fn inject(faults: &[(usize, &str)]) -> FaultGuard {
+    let faults = faults
+        .iter()
+        .map(|(rg, column)| (*rg, column.to_string()))
+        .collect::<Vec<_>>();
+    FAULTS.lock().unwrap().extend(faults.iter().cloned());
+    FaultGuard(faults)
+}
+
+pub(super) fn check(rg: usize, column: &str) -> Result<()> {
+    let faults = FAULTS.lock().unwrap();
+    if faults
+        .iter()
+        .any(|(f_rg, f_column)| *f_rg == rg && f_column == column)
+    {
+        Err(PolarsError::ComputeError(
+            format!(
+                "injected failure decoding column '{}' in row group {}",
+                column, rg
+            )
+            .into(),
+        ))
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use std::fmt;
+
+/// A fingerprint of the decisions a parquet read made: the projection, the
+/// predicate, the limit and the row groups that were read.
+///
+/// The fingerprint is computed with FNV-1a, which (unlike the hashers of `std`
+/// and `ahash`) is stable across processes, platforms and versions.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ReadFingerprint(u64);
+
+impl Default for ReadFingerprint {
+    fn default() -> Self {
+        ReadFingerprint(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl ReadFingerprint {
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    pub(super) fn write_usize(&mut self, value: usize) {
+        self.write(&(value as u64).to_le_bytes())
+    }
+
+    pub(super) fn write_bool(&mut self, value: bool) {
+        self.write(&[value as u8])
+    }
+
+    /// Strings are prefixed with their length, so that concatenations don't collide.
+    pub(super) fn write_str(&mut self, value: &str) {
+        self.write_usize(value.len());
+        self.write(value.as_bytes())
+    }
+}
+
+impl fmt::Debug for ReadFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ReadFingerprint({:016x})", self.0)
+    }
+}
+
+impl fmt::Display for ReadFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
@@ -0,0 +1,189 @@
+//! Reading columns of the legacy parquet `INTERVAL` type.
+//!
+//! An interval is stored as a fixed length byte array of 12 bytes: three little
+//! endian `u32`s with the number of months, days and milliseconds. Months don't
+//! have a fixed length, so an interval can only be read as a `Duration` if it
+//! has no months. Otherwise it must be read as a struct of its components.
+use crate::parquet::read::find_parquet_field;
+use crate::ArrowResult;
+use arrow::array::{Array, FixedSizeBinaryArray, PrimitiveArray, StructArray, UInt32Array};
+use arrow::datatypes::{DataType as ArrowDataType, Field, TimeUnit as ArrowTimeUnit};
+use arrow::error::Error as ArrowError;
+use arrow::io::parquet::read::{
+    ArrayIter, ColumnChunkMetaData, ParquetType, PrimitiveConvertedType,
+};
+use polars_core::prelude::ArrayRef;
+
+const INTERVAL_SIZE: usize = 12;
+const MILLISECONDS_IN_DAY: i64 = 86_400_000;
+
+/// How to read columns of the parquet `INTERVAL` type.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParquetInterval {
+    /// Read as `Duration` in milliseconds, where a day is 24 hours.
+    /// Reading fails if an interval has a non-zero number of months.
+    Duration,
+    /// Read as a struct with the `u32` fields `months`, `days` and `millis`.
+    Struct,
+}
+
+impl Default for ParquetInterval {
+    fn default() -> Self {
+        ParquetInterval::Duration
+    }
+}
+
+impl ParquetInterval {
+    pub(super) fn to_arrow(self) -> ArrowDataType {
+        match self {
+            ParquetInterval::Duration => ArrowDataType::Duration(ArrowTimeUnit::Millisecond),
+            ParquetInterval::Struct => ArrowDataType::Struct(vec![
+                Field::new("months", ArrowDataType::UInt32, true),
+                Field::new("days", ArrowDataType::UInt32, true),
+                Field::new("millis", ArrowDataType::UInt32, true),
+            ]),
+        }
+    }
+}
+
+fn is_interval(converted_type: Option<&PrimitiveConvertedType>) -> bool {
+    matches!(converted_type, Some(PrimitiveConvertedType::Interval))
+}
+
+/// Set the dtype of the top level `INTERVAL` columns to the dtype they are read as.
+pub(super) fn set_interval_dtypes(
+    parquet_fields: &[ParquetType],
+    fields: &mut [Field],
+    interval: ParquetInterval,
+) {
+    for field in fields {
+        if let Some(ParquetType::PrimitiveType(primitive)) =
+            find_parquet_field(parquet_fields, &field.name)
+        {
+            if is_interval(primitive.converted_type.as_ref()) {
+                field.data_type = interval.to_arrow();
+            }
+        }
+    }
+}
+
+/// If the columns hold an `INTERVAL`, return the field to decode the raw bytes with.
+pub(super) fn interval_physical_field(
+    columns: &[(&ColumnChunkMetaData, &[u8])],
+    field: &Field,
+) -> Option<Field> {
+    match columns {
+        [(meta, _)]
+            if is_interval(
+                meta.descriptor()
+                    .descriptor
+                    .primitive_type
+                    .converted_type
+                    .as_ref(),
+            ) =>
+        {
+            Some(Field::new(
+                &field.name,
+                ArrowDataType::FixedSizeBinary(INTERVAL_SIZE),
+                field.is_nullable,
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Convert the raw arrays produced by `iter` to arrays of `data_type`.
+pub(super) fn convert_intervals(iter: ArrayIter, data_type: ArrowDataType) -> ArrayIter {
+    Box::new(iter.map(move |arr| {
+        let arr = arr?;
+        let arr = arr
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .expect("intervals are decoded as fixed size binary");
+        intervals_to_arrow(arr, &data_type)
+    }))
+}
+
+fn intervals_to_arrow(
+    arr: &FixedSizeBinaryArray,
+    data_type: &ArrowDataType,
+) -> ArrowResult<ArrayRef> {
+    let component = |i: usize| -> Vec<u32> {
+        arr.values()
+            .chunks_exact(INTERVAL_SIZE)
+            .map(|bytes| u32::from_le_bytes(bytes[4 * i..4 * (i + 1)].try_into().unwrap()))
+            .collect()
+    };
+    let validity = arr.validity().cloned();
+
+    match data_type {
+        ArrowDataType::Struct(_) => {
+            let values = (0..3)
+                .map(|i| Box::new(UInt32Array::from_vec(component(i))) as ArrayRef)
+                .collect();
+            Ok(Box::new(StructArray::new(
+                data_type.clone(),
+                values,
+                validity,
+            )))
+        }
+        _ => {
+            let months = component(0);
+            if (0..arr.len()).any(|i| months[i] != 0 && arr.is_valid(i)) {
+                return Err(ArrowError::InvalidArgumentError(
+                    "an interval with months can not be read as a duration, read the intervals as a struct instead".into(),
+                ));
+            }
+            let values = component(1)
+                .into_iter()
+                .zip(component(2))
+                .map(|(days, millis)| days as i64 * MILLISECONDS_IN_DAY + millis as i64)
+                .collect::<Vec<_>>();
+            let arr = PrimitiveArray::from_vec(values)
+                .to(data_type.clone())
+                .with_validity(validity);
+            Ok(Box::new(arr))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn intervals(values: &[[u32; 3]]) -> FixedSizeBinaryArray {
+        let bytes = values
+            .iter()
+            .flat_map(|v| v.iter().flat_map(|c| c.to_le_bytes()))
+            .collect::<Vec<_>>();
+        FixedSizeBinaryArray::from_data(
+            ArrowDataType::FixedSizeBinary(INTERVAL_SIZE),
+            bytes.into(),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_intervals_to_duration() {
+        let arr = intervals(&[[0, 1, 500], [0, 0, 20]]);
+        let out = intervals_to_arrow(&arr, &ParquetInterval::Duration.to_arrow()).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+        assert_eq!(out.values().as_slice(), &[MILLISECONDS_IN_DAY + 500, 20]);
+
+        // months have no fixed duration
+        let arr = intervals(&[[0, 1, 500], [1, 0, 0]]);
+        assert!(intervals_to_arrow(&arr, &ParquetInterval::Duration.to_arrow()).is_err());
+    }
+
+    #[test]
+    fn test_intervals_to_struct() {
+        let arr = intervals(&[[0, 1, 500], [1, 0, 0]]);
+        let out = intervals_to_arrow(&arr, &ParquetInterval::Struct.to_arrow()).unwrap();
+        let out = out.as_any().downcast_ref::<StructArray>().unwrap();
+        let months = out.values()[0]
+            .as_any()
+            .downcast_ref::<UInt32Array>()
+            .unwrap();
+        assert_eq!(months.values().as_slice(), &[0, 1]);
+    }
+}
@@ -0,0 +1,120 @@
+use arrow::io::ndjson;
+use polars_core::prelude::*;
+
+/// What to do with a value of a JSON column that doesn't fit the schema that was
+/// inferred for the column, see [`JsonExpansion`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum JsonMismatch {
+    /// Read the value as null. Fields of the value that are not in the schema are
+    /// dropped, fields of the schema that the value lacks are null.
+    Null,
+    /// Return an error with the value.
+    Error,
+}
+
+/// Expand a string column that holds JSON objects into a `Struct` column, see
+/// [`ParquetReader::with_json_expansion`](super::ParquetReader::with_json_expansion).
+///
+/// The schema of the struct is inferred once, from the first `sample` values of the
+/// first row group that is read, or from all its values if `sample` is `None`, and
+/// applied to every row group. A value fits the schema if its fields are fields of the
+/// schema of the same dtype; integers also fit float fields and null fits any field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsonExpansion {
+    pub column: String,
+    pub sample: Option<usize>,
+    pub on_mismatch: JsonMismatch,
+}
+
+impl JsonExpansion {
+    pub fn new(column: &str) -> Self {
+        JsonExpansion {
+            column: column.to_string(),
+            sample: Some(128),
+            on_mismatch: JsonMismatch::Null,
+        }
+    }
+
+    pub fn with_sample(mut self, sample: Option<usize>) -> Self {
+        self.sample = sample;
+        self
+    }
+
+    pub fn with_on_mismatch(mut self, on_mismatch: JsonMismatch) -> Self {
+        self.on_mismatch = on_mismatch;
+        self
+    }
+
+    /// Replace the column of `df` by its expansion. The schema is inferred if `dtype`
+    /// is not yet set.
+    pub(super) fn expand(
+        &self,
+        df: &mut DataFrame,
+        dtype: &mut Option<ArrowDataType>,
+    ) -> Result<()> {
+        let ca = df.column(&self.column)?.utf8()?;
+        let mut rows = ca
+            .into_iter()
+            .map(|value| value.unwrap_or("null"))
+            .collect::<Vec<_>>();
+
+        if dtype.is_none() {
+            let n = std::cmp::min(self.sample.unwrap_or(usize::MAX), rows.len());
+            let inferred = ndjson::read::infer_iter(rows[..n].iter())?;
+            if !matches!(inferred, ArrowDataType::Struct(_)) {
+                return Err(PolarsError::ComputeError(
+                    format!(
+                        "the sampled values of column '{}' are not JSON objects",
+                        self.column
+                    )
+                    .into(),
+                ));
+            }
+            *dtype = Some(inferred);
+        }
+        let dtype = dtype.as_ref().unwrap();
+
+        for row in rows.iter_mut() {
+            let fits = match ndjson::read::infer_iter(std::iter::once(*row)) {
+                Ok(row_dtype) => fits(&row_dtype, dtype),
+                Err(_) => false,
+            };
+            if !fits {
+                match self.on_mismatch {
+                    JsonMismatch::Null => *row = "null",
+                    JsonMismatch::Error => {
+                        return Err(PolarsError::ComputeError(
+                            format!(
+                                "the value {} of column '{}' doesn't fit the inferred schema",
+                                row, self.column
+                            )
+                            .into(),
+                        ))
+                    }
+                }
+            }
+        }
+
+        let arr = ndjson::read::deserialize(&rows, dtype.clone())?;
+        let s = Series::try_from((self.column.as_str(), arr))?;
+        df.with_column(s)?;
+        Ok(())
+    }
+}
+
+/// Whether a value of dtype `value` can be read as `schema`.
+fn fits(value: &ArrowDataType, schema: &ArrowDataType) -> bool {
+    use ArrowDataType::*;
+    match (value, schema) {
+        (Null, _) | (Int64, Float64) => true,
+        (Struct(values), Struct(fields)) => values.iter().all(|value| {
+            fields
+                .iter()
+                .any(|field| field.name == value.name && fits(&value.data_type, &field.data_type))
+        }),
+        (List(value), List(field)) | (LargeList(value), LargeList(field)) => {
+            fits(&value.data_type, &field.data_type)
+        }
+        (value, schema) => value == schema,
+    }
+}
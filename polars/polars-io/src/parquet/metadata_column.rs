@@ -0,0 +1,118 @@
+use crate::parquet::predicates::collect_statistics;
+use crate::parquet::predicates::ColumnStats;
+use arrow::datatypes::ArrowSchema;
+use arrow::io::parquet::read::{get_field_columns, ColumnChunkMetaData, RowGroupMetaData};
+use polars_core::prelude::*;
+
+/// A field of the metadata of a row group.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MetadataField {
+    /// The number of rows of the row group, as `UInt64`.
+    NumRows,
+    /// The uncompressed byte size of the row group that the writer declared, as `UInt64`.
+    TotalByteSize,
+    /// The compressed byte size of the column chunks of a column, as `UInt64`.
+    CompressedSize(String),
+    /// The uncompressed byte size of the column chunks of a column, as `UInt64`.
+    UncompressedSize(String),
+    /// The null count in the statistics of a column, as `UInt64`.
+    NullCount(String),
+    /// The min value in the statistics of a column, of the dtype of the column.
+    Min(String),
+    /// The max value in the statistics of a column, of the dtype of the column. The max
+    /// value is as written, even if the writer truncated it.
+    Max(String),
+}
+
+/// A column of which the values are constant per row group and taken from the metadata
+/// of the row group, without decoding its pages.
+///
+/// A field that the metadata lacks, e.g. the min value of a column without statistics,
+/// is null.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MetadataColumn {
+    pub name: String,
+    pub field: MetadataField,
+}
+
+impl MetadataColumn {
+    pub fn new(name: &str, field: MetadataField) -> Self {
+        MetadataColumn {
+            name: name.to_string(),
+            field,
+        }
+    }
+
+    /// Check that the columns of which the field is taken are in `schema`.
+    pub(super) fn validate(&self, schema: &ArrowSchema) -> Result<()> {
+        use MetadataField::*;
+        match &self.field {
+            NumRows | TotalByteSize => Ok(()),
+            CompressedSize(column)
+            | UncompressedSize(column)
+            | NullCount(column)
+            | Min(column)
+            | Max(column) => schema.try_index_of(column).map(|_| ()),
+        }
+    }
+
+    /// The values of this column for the `len` rows that are read from the row group `md`.
+    pub(super) fn materialize(
+        &self,
+        md: &RowGroupMetaData,
+        schema: &ArrowSchema,
+        len: usize,
+    ) -> Result<Series> {
+        use MetadataField::*;
+        let name = self.name.as_str();
+        let full_u64 = |value: Option<u64>| match value {
+            Some(value) => UInt64Chunked::full(name, value, len).into_series(),
+            None => Series::full_null(name, len, &DataType::UInt64),
+        };
+        let column_size = |column: &str, size: fn(&ColumnChunkMetaData) -> i64| {
+            get_field_columns(md.columns(), column)
+                .into_iter()
+                .map(|meta| size(meta) as u64)
+                .sum::<u64>()
+        };
+        // the statistics of `column` in this row group, if it has any
+        let with_stats = |column: &str, f: &dyn Fn(Option<&ColumnStats>) -> Result<Series>| {
+            let field = &schema.fields[schema.try_index_of(column)?];
+            let schema = ArrowSchema::from(vec![field.clone()]);
+            let stats = collect_statistics(std::slice::from_ref(md), &schema, None)?;
+            f(stats
+                .as_ref()
+                .and_then(|stats| stats.get_stats(column).ok()))
+        };
+
+        Ok(match &self.field {
+            NumRows => full_u64(Some(md.num_rows() as u64)),
+            TotalByteSize => full_u64(Some(md.total_byte_size() as u64)),
+            CompressedSize(column) => full_u64(Some(column_size(
+                column,
+                ColumnChunkMetaData::compressed_size,
+            ))),
+            UncompressedSize(column) => full_u64(Some(column_size(
+                column,
+                ColumnChunkMetaData::uncompressed_size,
+            ))),
+            NullCount(column) => with_stats(column, &|stats| {
+                let count = stats.and_then(ColumnStats::null_count);
+                Ok(full_u64(count.map(|count| count as u64)))
+            })?,
+            Min(column) | Max(column) => with_stats(column, &|stats| {
+                let field = &schema.fields[schema.try_index_of(column)?];
+                let dtype = DataType::from(&field.data_type);
+                match stats.and_then(ColumnStats::to_min_max) {
+                    Some(bounds) => {
+                        let idx = if matches!(self.field, Min(_)) { 0 } else { 1 };
+                        let mut s = bounds.take(&IdxCa::full("", idx, len))?.cast(&dtype)?;
+                        s.rename(name);
+                        Ok(s)
+                    }
+                    None => Ok(Series::full_null(name, len, &dtype)),
+                }
+            })?,
+        })
+    }
+}
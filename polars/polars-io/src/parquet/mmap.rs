@@ -1,16 +1,116 @@
 use super::*;
+use crate::parquet::pool::DecompressionPool;
 use arrow::datatypes::Field;
 use arrow::io::parquet::read::{
-    column_iter_to_arrays, get_field_columns, ArrayIter, BasicDecompressor, ColumnChunkMetaData,
-    PageReader,
+    column_iter_to_arrays, decompress, get_field_columns, ArrayIter, BasicDecompressor,
+    ColumnChunkMetaData, CompressedDataPage, DataPage, FallibleStreamingIterator, PageReader,
+    ParquetError,
 };
+use std::io::Cursor;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// The bytes of a file, or of a window of a file that starts at `offset`, or the byte
+/// ranges that were read from a [`ParquetSource`](super::ParquetSource).
+///
+/// Column chunks of which the pages were already decompressed are decoded from those
+/// pages instead. The pages of the other column chunks decompress to at most the bytes
+/// left in the budget, if any.
+#[derive(Copy, Clone)]
+pub(super) struct FileBytes<'a> {
+    bytes: &'a [u8],
+    offset: u64,
+    ranges: &'a [(u64, Vec<u8>)],
+    decompressed: Option<&'a DecompressedChunks>,
+    budget: Option<&'a DecompressionBudget>,
+    charged: Option<&'a AtomicUsize>,
+}
+
+impl<'a> FileBytes<'a> {
+    pub(super) fn window(bytes: &'a [u8], offset: u64) -> Self {
+        FileBytes {
+            bytes,
+            offset,
+            ranges: &[],
+            decompressed: None,
+            budget: None,
+            charged: None,
+        }
+    }
+
+    /// The `(offset, bytes)` of byte ranges of a file.
+    pub(super) fn ranges(ranges: &'a [(u64, Vec<u8>)]) -> Self {
+        FileBytes {
+            bytes: &[],
+            offset: 0,
+            ranges,
+            decompressed: None,
+            budget: None,
+            charged: None,
+        }
+    }
+
+    /// Decode the column chunks of `decompressed` from their decompressed pages.
+    pub(super) fn with_decompressed(self, decompressed: &'a DecompressedChunks) -> Self {
+        FileBytes {
+            decompressed: Some(decompressed),
+            ..self
+        }
+    }
+
+    /// Fail to decompress pages once they decompress to more bytes than `budget` holds.
+    pub(super) fn with_budget(self, budget: &'a DecompressionBudget) -> Self {
+        FileBytes {
+            budget: Some(budget),
+            ..self
+        }
+    }
+
+    /// Add the bytes that the pages take from the budget to `charged`, also those of pages
+    /// that were decompressed up front.
+    pub(super) fn with_charges(self, charged: &'a AtomicUsize) -> Self {
+        FileBytes {
+            charged: Some(charged),
+            ..self
+        }
+    }
+
+    /// Return `size` bytes to the budget, e.g. the bytes of pages that are decompressed
+    /// again.
+    pub(super) fn release(&self, size: usize) {
+        if let Some(budget) = self.budget {
+            budget.release(size);
+        }
+    }
+
+    /// The bytes of the byte range `start..start + len` of the file, if they are in the
+    /// window or in one of the ranges.
+    fn get(&self, start: u64, len: u64) -> Option<&'a [u8]> {
+        let slice = |bytes: &'a [u8], offset: u64| {
+            let start = usize::try_from(start.checked_sub(offset)?).ok()?;
+            let len = usize::try_from(len).ok()?;
+            bytes.get(start..start.checked_add(len)?)
+        };
+        slice(self.bytes, self.offset).or_else(|| {
+            self.ranges
+                .iter()
+                .find_map(|(offset, bytes)| slice(bytes, *offset))
+        })
+    }
+}
+
+impl<'a> From<&'a [u8]> for FileBytes<'a> {
+    fn from(bytes: &'a [u8]) -> Self {
+        Self::window(bytes, 0)
+    }
+}
 
 /// memory maps all columns that are part of the parquet field `field_name`
 pub(super) fn mmap_columns<'a>(
-    file: &'a [u8],
+    file: FileBytes<'a>,
     columns: &'a [ColumnChunkMetaData],
     field_name: &str,
-) -> Vec<(&'a ColumnChunkMetaData, &'a [u8])> {
+) -> Result<Vec<(&'a ColumnChunkMetaData, &'a [u8])>> {
     get_field_columns(columns, field_name)
         .into_iter()
         .map(|meta| _mmap_single_column(file, meta))
@@ -18,12 +118,260 @@ pub(super) fn mmap_columns<'a>(
 }
 
 fn _mmap_single_column<'a>(
-    file: &'a [u8],
+    file: FileBytes<'a>,
     meta: &'a ColumnChunkMetaData,
-) -> (&'a ColumnChunkMetaData, &'a [u8]) {
+) -> Result<(&'a ColumnChunkMetaData, &'a [u8])> {
     let (start, len) = meta.byte_range();
-    let chunk = &file[start as usize..(start + len) as usize];
-    (meta, chunk)
+    let chunk = file.get(start, len).ok_or_else(|| {
+        PolarsError::ComputeError(
+            format!(
+                "column chunk at byte range {}..{} exceeds the file size of {} bytes",
+                start,
+                start.saturating_add(len),
+                file.offset + file.bytes.len() as u64
+            )
+            .into(),
+        )
+    })?;
+    Ok((meta, chunk))
+}
+
+/// The number of bytes that the pages of a read may decompress to, shared by all column
+/// chunks of the read.
+pub(super) struct DecompressionBudget {
+    max: usize,
+    used: AtomicUsize,
+}
+
+impl DecompressionBudget {
+    pub(super) fn new(max: usize) -> Self {
+        DecompressionBudget {
+            max,
+            used: AtomicUsize::new(0),
+        }
+    }
+
+    /// Take `size` bytes of the budget. A page doesn't decompress to more bytes than its
+    /// header declares, so this is checked before the page is decompressed.
+    fn take(&self, size: usize) -> std::result::Result<(), ParquetError> {
+        let used = self
+            .used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                Some(used.saturating_add(size))
+            })
+            .unwrap_or_default();
+        if used.saturating_add(size) > self.max {
+            Err(ParquetError::General(format!(
+                "the pages decompress to more than the maximum of {} bytes",
+                self.max
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn release(&self, size: usize) {
+        let _ = self
+            .used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                Some(used.saturating_sub(size))
+            });
+    }
+}
+
+/// The compressed pages of a column chunk, of which the decompressed size is taken from
+/// the budget.
+pub(super) struct BudgetedPages<'a> {
+    pages: PageReader<Cursor<&'a [u8]>>,
+    budget: Option<&'a DecompressionBudget>,
+    charged: Option<&'a AtomicUsize>,
+}
+
+impl<'a> BudgetedPages<'a> {
+    fn new(chunk: &'a [u8], meta: &ColumnChunkMetaData, file: FileBytes<'a>) -> Self {
+        BudgetedPages {
+            pages: PageReader::new(
+                Cursor::new(chunk),
+                meta,
+                std::sync::Arc::new(|_, _| true),
+                vec![],
+            ),
+            budget: file.budget,
+            charged: file.charged,
+        }
+    }
+}
+
+impl<'a> Iterator for BudgetedPages<'a> {
+    type Item = std::result::Result<CompressedDataPage, ParquetError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let page = self.pages.next()?;
+        Some(page.and_then(|page| {
+            if let Some(budget) = self.budget {
+                if let Some(charged) = self.charged {
+                    charged.fetch_add(page.uncompressed_size(), Ordering::Relaxed);
+                }
+                budget.take(page.uncompressed_size())?;
+            }
+            Ok(page)
+        }))
+    }
+}
+
+/// The decompressed pages of column chunks and the bytes they took from the budget, by
+/// the offset of the column chunk.
+#[derive(Default)]
+pub(super) struct DecompressedChunks(Mutex<PlHashMap<u64, (Vec<DataPage>, usize)>>);
+
+impl DecompressedChunks {
+    /// Take the pages of the column chunk at `offset`, so that a column chunk that is
+    /// decoded again is decoded from its bytes.
+    fn take(&self, offset: u64) -> Option<(Vec<DataPage>, usize)> {
+        self.0.lock().unwrap().remove(&offset)
+    }
+}
+
+/// Decompress the pages of all columns that are part of the parquet field `field_name`.
+/// Every page is decompressed into a buffer of `pool`, which is returned to it once the
+/// page is decoded.
+pub(super) fn decompress_columns(
+    file: FileBytes,
+    columns: &[ColumnChunkMetaData],
+    field_name: &str,
+    pool: Option<&DecompressionPool>,
+) -> Result<DecompressedChunks> {
+    let mut chunks = PlHashMap::new();
+    for (meta, chunk) in mmap_columns(file, columns, field_name)? {
+        let charged = AtomicUsize::new(0);
+        let pages = BudgetedPages::new(chunk, meta, file.with_charges(&charged))
+            .map(|page| {
+                let mut buffer = pool.map(|pool| pool.take()).unwrap_or_default();
+                page.and_then(|page| decompress(page, &mut buffer))
+            })
+            .collect::<std::result::Result<Vec<_>, ParquetError>>()
+            .map_err(arrow::error::Error::from)?;
+        chunks.insert(meta.byte_range().0, (pages, charged.into_inner()));
+    }
+    Ok(DecompressedChunks(Mutex::new(chunks)))
+}
+
+/// The pages of a column chunk that were decompressed up front. The buffers of the pages
+/// are returned to the pool once they are decoded.
+pub(super) struct DecompressedPages<'a> {
+    pages: std::vec::IntoIter<DataPage>,
+    current: Option<DataPage>,
+    pool: Option<&'a DecompressionPool>,
+}
+
+impl<'a> DecompressedPages<'a> {
+    fn recycle(&self, mut page: DataPage) {
+        if let Some(pool) = self.pool {
+            pool.put(std::mem::take(page.buffer_mut()));
+        }
+    }
+}
+
+impl<'a> FallibleStreamingIterator for DecompressedPages<'a> {
+    type Item = DataPage;
+    type Error = ParquetError;
+
+    fn advance(&mut self) -> std::result::Result<(), ParquetError> {
+        let next = self.pages.next();
+        if let Some(page) = std::mem::replace(&mut self.current, next) {
+            self.recycle(page);
+        }
+        Ok(())
+    }
+
+    fn get(&self) -> Option<&DataPage> {
+        self.current.as_ref()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.pages.len(), Some(self.pages.len()))
+    }
+}
+
+impl<'a> Drop for DecompressedPages<'a> {
+    fn drop(&mut self) {
+        if self.pool.is_some() {
+            let pages = self.current.take().into_iter().chain(&mut self.pages);
+            for page in pages.collect::<Vec<_>>() {
+                self.recycle(page);
+            }
+        }
+    }
+}
+
+/// The pages of a column chunk, decompressed while they are decoded or up front.
+pub(super) enum Pages<'a> {
+    Compressed(PooledDecompressor<'a>),
+    Decompressed(DecompressedPages<'a>),
+}
+
+impl<'a> FallibleStreamingIterator for Pages<'a> {
+    type Item = DataPage;
+    type Error = ParquetError;
+
+    fn advance(&mut self) -> std::result::Result<(), ParquetError> {
+        match self {
+            Pages::Compressed(pages) => pages.advance(),
+            Pages::Decompressed(pages) => pages.advance(),
+        }
+    }
+
+    fn get(&self) -> Option<&DataPage> {
+        match self {
+            Pages::Compressed(pages) => pages.get(),
+            Pages::Decompressed(pages) => pages.get(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Pages::Compressed(pages) => pages.size_hint(),
+            Pages::Decompressed(pages) => pages.size_hint(),
+        }
+    }
+}
+
+/// Decompresses the pages of a column chunk and returns the decompression buffer to the
+/// pool once the column chunk is decoded.
+pub(super) struct PooledDecompressor<'a> {
+    inner: Option<BasicDecompressor<BudgetedPages<'a>>>,
+    pool: Option<&'a DecompressionPool>,
+}
+
+impl<'a> FallibleStreamingIterator for PooledDecompressor<'a> {
+    type Item = DataPage;
+    type Error = ParquetError;
+
+    fn advance(&mut self) -> std::result::Result<(), ParquetError> {
+        match &mut self.inner {
+            Some(inner) => inner.advance(),
+            None => Ok(()),
+        }
+    }
+
+    fn get(&self) -> Option<&DataPage> {
+        self.inner.as_ref().and_then(|inner| inner.get())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            Some(inner) => inner.size_hint(),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<'a> Drop for PooledDecompressor<'a> {
+    fn drop(&mut self) {
+        if let (Some(inner), Some(pool)) = (self.inner.take(), self.pool) {
+            pool.put(inner.into_inner());
+        }
+    }
 }
 
 // similar to arrow2 serializer, except this accepts a slice instead of a vec.
@@ -33,24 +381,52 @@ pub(super) fn to_deserializer<'a>(
     field: Field,
     num_rows: usize,
     chunk_size: Option<usize>,
+    pool: Option<&'a DecompressionPool>,
+    file: FileBytes<'a>,
 ) -> ArrowResult<ArrayIter<'a>> {
     let chunk_size = chunk_size.unwrap_or(usize::MAX).min(num_rows);
 
-    let (columns, types): (Vec<_>, Vec<_>) = columns
-        .into_iter()
-        .map(|(column_meta, chunk)| {
-            let pages = PageReader::new(
-                std::io::Cursor::new(chunk),
-                column_meta,
-                std::sync::Arc::new(|_, _| true),
-                vec![],
-            );
-            (
-                BasicDecompressor::new(pages, vec![]),
-                &column_meta.descriptor().descriptor.primitive_type,
-            )
-        })
-        .unzip();
+    let types = columns
+        .iter()
+        .map(|(column_meta, _)| &column_meta.descriptor().descriptor.primitive_type)
+        .collect();
+    let columns = column_pages(&columns, pool, file);
 
     column_iter_to_arrays(columns, types, field, Some(chunk_size))
 }
+
+/// The pages of the column chunks `columns`.
+pub(super) fn column_pages<'a>(
+    columns: &[(&ColumnChunkMetaData, &'a [u8])],
+    pool: Option<&'a DecompressionPool>,
+    file: FileBytes<'a>,
+) -> Vec<Pages<'a>> {
+    columns
+        .iter()
+        .map(|(column_meta, chunk)| {
+            let decompressed = file
+                .decompressed
+                .and_then(|d| d.take(column_meta.byte_range().0));
+            match decompressed {
+                Some((pages, charged)) => {
+                    if let Some(total) = file.charged {
+                        total.fetch_add(charged, Ordering::Relaxed);
+                    }
+                    Pages::Decompressed(DecompressedPages {
+                        pages: pages.into_iter(),
+                        current: None,
+                        pool,
+                    })
+                }
+                None => {
+                    let pages = BudgetedPages::new(chunk, column_meta, file);
+                    let buffer = pool.map(|pool| pool.take()).unwrap_or_default();
+                    Pages::Compressed(PooledDecompressor {
+                        inner: Some(BasicDecompressor::new(pages, buffer)),
+                        pool,
+                    })
+                }
+            }
+        })
+        .collect()
+}
@@ -14,18 +14,57 @@
 //! }
 //! ```
 //!
+mod boolean;
+mod dataset;
+mod deferred;
+mod describe;
+#[cfg(test)]
+mod fault;
+mod fingerprint;
+mod interval;
+#[cfg(all(feature = "json", feature = "dtype-struct"))]
+mod json_column;
+mod metadata_column;
 pub(super) mod mmap;
+mod pipeline;
+mod pool;
 pub mod predicates;
+mod pruned;
 mod read;
 mod read_impl;
+mod sample;
+mod source;
+mod spec;
+mod validate;
 mod write;
 
 use super::*;
+pub use dataset::ParquetDataset;
+pub use deferred::DeferredParquetFrame;
+pub use describe::parquet_describe;
+pub use fingerprint::ReadFingerprint;
+pub use interval::ParquetInterval;
+#[cfg(all(feature = "json", feature = "dtype-struct"))]
+pub use json_column::{JsonExpansion, JsonMismatch};
+pub use metadata_column::{MetadataColumn, MetadataField};
+pub use pipeline::DecodePipeline;
+pub use pool::DecompressionPool;
+pub use pruned::PrunedRowGroups;
 pub use read::*;
+pub use read_impl::{
+    column_chunk_locations, column_encodings, read_column_dictionary, read_parquet_column,
+    resolve_row_count, ColumnChunkLocation, ColumnError, ConstraintViolation, OnSeries, PostDecode,
+    PruneReason, ResolvedRowCount, RowGroupChunks, RowGroupDecision,
+};
+pub use sample::{SampleSize, SampleSpec};
+pub use source::{ParquetSource, SourceReader};
+pub use spec::{ColumnSource, OverflowBehavior, ReadSpec};
+pub use validate::{validate_parquet, ValidationReport};
 pub use write::*;
 
 #[cfg(test)]
 mod test {
+    use crate::parquet::read_impl::ReadDiagnostics;
     use crate::prelude::*;
     use polars_core::{df, prelude::*};
     use std::fs::File;
@@ -66,6 +105,76 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg(all(feature = "dtype-datetime", feature = "parquet"))]
+    fn test_parquet_datetime_tz_round_trip() -> Result<()> {
+        let mut f = Cursor::new(vec![]);
+
+        let dtype = DataType::Datetime(TimeUnit::Microseconds, Some("Europe/Paris".into()));
+        let mut df = df![
+            "datetime" => [Some(191845729i64), Some(89107598), None, Some(3158971092)]
+        ]?;
+        df.try_apply("datetime", |s| s.cast(&dtype))?;
+
+        ParquetWriter::new(&mut f).finish(&mut df)?;
+        f.set_position(0);
+
+        let read = ParquetReader::new(f).finish()?;
+        assert_eq!(read.column("datetime")?.dtype(), &dtype);
+        assert!(read.frame_equal_missing(&df));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "dtype-categorical",
+        feature = "dtype-datetime",
+        feature = "dtype-date",
+        feature = "dtype-time"
+    ))]
+    fn test_parquet_arrow_schema_round_trip() -> Result<()> {
+        use arrow::io::parquet::read;
+
+        let datetime = DataType::Datetime(TimeUnit::Milliseconds, Some("Europe/Paris".into()));
+        let mut df = df![
+            "categorical" => ["a", "b", "a"],
+            "datetime" => [1i64, 2, 3],
+            "date" => [1i32, 2, 3],
+            "time" => [1i64, 2, 3]
+        ]?;
+        df.try_apply("categorical", |s| s.cast(&DataType::Categorical(None)))?;
+        df.try_apply("datetime", |s| s.cast(&datetime))?;
+        df.try_apply("date", |s| s.cast(&DataType::Date))?;
+        df.try_apply("time", |s| s.cast(&DataType::Time))?;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        // the writer embeds the arrow schema in the footer
+        let metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+        let has_schema = metadata
+            .key_value_metadata
+            .as_ref()
+            .map_or(false, |kv| kv.iter().any(|kv| kv.key == "ARROW:schema"));
+        assert!(has_schema);
+
+        let out = ParquetReader::new(Cursor::new(bytes.clone())).finish()?;
+        assert_eq!(out.schema(), df.schema());
+        for (l, r) in out.get_columns().iter().zip(df.get_columns()) {
+            assert!(l
+                .cast(&DataType::Utf8)?
+                .series_equal(&r.cast(&DataType::Utf8)?));
+        }
+
+        // the time zone is not stored in the parquet types
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .use_arrow_metadata(false)
+            .finish()?;
+        assert_ne!(out.column("datetime")?.dtype(), &datetime);
+        Ok(())
+    }
+
     #[test]
     fn test_read_parquet_with_projection() {
         let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
@@ -103,4 +212,3641 @@ mod test {
         assert_eq!(df_read.shape(), (3, 2));
         df_read.frame_equal(&expected);
     }
+
+    #[test]
+    fn test_read_parquet_column() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4, 5, 6], "b" => ["a", "b", "c", "d", "e", "f"])?;
+
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        buf.set_position(0);
+        let s = read_parquet_column(buf.clone(), "b", None, None)?;
+        assert!(s.series_equal(df.column("b")?));
+
+        let s = read_parquet_column(buf, "a", Some(3), None)?;
+        assert!(s.series_equal(&df.column("a")?.slice(0, 3)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_row_group_bounds() -> Result<()> {
+        use arrow::io::parquet::read;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4], "b" => ["a", "b", "c", "d"])?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        buf.set_position(0);
+        let metadata = read::read_metadata(&mut buf)?;
+        let schema = read::infer_schema(&metadata)?;
+        let bounds = crate::parquet::predicates::row_group_bounds(&metadata, &schema)?;
+
+        // the distinct count is optional and not written by polars
+        let stats = crate::parquet::predicates::FileStatistics::collect(&metadata, &schema)?;
+        let a = stats.row_group(0).unwrap().get_stats("a")?;
+        assert_eq!(a.null_count(), Some(0));
+        assert_eq!(a.distinct_count(), None);
+
+        assert_eq!(bounds.len(), 2);
+        let (min, max) = &bounds[1]["a"];
+        assert_eq!((min, max), (&AnyValue::Int32(3), &AnyValue::Int32(4)));
+        match &bounds[0]["b"] {
+            (AnyValue::Utf8Owned(min), AnyValue::Utf8Owned(max)) => {
+                assert_eq!((min.as_str(), max.as_str()), ("a", "b"))
+            }
+            _ => panic!("expected string bounds"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-date", feature = "dtype-datetime"))]
+    fn test_row_group_bounds_temporal() -> Result<()> {
+        use arrow::io::parquet::read;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = DataFrame::new(vec![
+            Int32Chunked::new("d", &[19001, 19000, 19003, 19002])
+                .into_date()
+                .into_series(),
+            Int64Chunked::new("t", &[2_000i64, 1_000, 4_000, 3_000])
+                .into_datetime(TimeUnit::Milliseconds, None)
+                .into_series(),
+        ])?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        buf.set_position(0);
+        let metadata = read::read_metadata(&mut buf)?;
+        let schema = read::infer_schema(&metadata)?;
+        let bounds = crate::parquet::predicates::row_group_bounds(&metadata, &schema)?;
+        assert_eq!(
+            bounds[1]["d"],
+            (AnyValue::Date(19002), AnyValue::Date(19003))
+        );
+        assert_eq!(
+            bounds[0]["t"],
+            (
+                AnyValue::Datetime(1_000, TimeUnit::Milliseconds, &None),
+                AnyValue::Datetime(2_000, TimeUnit::Milliseconds, &None)
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_non_seekable() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3], "b" => [2, 3, 4])?;
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+
+        // `&[u8]` implements `Read` but not `Seek`
+        let source: &[u8] = buf.get_ref();
+        let read = ParquetReader::from_non_seekable(source, NonSeekableBuffer::Memory)?.finish()?;
+        assert!(read.frame_equal(&df));
+        Ok(())
+    }
+
+    /// Predicate `column > value` for tests that need a `PhysicalIoExpr`.
+    #[cfg(feature = "lazy")]
+    struct ColumnGt(&'static str, i32);
+
+    #[cfg(feature = "lazy")]
+    impl crate::predicates::PhysicalIoExpr for ColumnGt {
+        fn evaluate(&self, df: &DataFrame) -> Result<Series> {
+            Ok(df.column(self.0)?.gt(self.1)?.into_series())
+        }
+
+        fn display(&self) -> Option<String> {
+            Some(format!("{} > {}", self.0, self.1))
+        }
+
+        fn as_stats_evaluator(&self) -> Option<&dyn crate::predicates::StatsEvaluator> {
+            Some(self)
+        }
+    }
+
+    #[cfg(feature = "lazy")]
+    impl crate::predicates::StatsEvaluator for ColumnGt {
+        fn evaluate_stats(
+            &self,
+            stats: &crate::parquet::predicates::BatchStats,
+        ) -> Result<crate::predicates::StatsEvaluation> {
+            use crate::predicates::StatsEvaluation;
+
+            let stats = stats.get_stats(self.0)?;
+            Ok(match stats.to_min_max_values() {
+                Some((_, AnyValue::Int32(max))) if max <= self.1 => {
+                    StatsEvaluation::DefinitelyFalse
+                }
+                Some((AnyValue::Int32(min), _))
+                    if min > self.1 && stats.null_count() == Some(0) =>
+                {
+                    StatsEvaluation::DefinitelyTrue
+                }
+                _ => StatsEvaluation::Unknown,
+            })
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn test_read_parquet_limit_after_predicate() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4, 5, 6])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        let bytes = buf.into_inner();
+        let read = |limit_after_predicate| {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_n_rows(Some(2))
+                .limit_after_predicate(limit_after_predicate)
+                ._finish_with_scan_ops(Some(Arc::new(ColumnGt("a", 2))), None, None)
+        };
+
+        let out = read(true)?;
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(3), Some(4)]);
+        // the limit is reached in the first row group, which has no matching rows
+        let out = read(false)?;
+        assert_eq!(out.height(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_cached_metadata_and_statistics() -> Result<()> {
+        use crate::parquet::predicates::FileStatistics;
+        use arrow::io::parquet::read;
+
+        let write = |df: &mut DataFrame| -> Result<Vec<u8>> {
+            let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+            ParquetWriter::new(&mut buf)
+                .with_statistics(true)
+                .with_row_group_size(Some(2))
+                .finish(df)?;
+            Ok(buf.into_inner())
+        };
+        let mut df = df!("a" => [1, 2, 3, 4])?;
+        let bytes = write(&mut df)?;
+
+        let metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+        let schema = read::infer_schema(&metadata)?;
+        let statistics = Arc::new(FileStatistics::collect(&metadata, &schema)?);
+        assert_eq!(statistics.num_row_groups(), 2);
+
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .with_metadata(Some(metadata))
+            .with_cached_statistics(Some(statistics.clone()))
+            .finish()?;
+        assert!(out.frame_equal(&df));
+
+        // statistics of another file are rejected
+        let bytes = write(&mut df!("a" => [1, 2, 3, 4, 5, 6])?)?;
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .with_cached_statistics(Some(statistics))
+            .finish();
+        assert!(out.is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_enum_to_dictionary() -> Result<()> {
+        use crate::parquet::read::enum_to_dictionary;
+        use arrow::datatypes::{DataType as ArrowDataType, Field};
+        use arrow::io::parquet::read::{ParquetType, PrimitiveLogicalType};
+        use arrow::io::parquet::write::to_parquet_type;
+
+        let mut schema = ArrowSchema::from(vec![
+            Field::new("enum", ArrowDataType::Utf8, true),
+            Field::new("str", ArrowDataType::Utf8, true),
+        ]);
+        let mut parquet_fields = schema
+            .fields
+            .iter()
+            .map(to_parquet_type)
+            .collect::<crate::ArrowResult<Vec<_>>>()?;
+        if let ParquetType::PrimitiveType(primitive) = &mut parquet_fields[0] {
+            primitive.logical_type = Some(PrimitiveLogicalType::Enum);
+        }
+
+        enum_to_dictionary(&parquet_fields, &mut schema);
+        let schema = Schema::from(&schema.fields);
+        assert_eq!(schema.get("enum"), Some(&DataType::Categorical(None)));
+        assert_eq!(schema.get("str"), Some(&DataType::Utf8));
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_to_utf8() -> Result<()> {
+        use crate::parquet::read::json_to_utf8;
+        use arrow::datatypes::{DataType as ArrowDataType, Field};
+        use arrow::io::parquet::read::{ParquetType, PrimitiveLogicalType};
+        use arrow::io::parquet::write::to_parquet_type;
+
+        let mut schema = ArrowSchema::from(vec![
+            Field::new("json", ArrowDataType::Binary, true),
+            Field::new("bson", ArrowDataType::Binary, true),
+        ]);
+        let mut parquet_fields = schema
+            .fields
+            .iter()
+            .map(to_parquet_type)
+            .collect::<crate::ArrowResult<Vec<_>>>()?;
+        for (fld, logical_type) in parquet_fields
+            .iter_mut()
+            .zip([PrimitiveLogicalType::Json, PrimitiveLogicalType::Bson])
+        {
+            if let ParquetType::PrimitiveType(primitive) = fld {
+                primitive.logical_type = Some(logical_type);
+            }
+        }
+
+        json_to_utf8(&parquet_fields, &mut schema);
+        assert_eq!(schema.fields[0].data_type, ArrowDataType::Utf8);
+        assert_eq!(schema.fields[1].data_type, ArrowDataType::Binary);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_malformed_metadata() -> Result<()> {
+        use arrow::io::parquet::read::{self, RowGroupMetaData};
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4], "b" => ["a", "b", "c", "d"])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+
+        let read_with = |map: &dyn Fn(&RowGroupMetaData) -> RowGroupMetaData| {
+            let mut metadata = metadata.clone();
+            metadata.row_groups = metadata.row_groups.iter().map(map).collect();
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_metadata(Some(metadata))
+                .finish()
+        };
+
+        // a bogus number of rows
+        assert!(read_with(&|md| {
+            RowGroupMetaData::new(md.columns().to_vec(), usize::MAX, md.total_byte_size())
+        })
+        .is_err());
+        // a missing column chunk
+        assert!(read_with(&|md| {
+            RowGroupMetaData::new(
+                md.columns()[..1].to_vec(),
+                md.num_rows(),
+                md.total_byte_size(),
+            )
+        })
+        .is_err());
+        // column chunks outside of the file
+        let mut truncated = bytes.clone();
+        truncated.truncate(16);
+        let out = ParquetReader::new(Cursor::new(truncated))
+            .with_metadata(Some(metadata.clone()))
+            .finish();
+        assert!(out.is_err());
+
+        // more values than the pages of a column chunk can hold
+        assert!(read_with(&|md| {
+            let columns = md
+                .columns()
+                .iter()
+                .map(|column| {
+                    let mut chunk = column.column_chunk().clone();
+                    chunk.meta_data.as_mut().unwrap().num_values = i64::MAX;
+                    read::ColumnChunkMetaData::try_from_thrift(column.descriptor().clone(), chunk)
+                        .unwrap()
+                })
+                .collect();
+            RowGroupMetaData::new(columns, md.num_rows(), md.total_byte_size())
+        })
+        .is_err());
+
+        // corrupting any byte of the page headers, the pages or the footer never panics
+        for i in 4..bytes.len() - 4 {
+            for flip in [0x01, 0x40] {
+                let mut corrupted = bytes.clone();
+                corrupted[i] ^= flip;
+                let result = std::panic::catch_unwind(|| {
+                    ParquetReader::new(Cursor::new(corrupted))
+                        .with_max_decompressed_bytes(Some(1 << 20))
+                        .finish()
+                        .is_ok()
+                });
+                assert!(result.is_ok(), "panicked on corrupted byte {}", i);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn test_resolve_row_count() -> Result<()> {
+        use arrow::io::parquet::read;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4, 5, 6])?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        buf.set_position(0);
+        let metadata = read::read_metadata(&mut buf)?;
+        let schema = read::infer_schema(&metadata)?;
+        let resolve = |value, limit| {
+            let predicate: Arc<dyn crate::predicates::PhysicalIoExpr> =
+                Arc::new(ColumnGt("a", value));
+            resolve_row_count(&metadata, &schema, Some(&predicate), limit)
+        };
+
+        // the first row group is pruned, the others pass entirely
+        let count = resolve(2, None)?;
+        assert_eq!(
+            count,
+            ResolvedRowCount {
+                num_rows: 4,
+                is_exact: true
+            }
+        );
+        let count = resolve(2, Some(3))?;
+        assert_eq!(count.num_rows, 3);
+        // the second row group may partly pass
+        let count = resolve(3, None)?;
+        assert_eq!(
+            count,
+            ResolvedRowCount {
+                num_rows: 4,
+                is_exact: false
+            }
+        );
+        let count = resolve_row_count(&metadata, &schema, None, None)?;
+        assert_eq!(count.num_rows, 6);
+        assert!(count.is_exact);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_null_type() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = DataFrame::new(vec![
+            Series::new("a", &[1, 2, 3, 4]),
+            Series::full_null("n", 4, &DataType::Null),
+        ])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        let out = ParquetReader::new(Cursor::new(bytes.clone())).finish()?;
+        let n = out.column("n")?;
+        assert_eq!(n.dtype(), &DataType::Null);
+        assert_eq!((n.len(), n.null_count()), (4, 4));
+
+        let dtypes = Schema::from(vec![Field::new("n", DataType::Utf8)]);
+        let out = ParquetReader::new(Cursor::new(bytes.clone()))
+            .with_null_column_dtypes(Some(dtypes))
+            .with_n_rows(Some(3))
+            .finish()?;
+        let n = out.column("n")?;
+        assert_eq!(n.dtype(), &DataType::Utf8);
+        assert_eq!((n.len(), n.null_count()), (3, 3));
+
+        let n = read_parquet_column(Cursor::new(bytes), "n", None, None)?;
+        assert_eq!(n.len(), 4);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_read_parquet_max_categories() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => ["a", "b", "c", "a"])?;
+        df.try_apply("a", |s| s.cast(&DataType::Categorical(None)))?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let read = |max_categories, overflow| {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_max_categories(Some(max_categories), overflow)
+                .finish()
+        };
+
+        let out = read(3, CategoricalOverflow::Error)?;
+        assert!(matches!(out.column("a")?.dtype(), DataType::Categorical(_)));
+        let out = read(2, CategoricalOverflow::Utf8)?;
+        assert_eq!(out.column("a")?.dtype(), &DataType::Utf8);
+        assert!(read(2, CategoricalOverflow::Error).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_unify_categorical_fallback() -> Result<()> {
+        use crate::parquet::read_impl::unify_categorical_fallback;
+
+        // the second row group exceeded the maximum number of categories
+        let categorical = Series::new("a", &["a", "a"]).cast(&DataType::Categorical(None))?;
+        let mut dfs = vec![
+            DataFrame::new(vec![categorical])?,
+            DataFrame::new(vec![Series::new("a", &["b", "c"])])?,
+        ];
+        unify_categorical_fallback(&mut dfs)?;
+        let out = polars_core::utils::accumulate_dataframes_vertical(dfs)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.utf8()?),
+            &[Some("a"), Some("a"), Some("b"), Some("c")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn test_read_parquet_fingerprint() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4, 5, 6], "b" => [1, 2, 3, 4, 5, 6])?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let read = |columns: &[&str], value, n_rows| {
+            let columns = columns.iter().map(|s| s.to_string()).collect();
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_columns(Some(columns))
+                .with_n_rows(n_rows)
+                .finish_with_fingerprint(Some(Arc::new(ColumnGt("a", value))))
+                .map(|(_, fingerprint)| fingerprint)
+        };
+
+        let fingerprint = read(&["a"], 2, None)?;
+        assert_eq!(fingerprint, read(&["a"], 2, None)?);
+        assert_ne!(fingerprint, read(&["a", "b"], 2, None)?);
+        // prunes an additional row group
+        assert_ne!(fingerprint, read(&["a"], 4, None)?);
+        assert_ne!(fingerprint, read(&["a"], 2, Some(3))?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_read_spec() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3], "b" => ["x", "y", "z"], "c" => [1.0, 2.0, 3.0])?;
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+
+        let spec = ReadSpec::new()
+            .with_column(ColumnSource::Name("c".into()), None, Some(DataType::Int64))
+            .with_column(ColumnSource::Name("a".into()), Some("a_renamed"), None);
+        let out = ParquetReader::new(Cursor::new(buf.into_inner()))
+            .with_read_spec(Some(spec))
+            .with_row_count(Some(crate::RowCount {
+                name: "row_nr".into(),
+                offset: 0,
+            }))
+            .finish()?;
+
+        assert_eq!(out.get_column_names(), &["row_nr", "c", "a_renamed"]);
+        assert_eq!(out.column("c")?.dtype(), &DataType::Int64);
+        assert_eq!(
+            Vec::from(out.column("a_renamed")?.i32()?),
+            &[Some(1), Some(2), Some(3)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_column_lengths() -> Result<()> {
+        use crate::parquet::read_impl::check_column_lengths;
+
+        let columns = || vec![Series::new("a", &[1, 2, 3]), Series::new("b", &[1])];
+        let err = check_column_lengths(columns(), 2, false).unwrap_err();
+        assert!(err.to_string().contains("row group 2"));
+
+        let padded = check_column_lengths(columns(), 2, true)?;
+        assert_eq!(padded[1].len(), 3);
+        assert_eq!(padded[1].null_count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_row_counts() -> Result<()> {
+        use crate::parquet::read_impl::verify_row_counts;
+        use arrow::io::parquet::read::{self, RowGroupMetaData};
+
+        let columns = vec![Series::new("a", &[1, 2, 3]), Series::new("b", &[1, 2])];
+        let err = verify_row_counts(&columns, 1, 3).unwrap_err();
+        assert!(err.to_string().contains("'b': 2"));
+        assert!(!err.to_string().contains("'a'"));
+        assert!(verify_row_counts(&columns[..1], 1, 3).is_ok());
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        // metadata that declares more rows than the pages hold
+        let mut metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+        let md = &metadata.row_groups[1];
+        metadata.row_groups[1] =
+            RowGroupMetaData::new(md.columns().to_vec(), 3, md.total_byte_size());
+        metadata.num_rows = 5;
+        let read = |verify| {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_metadata(Some(metadata.clone()))
+                .verify_row_counts(verify)
+                .finish()
+        };
+        let err = read(true).unwrap_err();
+        assert!(err.to_string().contains("row group 1 should have 3 rows"));
+        assert!(read(false).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn test_read_parquet_prune_only() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4, 5, 6])?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let read = |prune_only| {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .prune_only(prune_only)
+                ._finish_with_scan_ops(Some(Arc::new(ColumnGt("a", 3))), None, None)
+        };
+
+        // the first row group is pruned, the rows of the others are not filtered
+        let out = read(true)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(3), Some(4), Some(5), Some(6)]
+        );
+        let out = read(false)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(4), Some(5), Some(6)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_column_encodings() -> Result<()> {
+        use arrow::io::parquet::read;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4], "b" => ["a", "b", "c", "d"])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        buf.set_position(0);
+        let metadata = read::read_metadata(&mut buf)?;
+        let encodings = column_encodings(&metadata, Some(&[1]))?;
+        assert_eq!(encodings.len(), 2);
+        assert_eq!(encodings[0].len(), 1);
+        assert!(!encodings[0][0].is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_single_row_group() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4])?;
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        let out = ParquetReader::new(Cursor::new(bytes.clone())).finish()?;
+        assert!(out.frame_equal(&df));
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .with_n_rows(Some(2))
+            .with_row_count(Some(crate::RowCount {
+                name: "row_nr".into(),
+                offset: 10,
+            }))
+            .finish()?;
+        assert_eq!(out.shape(), (2, 2));
+        assert_eq!(
+            Vec::from(out.column("row_nr")?.idx()?),
+            &[Some(10), Some(11)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_sample() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => (0..20).collect::<Vec<i32>>())?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let read = |size, seed| {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_sample(Some(SampleSpec::new(size, seed)))
+                .with_row_count(Some(crate::RowCount {
+                    name: "row_nr".into(),
+                    offset: 0,
+                }))
+                .finish()
+        };
+
+        let out = read(SampleSize::RowGroups(3), 42)?;
+        assert_eq!(out.height(), 6);
+        assert!(out.frame_equal(&read(SampleSize::RowGroups(3), 42)?));
+        // the row count is the position in the file
+        let row_nr = out.column("row_nr")?.cast(&DataType::Int32)?;
+        assert!(row_nr.series_equal(out.column("a")?));
+
+        let out = read(SampleSize::Rows(5), 1)?;
+        assert_eq!(out.height(), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_allow_missing_columns() -> Result<()> {
+        use arrow::io::parquet::read::{self, RowGroupMetaData};
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4], "b" => [1, 2, 3, 4])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        // the first row group lacks column "b"
+        let mut metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+        let md = &metadata.row_groups[0];
+        metadata.row_groups[0] = RowGroupMetaData::new(
+            md.columns()[..1].to_vec(),
+            md.num_rows(),
+            md.total_byte_size(),
+        );
+
+        let read = |allow_missing_columns| {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_metadata(Some(metadata.clone()))
+                .allow_missing_columns(allow_missing_columns)
+                .finish()
+        };
+        assert!(read(false).is_err());
+        let out = read(true)?;
+        assert_eq!(
+            Vec::from(out.column("b")?.i32()?),
+            &[None, None, Some(3), Some(4)]
+        );
+        assert_eq!(out.column("a")?.null_count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_schema_order_differs_from_file() -> Result<()> {
+        use arrow::io::parquet::read;
+        use arrow::io::parquet::write::schema_to_metadata_key;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2], "b" => ["x", "y"], "c" => [1.0, 2.0])?;
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        // store an arrow schema with the fields in reversed order
+        let mut metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+        let mut schema = read::infer_schema(&metadata)?;
+        schema.fields.reverse();
+        metadata.key_value_metadata = Some(vec![schema_to_metadata_key(&schema)]);
+
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .with_metadata(Some(metadata))
+            .with_projection(Some(vec![0, 2]))
+            .finish()?;
+        assert_eq!(out.get_column_names(), &["c", "a"]);
+        assert!(out.column("a")?.series_equal(df.column("a")?));
+        assert!(out.column("c")?.series_equal(df.column("c")?));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn test_read_parquet_row_count_with_pruned_row_groups() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        // every other row group is pruned by `a > 9`
+        let mut df = df!("a" => [1, 2, 10, 11, 3, 4, 12, 13, 5, 6, 14, 15])?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        for offset in [0, 7] {
+            let out = ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_row_count(Some(crate::RowCount {
+                    name: "row_nr".into(),
+                    offset,
+                }))
+                ._finish_with_scan_ops(Some(Arc::new(ColumnGt("a", 9))), None, None)?;
+
+            let expected = [2, 3, 6, 7, 10, 11]
+                .iter()
+                .map(|i| Some(i + offset))
+                .collect::<Vec<_>>();
+            assert_eq!(Vec::from(out.column("row_nr")?.idx()?), expected);
+            // the row count is the physical row index in the file
+            for (row_nr, a) in out
+                .column("row_nr")?
+                .idx()?
+                .into_iter()
+                .zip(out.column("a")?.i32()?)
+            {
+                let physical = df.column("a")?.get((row_nr.unwrap() - offset) as usize);
+                assert_eq!(physical, AnyValue::Int32(a.unwrap()));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_column_ranges() -> Result<()> {
+        use crate::parquet::predicates::ColumnRanges;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4, 5, 6])?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let ranges = |lower: Option<i32>, upper: Option<i32>| {
+            let mut ranges = PlHashMap::new();
+            ranges.insert(
+                "a".to_string(),
+                (lower.map(AnyValue::Int32), upper.map(AnyValue::Int32)),
+            );
+            ColumnRanges(ranges)
+        };
+        let read = |lower, upper| {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_column_ranges(Some(ranges(lower, upper)))
+                .finish()
+        };
+
+        // only row groups are pruned, the rows are not filtered
+        let out = read(Some(3), Some(3))?;
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(3), Some(4)]);
+        let out = read(Some(2), Some(3))?;
+        assert_eq!(out.height(), 4);
+        let out = read(None, Some(2))?;
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(1), Some(2)]);
+        let out = read(Some(7), None)?;
+        assert_eq!(out.height(), 0);
+
+        // the ranges can not be combined with a predicate
+        let out = ParquetReader::new(Cursor::new(bytes.clone()))
+            .with_column_ranges(Some(ranges(Some(3), None)))
+            .finish_with_fingerprint(Some(Arc::new(ranges(None, Some(3)))));
+        assert!(out.is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-date")]
+    fn test_read_parquet_column_ranges_date() -> Result<()> {
+        use crate::parquet::predicates::ColumnRanges;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = DataFrame::new(vec![Int32Chunked::new(
+            "d",
+            &[19000, 19001, 19002, 19003, 19004, 19005],
+        )
+        .into_date()
+        .into_series()])?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let read = |lower: Option<AnyValue<'static>>, upper: Option<AnyValue<'static>>| {
+            let ranges = ColumnRanges(PlHashMap::from_iter([("d".to_string(), (lower, upper))]));
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_column_ranges(Some(ranges))
+                .finish()
+        };
+        let days = |out: &DataFrame| -> Result<Vec<Option<i32>>> {
+            Ok(Vec::from(out.column("d")?.to_physical_repr().i32()?))
+        };
+
+        let out = read(Some(AnyValue::Date(19002)), Some(AnyValue::Date(19003)))?;
+        assert_eq!(days(&out)?, &[Some(19002), Some(19003)]);
+        let out = read(None, Some(AnyValue::Date(19001)))?;
+        assert_eq!(days(&out)?, &[Some(19000), Some(19001)]);
+        let out = read(Some(AnyValue::Date(19006)), None)?;
+        assert_eq!(out.height(), 0);
+        // a bound of the physical dtype compares as the days of the column
+        let out = read(Some(AnyValue::Int32(19004)), None)?;
+        assert_eq!(days(&out)?, &[Some(19004), Some(19005)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_partitioned() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => (0..10).collect::<Vec<i32>>())?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(7))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let read = |n_rows, n| {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_n_rows(n_rows)
+                .finish_partitioned(n)
+        };
+
+        // the partitions don't follow the row groups of 7 rows
+        let parts = read(None, Some(3))?;
+        let heights = parts.iter().map(|df| df.height()).collect::<Vec<_>>();
+        assert_eq!(heights, &[3, 3, 4]);
+        let mut stacked = parts[0].clone();
+        for part in &parts[1..] {
+            stacked.vstack_mut(part)?;
+        }
+        assert!(stacked.frame_equal(&df));
+
+        let parts = read(Some(4), Some(2))?;
+        assert_eq!(Vec::from(parts[1].column("a")?.i32()?), &[Some(2), Some(3)]);
+        assert_eq!(read(None, None)?.len(), 1);
+        assert!(read(None, Some(0)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_parquet() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4], "b" => ["a", "b", "c", "d"])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        let report = validate_parquet(Cursor::new(bytes.clone()), true)?;
+        assert!(report.is_valid(), "{:?}", report.anomalies);
+        assert_eq!(report.num_row_groups, 2);
+        assert_eq!(report.num_rows, 4);
+
+        let report = validate_parquet(Cursor::new(bytes[..bytes.len() - 1].to_vec()), false)?;
+        assert!(!report.is_valid());
+
+        // corrupt the data of the first column chunk, which only decoding detects
+        let mut corrupt = bytes.clone();
+        for b in &mut corrupt[4..12] {
+            *b = 0xff;
+        }
+        assert!(validate_parquet(Cursor::new(corrupt.clone()), false)?.is_valid());
+        assert!(!validate_parquet(Cursor::new(corrupt), true)?.is_valid());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_chunk_lengths() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => (0..10).collect::<Vec<i32>>(), "b" => [1.0; 10])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(4))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let read = |lengths| {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_chunk_lengths(Some(lengths))
+                .finish()
+        };
+
+        let out = read(vec![3, 2, 5])?;
+        for s in out.get_columns() {
+            assert_eq!(s.chunk_lengths().collect::<Vec<_>>(), &[3, 2, 5]);
+        }
+        assert!(out.frame_equal(&df));
+
+        let out = read(vec![6])?;
+        assert_eq!(
+            out.column("a")?.chunk_lengths().collect::<Vec<_>>(),
+            &[6, 4]
+        );
+        let out = read(vec![8, 8])?;
+        assert_eq!(
+            out.column("a")?.chunk_lengths().collect::<Vec<_>>(),
+            &[8, 2]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn test_read_parquet_post_predicate() -> Result<()> {
+        /// Predicate `a * b > value`, which statistics can not evaluate.
+        struct ProductGt(i32);
+
+        impl crate::predicates::PhysicalIoExpr for ProductGt {
+            fn evaluate(&self, df: &DataFrame) -> Result<Series> {
+                let product = df.column("a")? * df.column("b")?;
+                Ok(product.gt(self.0)?.into_series())
+            }
+        }
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4, 5, 6], "b" => [10, 1, 10, 1, 10, 1])?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .with_post_predicate(Some(Arc::new(ProductGt(20))))
+            ._finish_with_scan_ops(Some(Arc::new(ColumnGt("a", 2))), None, None)?;
+
+        // the same as a single combined filter
+        let mask = df.column("a")?.gt(2)? & (df.column("a")? * df.column("b")?).gt(20)?;
+        let expected = df.filter(&mask)?;
+        assert!(out.frame_equal(&expected));
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(3), Some(5)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_row_count_i64() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        // the row count of the second row group exceeds `u32::MAX`
+        let offset = u32::MAX as i64 - 1;
+        let out = ParquetReader::new(buf)
+            .with_row_count(Some(crate::RowCount {
+                name: "row_nr".into(),
+                offset: offset as IdxSize,
+            }))
+            .row_count_i64(true)
+            .finish()?;
+        let expected = (offset..offset + 4).map(Some).collect::<Vec<_>>();
+        assert_eq!(Vec::from(out.column("row_nr")?.i64()?), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_row_count_i64_large_file() -> Result<()> {
+        use arrow::io::parquet::read::{self, ColumnChunkMetaData, RowGroupMetaData};
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4, 5, 6])?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        // the first row group claims more rows than fit in `u32`, it is pruned so that
+        // they are never decoded
+        let n_rows = u32::MAX as usize + 1;
+        let mut metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+        let md = &metadata.row_groups[0];
+        let columns = md
+            .columns()
+            .iter()
+            .map(|column| {
+                let mut chunk = column.column_chunk().clone();
+                chunk.meta_data.as_mut().unwrap().num_values = n_rows as i64;
+                ColumnChunkMetaData::try_from_thrift(column.descriptor().clone(), chunk).unwrap()
+            })
+            .collect();
+        metadata.row_groups[0] = RowGroupMetaData::new(columns, n_rows, md.total_byte_size());
+        let read = |row_count_i64| {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_metadata(Some(metadata.clone()))
+                .with_since(Some(("a".to_string(), AnyValue::Int32(3))))
+                .with_row_count(Some(crate::RowCount {
+                    name: "row_nr".into(),
+                    offset: 0,
+                }))
+                .row_count_i64(row_count_i64)
+                .finish()
+        };
+
+        let out = read(true)?;
+        let expected = (n_rows as i64..n_rows as i64 + 4)
+            .map(Some)
+            .collect::<Vec<_>>();
+        assert_eq!(Vec::from(out.column("row_nr")?.i64()?), expected);
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(3), Some(4), Some(5), Some(6)]
+        );
+        // the row count doesn't wrap
+        if IdxSize::MAX as u64 == u32::MAX as u64 {
+            assert!(read(false).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_limit_uneven_pages() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        // the large strings span multiple pages, while the integers fit in a single page
+        let n = 3000;
+        let long = "x".repeat(1000);
+        let mut df = df!(
+            "int" => (0..n).collect::<Vec<i32>>(),
+            "str" => (0..n).map(|_| long.as_str()).collect::<Vec<_>>()
+        )?;
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        for limit in [1, 1001, 2999] {
+            let out = ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_n_rows(Some(limit))
+                .finish()?;
+            for s in out.get_columns() {
+                assert_eq!(s.len(), limit);
+            }
+            assert!(out.frame_equal(&df.head(Some(limit))));
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn test_read_parquet_predicate_only_columns() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4, 5, 6], "b" => [10, 20, 30, 40, 50, 60])?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .with_predicate_only_columns(Some(vec!["a".into()]))
+            ._finish_with_scan_ops(Some(Arc::new(ColumnGt("a", 3))), None, None)?;
+        // `a` is only decoded in the row group where the statistics are not conclusive
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(4), None, None]);
+        assert_eq!(
+            Vec::from(out.column("b")?.i32()?),
+            &[Some(40), Some(50), Some(60)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_deadline() -> Result<()> {
+        use std::time::{Duration, Instant};
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4, 5, 6])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let reader = || ParquetReader::new(Cursor::new(bytes.clone()));
+
+        let passed = Instant::now();
+        let out = reader().with_deadline(Some(passed)).finish();
+        assert!(matches!(out, Err(PolarsError::Timeout(_))));
+        let (out, complete) = reader().finish_with_deadline(passed)?;
+        assert!(!complete);
+        assert_eq!(out.shape(), (0, 1));
+
+        let future = Instant::now() + Duration::from_secs(3600);
+        let out = reader().with_deadline(Some(future)).finish()?;
+        assert!(out.frame_equal(&df));
+        let (out, complete) = reader().finish_with_deadline(future)?;
+        assert!(complete);
+        assert!(out.frame_equal(&df));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_chunk_counts() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4, 5, 6], "b" => ["a", "b", "c", "d", "e", "f"])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(3))
+            .finish(&mut df)?;
+
+        let (out, chunk_counts) = ParquetReader::new(buf)
+            .with_columns(Some(vec!["b".into()]))
+            .finish_with_chunk_counts()?;
+        assert_eq!(out.shape(), (6, 1));
+        let expected = (0..2)
+            .map(|row_group| RowGroupChunks {
+                row_group,
+                columns: vec![("b".to_string(), 1)],
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(chunk_counts, expected);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn test_read_parquet_row_group_id() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4, 5, 6])?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        let out = ParquetReader::new(buf)
+            .with_row_group_id(Some("__row_group".into()))
+            ._finish_with_scan_ops(Some(Arc::new(ColumnGt("a", 2))), None, None)?;
+        assert_eq!(out.get_column_names(), &["a", "__row_group"]);
+        assert_eq!(
+            Vec::from(out.column("__row_group")?.idx()?),
+            &[Some(1), Some(1), Some(2), Some(2)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_duplicate_columns() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df =
+            DataFrame::new_no_checks(vec![Series::new("a", &[1, 2]), Series::new("a", &[3, 4])]);
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let read = |policy| {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .on_duplicate_columns(policy)
+                .finish()
+        };
+
+        assert!(matches!(
+            read(Some(DuplicateColumns::Error)),
+            Err(PolarsError::Duplicate(_))
+        ));
+        let out = read(Some(DuplicateColumns::Warn))?;
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(1), Some(2)]);
+        assert!(read(None).is_ok());
+        // by default the duplicates are warned about and the first is read
+        let out = ParquetReader::new(Cursor::new(bytes.clone())).finish()?;
+        assert_eq!(out.get_column_names(), &["a"]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn test_read_parquet_dataset() -> Result<()> {
+        use arrow::io::parquet::read;
+
+        let dir = tempdir::TempDir::new("parquet-dataset")?;
+        let write = |name: &str, mut df: DataFrame| -> Result<read::FileMetaData> {
+            let path = dir.path().join(name);
+            ParquetWriter::new(File::create(&path)?)
+                .with_statistics(true)
+                .with_row_group_size(Some(2))
+                .finish(&mut df)?;
+            Ok(read::read_metadata(&mut File::open(&path)?)?)
+        };
+        let first = write("first.parquet", df!("a" => [1, 2, 3, 4])?)?;
+        let second = write("second.parquet", df!("a" => [5, 6, 7, 8])?)?;
+        let dataset = ParquetDataset::new(
+            dir.path().to_path_buf(),
+            vec![
+                ("first.parquet".into(), first),
+                ("second.parquet".into(), second),
+            ],
+        );
+
+        let out = dataset.read(None, None)?;
+        assert_eq!(out.height(), 8);
+
+        // the first file is pruned with the known metadata, so it is never opened
+        std::fs::remove_file(dir.path().join("first.parquet"))?;
+        let out = dataset.read(None, Some(Arc::new(ColumnGt("a", 5))))?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(6), Some(7), Some(8)]
+        );
+        assert!(dataset.read(None, None).is_err());
+
+        // the second file changed since its metadata was collected
+        write("second.parquet", df!("a" => [5, 6, 7, 8, 9, 10])?)?;
+        let out = dataset.read(None, Some(Arc::new(ColumnGt("a", 5))));
+        assert!(matches!(out, Err(PolarsError::ComputeError(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_limit_reached() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4, 5, 6])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let read = |n_rows| {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_n_rows(n_rows)
+                .finish_with_limit_reached()
+        };
+
+        // stops within a row group
+        let (out, limit_reached) = read(Some(3))?;
+        assert_eq!(out.height(), 3);
+        assert!(limit_reached);
+        // stops at the end of a row group
+        let (out, limit_reached) = read(Some(4))?;
+        assert_eq!(out.height(), 4);
+        assert!(limit_reached);
+
+        let (out, limit_reached) = read(Some(6))?;
+        assert_eq!(out.height(), 6);
+        assert!(!limit_reached);
+        let (out, limit_reached) = read(None)?;
+        assert!(out.frame_equal(&df));
+        assert!(!limit_reached);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_deferred() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "a" => [1, 2, 3, 4, 5],
+            "b" => ["a", "b", "c", "d", "e"]
+        )?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        let frame = ParquetReader::new(buf).finish_deferred(None)?;
+        assert_eq!(frame.get_column_names(), &["a", "b"]);
+        assert_eq!(frame.height(), 5);
+        assert!(!frame.is_decoded("a") && !frame.is_decoded("b"));
+
+        assert!(frame.column("a")?.series_equal(df.column("a")?));
+        assert!(frame.is_decoded("a"));
+        assert!(!frame.is_decoded("b"));
+        assert!(frame.column("c").is_err());
+
+        assert!(frame.into_df()?.frame_equal(&df));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_statistics_truncated_len() -> Result<()> {
+        use crate::parquet::predicates::ColumnRanges;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => ["apple", "banana"])?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let reader = || {
+            let mut ranges = PlHashMap::new();
+            ranges.insert(
+                "a".to_string(),
+                (Some(AnyValue::Utf8Owned("bananas".into())), None),
+            );
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_column_ranges(Some(ColumnRanges(ranges)))
+        };
+        let read = |truncated_len| {
+            reader()
+                .with_statistics_truncated_len(truncated_len)
+                .finish()
+        };
+
+        // the writer is not known, so by default "banana" may be a truncated max
+        assert!(reader().finish()?.frame_equal(&df));
+        // the max of "banana" is exact, so no value can be "bananas"
+        assert_eq!(read(None)?.height(), 0);
+        assert_eq!(read(Some(7))?.height(), 0);
+        // "banana" may be the truncation of "bananas"
+        assert!(read(Some(6))?.frame_equal(&df));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_aggregation_projection() -> Result<()> {
+        use crate::aggregations::ScanAggregation;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "a" => [1, 2, 3, 4],
+            "b" => [5, 6, 7, 8],
+            "c" => ["a", "b", "c", "d"]
+        )?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        let aggregate = [
+            ScanAggregation::Sum {
+                column: "a".to_string(),
+                alias: None,
+            },
+            ScanAggregation::Max {
+                column: "b".to_string(),
+                alias: None,
+            },
+        ];
+        let mut chunk_counts = vec![];
+        let out = ParquetReader::new(buf).finish_impl(
+            None,
+            Some(&aggregate),
+            None,
+            ReadDiagnostics {
+                chunk_counts: Some(&mut chunk_counts),
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(out.get_column_names(), &["a", "b"]);
+        assert_eq!(out.column("a")?.i32()?.get(0), Some(10));
+        assert_eq!(out.column("b")?.i32()?.get(0), Some(8));
+        // "c" is not decoded
+        assert_eq!(chunk_counts.len(), 2);
+        for rg in &chunk_counts {
+            let names = rg.columns.iter().map(|(name, _)| name.as_str());
+            assert!(names.eq(["a", "b"]));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_aggregation_distinct_on() -> Result<()> {
+        use crate::aggregations::ScanAggregation;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "a" => [1, 1, 2, 1],
+            "b" => [5, 5, 6, 5],
+            "c" => ["a", "b", "c", "d"]
+        )?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        let aggregate = [ScanAggregation::Sum {
+            column: "b".to_string(),
+            alias: None,
+        }];
+        let mut chunk_counts = vec![];
+        let out = ParquetReader::new(buf)
+            .with_distinct_on(Some(vec!["a".to_string(), "b".to_string()]))
+            .finish_impl(
+                None,
+                Some(&aggregate),
+                None,
+                ReadDiagnostics {
+                    chunk_counts: Some(&mut chunk_counts),
+                    ..Default::default()
+                },
+            )?;
+        // the duplicates, within and across row groups, are not summed
+        assert_eq!(out.column("b")?.i32()?.get(0), Some(11));
+        // "a" is decoded for `distinct_on`, "c" is not decoded
+        assert_eq!(chunk_counts.len(), 2);
+        for rg in &chunk_counts {
+            let names = rg.columns.iter().map(|(name, _)| name.as_str());
+            assert!(names.eq(["a", "b"]));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_decompression_pool() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "a" => [1, 2, 3, 4, 5, 6],
+            "b" => ["a", "b", "c", "d", "e", "f"]
+        )?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        let pool = Arc::new(DecompressionPool::new());
+        for _ in 0..2 {
+            let out = ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_decompression_pool(Some(pool.clone()))
+                .finish()?;
+            assert!(out.frame_equal(&df));
+            // the buffers are returned once the columns are decoded
+            assert!(!pool.is_empty());
+            assert!(pool.len() <= 2);
+        }
+
+        // the pipeline decompresses into the buffers of the pool as well
+        let pool = Arc::new(DecompressionPool::new());
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .with_decode_pipeline(Some(Arc::new(DecodePipeline::new(2, 2, 4)?)))
+            .with_decompression_pool(Some(pool.clone()))
+            .finish()?;
+        assert!(out.frame_equal(&df));
+        assert!(!pool.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_columns_missing_in_row_groups() -> Result<()> {
+        use crate::parquet::predicates::ColumnRanges;
+        use arrow::io::parquet::read::{self, RowGroupMetaData};
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4], "b" => [1, 2, 3, 4])?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        // column "b" was added after the first row group was written
+        let mut metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+        let md = &metadata.row_groups[0];
+        metadata.row_groups[0] = RowGroupMetaData::new(
+            md.columns()[..1].to_vec(),
+            md.num_rows(),
+            md.total_byte_size(),
+        );
+        let read = |lower: Option<i32>| {
+            let reader = ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_metadata(Some(metadata.clone()))
+                .allow_missing_columns(true)
+                .with_columns(Some(vec!["b".to_string()]));
+            match lower {
+                Some(lower) => {
+                    let mut ranges = PlHashMap::new();
+                    ranges.insert("b".to_string(), (Some(AnyValue::Int32(lower)), None));
+                    reader
+                        .with_column_ranges(Some(ColumnRanges(ranges)))
+                        .finish()
+                }
+                None => reader.finish(),
+            }
+        };
+
+        let out = read(None)?;
+        assert_eq!(out.get_column_names(), &["b"]);
+        assert_eq!(
+            Vec::from(out.column("b")?.i32()?),
+            &[None, None, Some(3), Some(4)]
+        );
+        // the first row group has no statistics of "b", so it is not pruned
+        let out = read(Some(5))?;
+        assert_eq!(Vec::from(out.column("b")?.i32()?), &[None, None]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_read_column_dictionary() -> Result<()> {
+        use arrow::io::parquet::read;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4], "b" => ["y", "x", "y", "y"])?;
+        df.try_apply("b", |s| s.cast(&DataType::Categorical(None)))?;
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+
+        let dictionary = read_column_dictionary(Cursor::new(&bytes), &metadata, 0, "b")?.unwrap();
+        assert_eq!(dictionary.name(), "b");
+        assert_eq!(Vec::from(dictionary.utf8()?), &[Some("y"), Some("x")]);
+        // plain encoded
+        assert!(read_column_dictionary(Cursor::new(&bytes), &metadata, 0, "a")?.is_none());
+        assert!(read_column_dictionary(Cursor::new(&bytes), &metadata, 1, "b").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_within_pool() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4], "b" => ["a", "b", "c", "d"])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        // small files are read serially by the threads of the pool
+        let out = polars_core::POOL.install(|| ParquetReader::new(Cursor::new(bytes)).finish())?;
+        assert!(out.frame_equal(&df));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_row_selection() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4, 5, 6])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let reader = |selection: &[bool]| {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_row_selection(Some(BooleanChunked::new("", selection)))
+        };
+
+        let selection = [false, false, true, false, true, true];
+        let (out, chunk_counts) = reader(&selection)
+            .with_row_count(Some(crate::RowCount {
+                name: "row_nr".to_string(),
+                offset: 0,
+            }))
+            .finish_with_chunk_counts()?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(3), Some(5), Some(6)]
+        );
+        assert_eq!(
+            Vec::from(out.column("row_nr")?.idx()?),
+            &[Some(2), Some(4), Some(5)]
+        );
+        // the first row group has no selected rows
+        let row_groups = chunk_counts.iter().map(|rg| rg.row_group);
+        assert!(row_groups.eq([1, 2]));
+
+        // the limit counts the selected rows
+        let out = reader(&selection).with_n_rows(Some(2)).finish()?;
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(3), Some(5)]);
+
+        assert!(reader(&[true, false]).finish().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_cast_overflow() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [Some(1i64), Some(i64::MAX), Some(-300), None])?;
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let read = |dtype: DataType, overflow| {
+            let spec = ReadSpec::new()
+                .with_column(ColumnSource::Name("a".into()), None, Some(dtype))
+                .with_overflow(overflow);
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_read_spec(Some(spec))
+                .finish_with_cast_failures()
+        };
+
+        assert!(read(DataType::Int32, OverflowBehavior::Error).is_err());
+        let (out, failed) = read(DataType::Int64, OverflowBehavior::Error)?;
+        assert!(out.frame_equal_missing(&df));
+        assert_eq!(failed, 0);
+
+        let (out, failed) = read(DataType::Int32, OverflowBehavior::Null)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(1), None, Some(-300), None]
+        );
+        assert_eq!(failed, 1);
+
+        let (out, failed) = read(DataType::Int32, OverflowBehavior::Saturate)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(1), Some(i32::MAX), Some(-300), None]
+        );
+        assert_eq!(failed, 1);
+        let (out, failed) = read(DataType::UInt32, OverflowBehavior::Saturate)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.u32()?),
+            &[Some(1), Some(u32::MAX), Some(0), None]
+        );
+        assert_eq!(failed, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_file_bounds() -> Result<()> {
+        use crate::parquet::predicates::file_bounds;
+        use arrow::io::parquet::read;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [3, 4, 1, 2, 5, 6], "b" => ["d", "c", "f", "e", "a", "b"])?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        buf.set_position(0);
+        let metadata = read::read_metadata(&mut buf)?;
+        let schema = read::infer_schema(&metadata)?;
+        assert_eq!(
+            file_bounds(&metadata, &schema, "a")?,
+            Some((AnyValue::Int32(1), AnyValue::Int32(6)))
+        );
+        match file_bounds(&metadata, &schema, "b")? {
+            Some((AnyValue::Utf8Owned(min), AnyValue::Utf8Owned(max))) => {
+                assert_eq!((min.as_str(), max.as_str()), ("a", "f"))
+            }
+            _ => panic!("expected string bounds"),
+        }
+        assert!(file_bounds(&metadata, &schema, "c").is_err());
+
+        // without statistics the bounds are unknown
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+        let metadata = read::read_metadata(&mut buf)?;
+        assert_eq!(file_bounds(&metadata, &schema, "a")?, None);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-date")]
+    fn test_file_bounds_dtypes() -> Result<()> {
+        use crate::parquet::predicates::file_bounds;
+        use arrow::io::parquet::read;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = DataFrame::new(vec![
+            Int32Chunked::new("d", &[19003, 19004, 19001, 19002])
+                .into_date()
+                .into_series(),
+            Series::new("b", &[true, false, true, true]),
+        ])?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        buf.set_position(0);
+        let metadata = read::read_metadata(&mut buf)?;
+        let schema = read::infer_schema(&metadata)?;
+        assert_eq!(
+            file_bounds(&metadata, &schema, "d")?,
+            Some((AnyValue::Date(19001), AnyValue::Date(19004)))
+        );
+        // unsupported rather than unknown
+        let err = file_bounds(&metadata, &schema, "b").unwrap_err();
+        assert!(err.to_string().contains("not supported"));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn test_pruned_row_groups() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4, 5, 6], "b" => [6, 5, 4, 3, 2, 1])?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        let (out, pruned) = ParquetReader::new(Cursor::new(bytes.clone()))
+            .finish_with_pruned_row_groups(Some(Arc::new(ColumnGt("a", 2))))?;
+        assert_eq!(pruned.row_groups(), &[1, 2]);
+        assert_eq!(out.height(), 4);
+
+        // a later read with another projection reuses the pruned row groups
+        let out = ParquetReader::new(Cursor::new(bytes.clone()))
+            .with_columns(Some(vec!["b".to_string()]))
+            .with_pruned_row_groups(Some(pruned.clone()))
+            ._finish_with_scan_ops(Some(Arc::new(ColumnGt("a", 2))), None, None)?;
+        assert_eq!(
+            Vec::from(out.column("b")?.i32()?),
+            &[Some(4), Some(3), Some(2), Some(1)]
+        );
+
+        // a different predicate
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .with_pruned_row_groups(Some(pruned.clone()))
+            ._finish_with_scan_ops(Some(Arc::new(ColumnGt("a", 4))), None, None);
+        assert!(out.is_err());
+
+        // a different file
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(3))
+            .finish(&mut df)?;
+        let out = ParquetReader::new(buf)
+            .with_pruned_row_groups(Some(pruned))
+            ._finish_with_scan_ops(Some(Arc::new(ColumnGt("a", 2))), None, None);
+        assert!(out.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_chunk_length() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => (0..10).collect::<Vec<i32>>(), "b" => [1.0; 10])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(3))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let read = |length| {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_chunk_length(Some(length))
+                .finish()
+        };
+
+        let out = read(4)?;
+        for s in out.get_columns() {
+            assert_eq!(s.chunk_lengths().collect::<Vec<_>>(), &[4, 4, 2]);
+        }
+        assert!(out.frame_equal(&df));
+
+        let out = read(5)?;
+        assert_eq!(
+            out.column("a")?.chunk_lengths().collect::<Vec<_>>(),
+            &[5, 5]
+        );
+        let out = read(20)?;
+        assert_eq!(out.column("a")?.chunk_lengths().collect::<Vec<_>>(), &[10]);
+        assert!(out.frame_equal(&df));
+        assert!(read(0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_read_parquet_dictionary_columns() -> Result<()> {
+        use arrow::io::parquet::read;
+
+        let mut df = df!("a" => ["y", "x", "y", "y"])?;
+        let mut categorical = df.clone();
+        categorical.try_apply("a", |s| s.cast(&DataType::Categorical(None)))?;
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        ParquetWriter::new(&mut buf).finish(&mut categorical)?;
+        let dictionary_bytes = buf.into_inner();
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+        let plain_bytes = buf.into_inner();
+
+        let read = |bytes: &[u8], dictionary_columns, use_arrow_metadata| {
+            ParquetReader::new(Cursor::new(bytes.to_vec()))
+                .use_arrow_metadata(use_arrow_metadata)
+                .with_dictionary_columns(dictionary_columns)
+                .finish()
+        };
+        let out = read(&dictionary_bytes, DictionaryColumns::Schema, true)?;
+        assert_eq!(out.column("a")?.dtype(), &DataType::Categorical(None));
+        let out = read(&dictionary_bytes, DictionaryColumns::Values, true)?;
+        assert!(out.frame_equal(&df));
+        // without the arrow schema the column is `Utf8`, but it is dictionary encoded
+        let out = read(&dictionary_bytes, DictionaryColumns::Schema, false)?;
+        assert!(out.frame_equal(&df));
+        let out = read(&dictionary_bytes, DictionaryColumns::Categorical, false)?;
+        assert_eq!(out.column("a")?.dtype(), &DataType::Categorical(None));
+        // the plain column is not
+        let out = read(&plain_bytes, DictionaryColumns::Categorical, true)?;
+        assert!(out.frame_equal(&df));
+
+        // the arrow schema marks the plain encoded column as a dictionary
+        let dictionary_metadata = read::read_metadata(&mut Cursor::new(&dictionary_bytes))?;
+        let mut metadata = read::read_metadata(&mut Cursor::new(&plain_bytes))?;
+        metadata.key_value_metadata = dictionary_metadata.key_value_metadata;
+        let out = ParquetReader::new(Cursor::new(plain_bytes))
+            .with_metadata(Some(metadata))
+            .finish()?;
+        assert_eq!(out.column("a")?.dtype(), &DataType::Categorical(None));
+        assert_eq!(
+            Vec::from(out.column("a")?.cast(&DataType::Utf8)?.utf8()?),
+            &[Some("y"), Some("x"), Some("y"), Some("y")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_read_parquet_dictionary_fallback_budget() -> Result<()> {
+        use arrow::io::parquet::read::{self, ColumnChunkMetaData, RowGroupMetaData};
+        use arrow::io::parquet::write::Encoding;
+
+        let values = (0..1000)
+            .map(|i| format!("value-{}", i))
+            .collect::<Vec<_>>();
+        let mut df = df!("a" => values)?;
+        let mut categorical = df.clone();
+        categorical.try_apply("a", |s| s.cast(&DataType::Categorical(None)))?;
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        ParquetWriter::new(&mut buf).finish(&mut categorical)?;
+        let dictionary_metadata = read::read_metadata(&mut Cursor::new(buf.into_inner()))?;
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        // a dictionary column of which the writer fell back to plain encoding at the
+        // first page, so that it is decoded again as its values
+        let mut metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+        metadata.key_value_metadata = dictionary_metadata.key_value_metadata;
+        let md = &metadata.row_groups[0];
+        let column = &md.columns()[0];
+        let uncompressed = column.uncompressed_size() as usize;
+        let mut chunk = column.column_chunk().clone();
+        chunk
+            .meta_data
+            .as_mut()
+            .unwrap()
+            .encodings
+            .push(Encoding::RleDictionary.into());
+        let column =
+            ColumnChunkMetaData::try_from_thrift(column.descriptor().clone(), chunk).unwrap();
+        metadata.row_groups[0] =
+            RowGroupMetaData::new(vec![column], md.num_rows(), md.total_byte_size());
+
+        // the budget holds the pages once, not twice
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .with_metadata(Some(metadata))
+            .with_max_decompressed_bytes(Some(uncompressed))
+            .finish()?;
+        assert_eq!(
+            Vec::from(out.column("a")?.cast(&DataType::Utf8)?.utf8()?),
+            Vec::from(df.column("a")?.utf8()?)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_column_errors() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4], "b" => [5, 6, 7, 8])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        // corrupt the column chunk of "a" in the first row group
+        let mut bytes = buf.into_inner();
+        for b in &mut bytes[4..12] {
+            *b = 0xff;
+        }
+        assert!(ParquetReader::new(Cursor::new(bytes.clone()))
+            .finish()
+            .is_err());
+
+        let (out, errors) = ParquetReader::new(Cursor::new(bytes.clone()))
+            .finish_with_column_errors(FailedColumns::Null)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[None, None, Some(3), Some(4)]
+        );
+        assert!(out.column("b")?.series_equal(df.column("b")?));
+        assert_eq!(errors.len(), 1);
+        assert_eq!((errors[0].column.as_str(), errors[0].row_group), ("a", 0));
+
+        let (out, errors) = ParquetReader::new(Cursor::new(bytes))
+            .finish_with_column_errors(FailedColumns::Omit)?;
+        assert_eq!(out.get_column_names(), &["b"]);
+        assert_eq!(errors.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_unsigned() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "a" => [u32::MAX, 1 << 31, 0],
+            "b" => [u64::MAX, 1 << 63, 0]
+        )?;
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        // the parquet types are unsigned integers, stored as signed physical integers
+        for use_arrow_metadata in [true, false] {
+            let out = ParquetReader::new(Cursor::new(bytes.clone()))
+                .use_arrow_metadata(use_arrow_metadata)
+                .finish()?;
+            assert_eq!(out.column("a")?.dtype(), &DataType::UInt32);
+            assert_eq!(out.column("b")?.dtype(), &DataType::UInt64);
+            assert!(out.frame_equal(&df));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_column_chunk_locations() -> Result<()> {
+        use arrow::io::parquet::read;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4], "b" => ["w", "x", "y", "z"])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+
+        let locations = column_chunk_locations(&metadata);
+        let keys = locations
+            .iter()
+            .map(|loc| (loc.row_group, loc.path.join(".")))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            keys,
+            &[
+                (0, "a".to_string()),
+                (0, "b".to_string()),
+                (1, "a".to_string()),
+                (1, "b".to_string())
+            ]
+        );
+        // the column chunks follow the magic bytes and don't overlap
+        let mut end = 4;
+        for loc in &locations {
+            assert!(loc.offset >= end);
+            assert!(loc.compressed_length > 0);
+            end = loc.offset + loc.compressed_length;
+        }
+        assert!(end as usize <= bytes.len());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn test_read_parquet_post_decode() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("high" => [0, 1, 1, 2], "low" => [5, 0, 7, 1])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        let post_decode: Arc<PostDecode> = Arc::new(|df: &mut DataFrame| {
+            // every row group is decoded into a frame of its own
+            assert!(df.height() <= 2);
+            let value = &(df.column("high")? * 10) + df.column("low")?;
+            df.with_column(value.with_name("value"))?;
+            Ok(())
+        });
+        // the predicate refers to the added column
+        let out = ParquetReader::new(buf)
+            .with_post_decode(Some(post_decode))
+            ._finish_with_scan_ops(Some(Arc::new(ColumnGt("value", 6))), None, None)?;
+        assert_eq!(
+            Vec::from(out.column("value")?.i32()?),
+            &[Some(10), Some(17), Some(21)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_windowed_mmap() -> Result<()> {
+        let path = std::env::temp_dir().join("polars_parquet_windowed_mmap.parquet");
+        let mut df = df!(
+            "a" => (0..10).collect::<Vec<i32>>(),
+            "b" => (0..10).map(|i| format!("value {}", i)).collect::<Vec<_>>()
+        )?;
+        ParquetWriter::new(File::create(&path)?)
+            .with_row_group_size(Some(3))
+            .finish(&mut df)?;
+
+        let out = ParquetReader::new(File::open(&path)?)
+            .windowed_mmap(true)
+            .finish()?;
+        assert!(out.frame_equal(&df));
+        let out = ParquetReader::new(File::open(&path)?)
+            .windowed_mmap(true)
+            .with_columns(Some(vec!["b".to_string()]))
+            .with_n_rows(Some(4))
+            .finish()?;
+        assert!(out.frame_equal(&df.select(["b"])?.head(Some(4))));
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_injected_faults() -> Result<()> {
+        use crate::parquet::fault;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "fault_a" => [1, 2, 3, 4, 5, 6],
+            "fault_b" => [7, 8, 9, 10, 11, 12]
+        )?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        let _guard = fault::inject(&[(0, "fault_a"), (2, "fault_a"), (1, "fault_b")]);
+        assert!(ParquetReader::new(Cursor::new(bytes.clone()))
+            .finish()
+            .is_err());
+
+        let (out, errors) = ParquetReader::new(Cursor::new(bytes.clone()))
+            .finish_with_column_errors(FailedColumns::Null)?;
+        assert_eq!(
+            Vec::from(out.column("fault_a")?.i32()?),
+            &[None, None, Some(3), Some(4), None, None]
+        );
+        assert_eq!(
+            Vec::from(out.column("fault_b")?.i32()?),
+            &[Some(7), Some(8), None, None, Some(11), Some(12)]
+        );
+        let mut failed = errors
+            .iter()
+            .map(|e| (e.row_group, e.column.as_str()))
+            .collect::<Vec<_>>();
+        failed.sort_unstable();
+        assert_eq!(failed, &[(0, "fault_a"), (1, "fault_b"), (2, "fault_a")]);
+
+        let (out, _) = ParquetReader::new(Cursor::new(bytes.clone()))
+            .with_columns(Some(vec!["fault_b".to_string()]))
+            .finish_with_column_errors(FailedColumns::Omit)?;
+        assert_eq!(out.width(), 0);
+
+        drop(_guard);
+        assert!(ParquetReader::new(Cursor::new(bytes))
+            .finish()?
+            .frame_equal(&df));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parquet_describe() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "a" => [Some(3i64), None, Some(-1), Some(10), None],
+            "b" => ["x", "y", "a", "q", "b"]
+        )?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        let out = parquet_describe(Cursor::new(buf.into_inner()))?;
+        assert_eq!(
+            Vec::from(out.column("column")?.utf8()?),
+            &[Some("a"), Some("b")]
+        );
+        assert_eq!(
+            Vec::from(out.column("dtype")?.utf8()?),
+            &[Some("i64"), Some("str")]
+        );
+        assert_eq!(
+            Vec::from(out.column("null_count")?.u64()?),
+            &[Some(2), Some(0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("min")?.utf8()?),
+            &[Some("-1"), Some("a")]
+        );
+        assert_eq!(
+            Vec::from(out.column("max")?.utf8()?),
+            &[Some("10"), Some("y")]
+        );
+        // the file has multiple row groups
+        assert_eq!(out.column("distinct_count")?.null_count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_distinct_on() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "key" => [1, 2, 1, 3, 3, 1],
+            "value" => ["a", "b", "c", "d", "e", "f"]
+        )?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(3))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        let out = ParquetReader::new(Cursor::new(bytes.clone()))
+            .with_distinct_on(Some(vec!["key".to_string()]))
+            .finish()?;
+        // the first occurrence is kept, also of the duplicates in another row group
+        assert_eq!(
+            Vec::from(out.column("key")?.i32()?),
+            &[Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(
+            Vec::from(out.column("value")?.utf8()?),
+            &[Some("a"), Some("b"), Some("d")]
+        );
+
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .with_distinct_on(Some(vec!["key".to_string(), "value".to_string()]))
+            .finish()?;
+        assert!(out.frame_equal(&df));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_little_endian_values() -> Result<()> {
+        // parquet stores its values little endian, whatever the byte order of the host
+        let int = 0x0102_0304i32;
+        let long = 0x0102_0304_0506_0708i64;
+        let float = -1.5e300f64;
+        let mut df = df!("int" => [int], "long" => [long], "float" => [float])?;
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        ParquetWriter::new(&mut buf)
+            .with_compression(ParquetCompression::Uncompressed)
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        let contains = |needle: &[u8]| bytes.windows(needle.len()).any(|w| w == needle);
+        assert!(contains(&[0x04, 0x03, 0x02, 0x01]));
+        assert!(contains(&long.to_le_bytes()));
+        assert!(contains(&float.to_le_bytes()));
+
+        let out = ParquetReader::new(Cursor::new(bytes)).finish()?;
+        assert_eq!(out.column("int")?.i32()?.get(0), Some(int));
+        assert_eq!(out.column("long")?.i64()?.get(0), Some(long));
+        assert_eq!(out.column("float")?.f64()?.get(0), Some(float));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_since() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "ts" => [1i64, 2, 3, 4, 5, 6],
+            "value" => [10, 20, 30, 40, 50, 60]
+        )?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        let out = ParquetReader::new(Cursor::new(bytes.clone()))
+            .with_since(Some(("ts".to_string(), AnyValue::Int64(4))))
+            .finish()?;
+        // the row group with 3 is read as its max is 4, its rows are not filtered
+        assert_eq!(
+            Vec::from(out.column("ts")?.i64()?),
+            &[Some(3), Some(4), Some(5), Some(6)]
+        );
+
+        let out = ParquetReader::new(Cursor::new(bytes.clone()))
+            .with_since(Some(("ts".to_string(), AnyValue::Int64(7))))
+            .finish()?;
+        assert_eq!(out.shape(), (0, 2));
+
+        assert!(ParquetReader::new(Cursor::new(bytes))
+            .with_since(Some(("unknown".to_string(), AnyValue::Int64(4))))
+            .finish()
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-datetime")]
+    fn test_read_parquet_since_datetime() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = DataFrame::new(vec![
+            Int64Chunked::new("ts", &[1_000i64, 2_000, 3_000, 4_000, 5_000, 6_000])
+                .into_datetime(TimeUnit::Milliseconds, None)
+                .into_series(),
+            Series::new("value", &[10, 20, 30, 40, 50, 60]),
+        ])?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let since = |bound| {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_since(Some(("ts".to_string(), bound)))
+                .finish()
+        };
+
+        // the bound is cast to the milliseconds of the column
+        let out = since(AnyValue::Datetime(4_000_000, TimeUnit::Microseconds, &None))?;
+        assert_eq!(
+            Vec::from(out.column("value")?.i32()?),
+            &[Some(30), Some(40), Some(50), Some(60)]
+        );
+        let out = since(AnyValue::Datetime(6_500, TimeUnit::Milliseconds, &None))?;
+        assert_eq!(out.height(), 0);
+        // a bound that can't be cast to a datetime is an error rather than not pruning
+        assert!(since(AnyValue::Utf8Owned("soon".into())).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_skips_empty_row_groups() -> Result<()> {
+        use arrow::io::parquet::read::{self, RowGroupMetaData};
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4], "b" => ["a", "b", "c", "d"])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let mut metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+        // a placeholder row group without rows between the two row groups
+        let md = &metadata.row_groups[0];
+        let empty = RowGroupMetaData::new(md.columns().to_vec(), 0, 0);
+        metadata.row_groups.insert(1, empty);
+
+        let (out, chunk_counts) = ParquetReader::new(Cursor::new(bytes))
+            .with_metadata(Some(metadata))
+            .with_row_count(Some(crate::RowCount {
+                name: "rc".into(),
+                offset: 0,
+            }))
+            .finish_with_chunk_counts()?;
+        assert!(out.drop("rc")?.frame_equal(&df));
+        assert_eq!(
+            out.column("rc")?
+                .idx()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            &[0, 1, 2, 3]
+        );
+        assert_eq!(
+            chunk_counts.iter().map(|c| c.row_group).collect::<Vec<_>>(),
+            &[0, 2]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_source() -> Result<()> {
+        use arrow::io::parquet::read;
+        use std::sync::{Arc, Mutex};
+
+        // a source that records the ranges that are read
+        struct RecordingSource {
+            bytes: Vec<u8>,
+            reads: Arc<Mutex<Vec<(u64, u64)>>>,
+        }
+
+        impl ParquetSource for RecordingSource {
+            fn file_len(&self) -> Result<u64> {
+                Ok(self.bytes.len() as u64)
+            }
+
+            fn read_range(&self, offset: u64, len: u64) -> Result<Vec<u8>> {
+                self.reads.lock().unwrap().push((offset, len));
+                let start = offset as usize;
+                Ok(self.bytes[start..start + len as usize].to_vec())
+            }
+        }
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4], "b" => ["a", "b", "c", "d"])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let file_len = bytes.len() as u64;
+        let metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+
+        let reads = Arc::new(Mutex::new(vec![]));
+        let source = RecordingSource {
+            bytes,
+            reads: reads.clone(),
+        };
+        let out = ParquetReader::new(SourceReader::new(source)?)
+            .with_columns(Some(vec!["a".to_string()]))
+            .finish()?;
+        assert!(out.frame_equal(&df.select(["a"])?));
+
+        let reads = reads.lock().unwrap();
+        // the footer is read from the end of the file
+        assert!(reads.iter().any(|(offset, len)| offset + len == file_len));
+        // every column chunk of "a" is read as a single range, those of "b" are not read
+        for location in column_chunk_locations(&metadata) {
+            let range = (location.offset, location.compressed_length);
+            assert_eq!(reads.contains(&range), location.path == ["a"]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_source_coalesced_ranges() -> Result<()> {
+        use arrow::io::parquet::read;
+        use std::sync::{Arc, Mutex};
+
+        // a source that records the ranges that are read and fails the reads of the
+        // column chunks once `fail` is set
+        struct RecordingSource {
+            bytes: Vec<u8>,
+            data_end: u64,
+            fail: bool,
+            reads: Arc<Mutex<Vec<(u64, u64)>>>,
+        }
+
+        impl ParquetSource for RecordingSource {
+            fn file_len(&self) -> Result<u64> {
+                Ok(self.bytes.len() as u64)
+            }
+
+            fn read_range(&self, offset: u64, len: u64) -> Result<Vec<u8>> {
+                if self.fail && offset < self.data_end {
+                    return Err(PolarsError::ComputeError("the source is offline".into()));
+                }
+                self.reads.lock().unwrap().push((offset, len));
+                let start = offset as usize;
+                Ok(self.bytes[start..start + len as usize].to_vec())
+            }
+        }
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "a" => [1, 2, 3, 4],
+            "b" => ["a", "b", "c", "d"],
+            "c" => [1.0, 2.0, 3.0, 4.0]
+        )?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+        let data_end = column_chunk_locations(&metadata)
+            .iter()
+            .map(|location| location.offset + location.compressed_length)
+            .max()
+            .unwrap();
+
+        let reads = Arc::new(Mutex::new(vec![]));
+        let source = |fail| RecordingSource {
+            bytes: bytes.clone(),
+            data_end,
+            fail,
+            reads: reads.clone(),
+        };
+        let data_reads = || {
+            reads
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(offset, _)| *offset < data_end)
+                .count()
+        };
+
+        // the adjacent column chunks of a row group are read as a single range
+        let out = ParquetReader::new(SourceReader::new(source(false))?).finish()?;
+        assert!(out.frame_equal(&df));
+        assert_eq!(data_reads(), 2);
+
+        // the column chunks around a column that is not decoded are only merged if the
+        // gap allows it
+        let read_with_gap = |gap| {
+            reads.lock().unwrap().clear();
+            ParquetReader::new(SourceReader::new(source(false))?)
+                .with_columns(Some(vec!["a".into(), "c".into()]))
+                .with_coalesce_gap(gap)
+                .finish()
+        };
+        read_with_gap(DEFAULT_COALESCE_GAP_BYTES)?;
+        assert_eq!(data_reads(), 2);
+        let out = read_with_gap(0)?;
+        assert_eq!(out.get_column_names(), &["a", "c"]);
+        assert_eq!(data_reads(), 4);
+
+        // a single column reads its column chunks only
+        reads.lock().unwrap().clear();
+        let s = read_parquet_column(SourceReader::new(source(false))?, "b", None, None)?;
+        assert!(s.series_equal(df.column("b")?));
+        assert_eq!(data_reads(), 2);
+        read_column_dictionary(SourceReader::new(source(false))?, &metadata, 0, "b")?;
+
+        // a failing source is an error, not a panic
+        assert!(read_parquet_column(SourceReader::new(source(true))?, "b", None, None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_source_bogus_ranges() -> Result<()> {
+        use arrow::io::parquet::read::{self, ColumnChunkMetaData, RowGroupMetaData};
+
+        let dir = tempdir::TempDir::new("parquet-source")?;
+        let path = dir.path().join("a.parquet");
+        let mut df = df!("a" => [1, 2, 3, 4])?;
+        ParquetWriter::new(File::create(&path)?).finish(&mut df)?;
+        let file_len = File::open(&path)?.file_len()?;
+
+        // the range is checked before a buffer of its length is allocated
+        let file = File::open(&path)?;
+        assert!(file.read_range(0, u64::MAX / 2).is_err());
+        assert!(file.read_range(file_len - 1, 2).is_err());
+        assert_eq!(file.read_range(0, 4)?, b"PAR1");
+
+        // a footer that claims a huge column chunk
+        let mut metadata = read::read_metadata(&mut File::open(&path)?)?;
+        let md = &metadata.row_groups[0];
+        let columns = md
+            .columns()
+            .iter()
+            .map(|column| {
+                let mut chunk = column.column_chunk().clone();
+                chunk.meta_data.as_mut().unwrap().total_compressed_size = i64::MAX / 2;
+                ColumnChunkMetaData::try_from_thrift(column.descriptor().clone(), chunk).unwrap()
+            })
+            .collect();
+        metadata.row_groups[0] =
+            RowGroupMetaData::new(columns, md.num_rows(), md.total_byte_size());
+        let out = ParquetReader::new(SourceReader::new(File::open(&path)?)?)
+            .with_metadata(Some(metadata))
+            .finish();
+        assert!(out
+            .unwrap_err()
+            .to_string()
+            .contains("exceeds the file size"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_limit_distribution() -> Result<()> {
+        use crate::parquet::read_impl::distribute_limit;
+
+        assert_eq!(distribute_limit(4, &[4, 4, 4]), &[1, 1, 2]);
+        assert_eq!(distribute_limit(5, &[3, 0, 3, 1]), &[2, 0, 2, 1]);
+        assert_eq!(distribute_limit(10, &[3, 2]), &[3, 2]);
+        assert_eq!(distribute_limit(0, &[3, 2]), &[0, 0]);
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => (0..12).collect::<Vec<i32>>())?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(4))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        let out = ParquetReader::new(Cursor::new(bytes.clone()))
+            .with_n_rows(Some(6))
+            .with_limit_distribution(LimitDistribution::Even)
+            .finish()?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(0), Some(1), Some(4), Some(5), Some(8), Some(9)]
+        );
+
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .with_n_rows(Some(7))
+            .with_limit_distribution(LimitDistribution::Even)
+            .finish()?;
+        // the row lost by rounding is taken from the last row group
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[
+                Some(0),
+                Some(1),
+                Some(4),
+                Some(5),
+                Some(8),
+                Some(9),
+                Some(10)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn test_read_parquet_constraints() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "age" => [Some(3), Some(5), Some(-2), None, Some(-4), Some(-1)],
+            "name" => ["a", "b", "c", "d", "e", "f"]
+        )?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let constraints = || {
+            Some(vec![(
+                "age".to_string(),
+                Arc::new(ColumnGt("age", -3)) as Arc<dyn crate::predicates::PhysicalIoExpr>,
+            )])
+        };
+
+        let err = ParquetReader::new(Cursor::new(bytes.clone()))
+            .with_constraints(constraints())
+            .finish()
+            .unwrap_err();
+        assert!(err.to_string().contains("row group 2, e.g. by -4"));
+
+        let (out, violations) = ParquetReader::new(Cursor::new(bytes.clone()))
+            .with_constraints(constraints())
+            .finish_with_constraint_violations()?;
+        assert!(out.frame_equal_missing(&df));
+        // the null in row group 1 satisfies the constraint
+        assert_eq!(
+            violations,
+            &[ConstraintViolation {
+                column: "age".to_string(),
+                row_group: 2,
+                rows: 1,
+                example: "-4".to_string(),
+            }]
+        );
+
+        let (_, violations) = ParquetReader::new(Cursor::new(bytes))
+            .with_constraints(Some(vec![(
+                "age".to_string(),
+                Arc::new(ColumnGt("age", 3)) as Arc<dyn crate::predicates::PhysicalIoExpr>,
+            )]))
+            .finish_with_constraint_violations()?;
+        assert_eq!(
+            violations
+                .iter()
+                .map(|v| (v.row_group, v.rows, v.example.as_str()))
+                .collect::<Vec<_>>(),
+            &[(0, 1, "3"), (1, 1, "-2"), (2, 2, "-4")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_decode_pipeline() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "a" => [1, 2, 3, 4, 5, 6],
+            "b" => ["a", "b", "c", "d", "e", "f"],
+            "c" => [Some(1.0), None, Some(3.0), None, Some(5.0), None]
+        )?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        // a channel that holds a single column chunk makes the decompressing threads wait
+        let pipeline = Arc::new(DecodePipeline::new(2, 2, 1)?);
+        for _ in 0..2 {
+            let out = ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_decode_pipeline(Some(pipeline.clone()))
+                .finish()?;
+            assert!(out.frame_equal_missing(&df));
+        }
+
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .with_columns(Some(vec!["a".into(), "c".into()]))
+            .with_decode_pipeline(Some(pipeline))
+            .finish()?;
+        assert!(out.frame_equal_missing(&df.select(["a", "c"])?));
+
+        assert!(DecodePipeline::new(0, 1, 1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn test_read_parquet_inverted_statistics() -> Result<()> {
+        use crate::parquet::predicates::FileStatistics;
+        use arrow::io::parquet::read::{self, ColumnChunkMetaData, RowGroupMetaData};
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4, 5, 6])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(3))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let mut metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+        // a writer that swapped the bounds of the first row group: min 3, max 1
+        let md = &metadata.row_groups[0];
+        let column = &md.columns()[0];
+        let mut chunk = column.column_chunk().clone();
+        let stats = chunk
+            .meta_data
+            .as_mut()
+            .and_then(|meta| meta.statistics.as_mut())
+            .unwrap();
+        std::mem::swap(&mut stats.min_value, &mut stats.max_value);
+        std::mem::swap(&mut stats.min, &mut stats.max);
+        let column =
+            ColumnChunkMetaData::try_from_thrift(column.descriptor().clone(), chunk).unwrap();
+        metadata.row_groups[0] =
+            RowGroupMetaData::new(vec![column], md.num_rows(), md.total_byte_size());
+
+        let schema = read::infer_schema(&metadata)?;
+        let statistics = FileStatistics::collect(&metadata, &schema)?;
+        let stats = statistics.row_group(0).unwrap().get_stats("a")?;
+        assert!(stats.bounds_are_inverted());
+        assert!(stats.to_min_max().is_none());
+        let stats = statistics.row_group(1).unwrap().get_stats("a")?;
+        assert!(!stats.bounds_are_inverted());
+
+        // trusting the max of 1 would prune the first row group
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .with_metadata(Some(metadata))
+            .finish_impl(
+                Some(Arc::new(ColumnGt("a", 2))),
+                None,
+                None,
+                ReadDiagnostics::default(),
+            )?;
+        assert_eq!(
+            out.column("a")?
+                .i32()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            &[3, 4, 5, 6]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_max_decompressed_bytes() -> Result<()> {
+        use arrow::io::parquet::read;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        // the pages of a constant column are small, but decompress to 4 bytes per row
+        let mut df = df!("a" => vec![7i32; 100_000])?;
+        ParquetWriter::new(&mut buf)
+            .with_compression(ParquetCompression::Zstd(None))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+        let compressed = metadata.row_groups[0].columns()[0].compressed_size() as usize;
+        assert!(compressed < 64 * 1024);
+
+        let out = ParquetReader::new(Cursor::new(bytes.clone()))
+            .with_max_decompressed_bytes(Some(64 * 1024))
+            .finish();
+        assert!(out
+            .unwrap_err()
+            .to_string()
+            .contains("decompress to more than the maximum of 65536 bytes"));
+
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .with_max_decompressed_bytes(Some(1 << 20))
+            .finish()?;
+        assert!(out.frame_equal(&df));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_max_decompressed_bytes_page_header() -> Result<()> {
+        use arrow::io::parquet::read::{self, ColumnChunkMetaData, RowGroupMetaData};
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => (0..1000).collect::<Vec<i32>>())?;
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let mut metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+
+        // the header of the data page starts with its type, `DATA_PAGE_V2`, and then
+        // declares its uncompressed size as a zigzag varint
+        let md = &metadata.row_groups[0];
+        let column = &md.columns()[0];
+        let start = column.byte_range().0 as usize;
+        assert_eq!(&bytes[start..start + 3], &[0x15, 0x06, 0x15]);
+        let size_start = start + 3;
+        let size_len = bytes[size_start..]
+            .iter()
+            .position(|b| b & 0x80 == 0)
+            .unwrap()
+            + 1;
+        // declare a page of `i32::MAX` bytes, which takes a longer varint
+        let huge = [0xfe, 0xff, 0xff, 0xff, 0x0f];
+        let bytes = [
+            &bytes[..size_start],
+            &huge[..],
+            &bytes[size_start + size_len..],
+        ]
+        .concat();
+        let grown = (huge.len() - size_len) as i64;
+
+        let mut chunk = column.column_chunk().clone();
+        let meta = chunk.meta_data.as_mut().unwrap();
+        meta.total_compressed_size += grown;
+        meta.total_uncompressed_size += grown;
+        let column =
+            ColumnChunkMetaData::try_from_thrift(column.descriptor().clone(), chunk).unwrap();
+        metadata.row_groups[0] =
+            RowGroupMetaData::new(vec![column], md.num_rows(), md.total_byte_size());
+
+        // the declared size is taken from the budget before the page is decompressed
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .with_metadata(Some(metadata))
+            .with_max_decompressed_bytes(Some(1 << 20))
+            .finish();
+        assert!(out
+            .unwrap_err()
+            .to_string()
+            .contains("decompress to more than the maximum of 1048576 bytes"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_metadata_columns() -> Result<()> {
+        use arrow::io::parquet::read;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "a" => [3, 1, 2, 6, 4, 5],
+            "b" => [Some("x"), None, Some("y"), None, None, Some("z")]
+        )?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(3))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+
+        let out = ParquetReader::new(Cursor::new(bytes.clone()))
+            .with_columns(Some(vec!["a".into()]))
+            .with_metadata_columns(Some(vec![
+                MetadataColumn::new("rows", MetadataField::NumRows),
+                MetadataColumn::new("b_size", MetadataField::CompressedSize("b".into())),
+                MetadataColumn::new("b_nulls", MetadataField::NullCount("b".into())),
+                MetadataColumn::new("a_min", MetadataField::Min("a".into())),
+                MetadataColumn::new("a_max", MetadataField::Max("a".into())),
+            ]))
+            .finish()?;
+        assert_eq!(
+            out.get_column_names(),
+            &["a", "rows", "b_size", "b_nulls", "a_min", "a_max"]
+        );
+        let values = |name: &str| -> Result<Vec<u64>> {
+            Ok(out.column(name)?.u64()?.into_no_null_iter().collect())
+        };
+        assert_eq!(values("rows")?, &[3, 3, 3, 3, 3, 3]);
+        assert_eq!(values("b_nulls")?, &[1, 1, 1, 2, 2, 2]);
+        let sizes = metadata
+            .row_groups
+            .iter()
+            .map(|md| md.columns()[1].compressed_size() as u64);
+        assert!(values("b_size")?.chunks(3).map(|chunk| chunk[0]).eq(sizes));
+        let a = |name: &str| -> Result<Vec<i32>> {
+            Ok(out.column(name)?.i32()?.into_no_null_iter().collect())
+        };
+        assert_eq!(a("a_min")?, &[1, 1, 1, 4, 4, 4]);
+        assert_eq!(a("a_max")?, &[3, 3, 3, 6, 6, 6]);
+
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .with_metadata_columns(Some(vec![MetadataColumn::new(
+                "c_min",
+                MetadataField::Min("c".into()),
+            )]))
+            .finish();
+        assert!(out.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_suffix_duplicate_columns() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = DataFrame::new_no_checks(vec![
+            Series::new("x", &[1, 2]),
+            Series::new("x_1", &["a", "b"]),
+            Series::new("x", &[3, 4]),
+        ]);
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let reader = || {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .on_duplicate_columns(Some(DuplicateColumns::Suffix))
+        };
+
+        // `x_1` is taken, so the second `x` becomes `x_2`
+        let schema = reader().schema()?;
+        assert_eq!(
+            schema.iter_names().collect::<Vec<_>>(),
+            &["x", "x_1", "x_2"]
+        );
+        let out = reader().finish()?;
+        assert_eq!(out.get_column_names(), &["x", "x_1", "x_2"]);
+        assert_eq!(Vec::from(out.column("x")?.i32()?), &[Some(1), Some(2)]);
+        assert_eq!(Vec::from(out.column("x_2")?.i32()?), &[Some(3), Some(4)]);
+
+        let out = reader().with_projection(Some(vec![2])).finish()?;
+        assert_eq!(out.get_column_names(), &["x_2"]);
+        assert_eq!(Vec::from(out.column("x_2")?.i32()?), &[Some(3), Some(4)]);
+        let out = reader().with_columns(Some(vec!["x_2".into()])).finish()?;
+        assert_eq!(Vec::from(out.column("x_2")?.i32()?), &[Some(3), Some(4)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_top_k() -> Result<()> {
+        let write = |values: &[i32]| -> Result<Vec<u8>> {
+            let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+            let mut df =
+                df!("a" => values, "b" => values.iter().map(|v| v * 10).collect::<Vec<_>>())?;
+            ParquetWriter::new(&mut buf)
+                .with_statistics(true)
+                .with_row_group_size(Some(3))
+                .finish(&mut df)?;
+            Ok(buf.into_inner())
+        };
+        // sorted across row groups, but not within them
+        let bytes = write(&[3, 1, 2, 6, 4, 5, 9, 7, 8])?;
+        let read = |k, ascending| {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_top_k(Some(("a".into(), k, ascending)))
+                .finish_with_chunk_counts()
+        };
+
+        let (out, chunk_counts) = read(4, true)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(1), Some(2), Some(3), Some(4)]
+        );
+        assert_eq!(
+            Vec::from(out.column("b")?.i32()?),
+            &[Some(10), Some(20), Some(30), Some(40)]
+        );
+        assert_eq!(
+            chunk_counts.iter().map(|c| c.row_group).collect::<Vec<_>>(),
+            &[0, 1]
+        );
+
+        let (out, chunk_counts) = read(2, false)?;
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(9), Some(8)]);
+        assert_eq!(
+            chunk_counts.iter().map(|c| c.row_group).collect::<Vec<_>>(),
+            &[2]
+        );
+
+        // a descending file is read from the end for the smallest values
+        let bytes = write(&[9, 8, 7, 6, 5, 4])?;
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .with_top_k(Some(("a".into(), 2, true)))
+            .finish()?;
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(4), Some(5)]);
+
+        let bytes = write(&[1, 5, 9, 2, 3, 4])?;
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .with_top_k(Some(("a".into(), 2, true)))
+            .finish();
+        assert!(out.unwrap_err().to_string().contains("sorted by 'a'"));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-datetime")]
+    fn test_read_parquet_top_k_datetime() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = DataFrame::new(vec![
+            Int64Chunked::new("t", &[2_000i64, 1_000, 4_000, 3_000, 6_000, 5_000])
+                .into_datetime(TimeUnit::Milliseconds, None)
+                .into_series(),
+            Series::new("b", &[true, false, true, false, true, false]),
+        ])?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        let out = ParquetReader::new(Cursor::new(bytes.clone()))
+            .with_top_k(Some(("t".into(), 3, false)))
+            .finish()?;
+        assert_eq!(
+            Vec::from(out.column("t")?.to_physical_repr().i64()?),
+            &[Some(6_000), Some(5_000), Some(4_000)]
+        );
+        // a boolean column is unsupported rather than not sorted
+        let err = ParquetReader::new(Cursor::new(bytes))
+            .with_top_k(Some(("b".into(), 3, false)))
+            .finish()
+            .unwrap_err();
+        assert!(err.to_string().contains("not supported"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_reverse() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4, 5, 6, 7])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(3))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let read = |n_rows| {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .read_reverse(true)
+                .with_n_rows(n_rows)
+                .with_row_count(Some(crate::RowCount {
+                    name: "rc".into(),
+                    offset: 10,
+                }))
+                .finish()
+        };
+
+        let out = read(None)?;
+        assert_eq!(
+            out.column("a")?
+                .i32()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            &[7, 6, 5, 4, 3, 2, 1]
+        );
+        assert_eq!(
+            out.column("rc")?
+                .idx()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            &[16, 15, 14, 13, 12, 11, 10]
+        );
+
+        // the limit keeps the last rows of the file
+        let out = read(Some(3))?;
+        assert_eq!(
+            out.column("a")?
+                .i32()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            &[7, 6, 5]
+        );
+        assert_eq!(
+            out.column("rc")?
+                .idx()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            &[16, 15, 14]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_offset_widths() -> Result<()> {
+        use crate::parquet::read::set_offset_widths;
+        use arrow::io::parquet::read::{self, ColumnChunkMetaData, RowGroupMetaData};
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => ["x", "yy", "zzz"], "b" => ["u", "v", "w"])?;
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+
+        // a column chunk of which the values exceed what 32 bit offsets address, without
+        // writing them
+        let mut large = metadata.clone();
+        let md = &large.row_groups[0];
+        let column = &md.columns()[0];
+        let mut chunk = column.column_chunk().clone();
+        chunk.meta_data.as_mut().unwrap().total_uncompressed_size = i32::MAX as i64 + 1;
+        let column =
+            ColumnChunkMetaData::try_from_thrift(column.descriptor().clone(), chunk).unwrap();
+        let columns = vec![column, md.columns()[1].clone()];
+        large.row_groups[0] = RowGroupMetaData::new(columns, md.num_rows(), md.total_byte_size());
+
+        let dtypes = |metadata: &read::FileMetaData, widths: Option<&PlHashMap<_, _>>| {
+            let mut schema = read::infer_schema(metadata).unwrap();
+            schema.fields[0].data_type = ArrowDataType::Utf8;
+            schema.fields[1].data_type = ArrowDataType::Utf8;
+            set_offset_widths(metadata, &mut schema, widths);
+            schema
+                .fields
+                .into_iter()
+                .map(|field| field.data_type)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            dtypes(&large, None),
+            &[ArrowDataType::LargeUtf8, ArrowDataType::Utf8]
+        );
+        assert_eq!(
+            dtypes(&metadata, None),
+            &[ArrowDataType::Utf8, ArrowDataType::Utf8]
+        );
+        let mut widths = PlHashMap::new();
+        widths.insert("a".to_string(), OffsetWidth::Regular);
+        widths.insert("b".to_string(), OffsetWidth::Large);
+        assert_eq!(
+            dtypes(&large, Some(&widths)),
+            &[ArrowDataType::Utf8, ArrowDataType::LargeUtf8]
+        );
+
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .with_offset_widths(Some(widths))
+            .finish()?;
+        assert!(out.frame_equal(&df));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_finish_with_metadata() -> Result<()> {
+        use arrow::io::parquet::read;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4], "b" => ["a", "b", "c", "d"])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        let (out, metadata) = ParquetReader::new(Cursor::new(bytes.clone()))
+            .with_columns(Some(vec!["b".into()]))
+            .finish_with_metadata()?;
+        assert!(out.frame_equal(&df.select(["b"])?));
+        let expected = read::read_metadata(&mut Cursor::new(&bytes))?;
+        assert_eq!(metadata.num_rows, expected.num_rows);
+        assert_eq!(metadata.row_groups.len(), 2);
+        assert_eq!(metadata.schema().columns().len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "json", feature = "dtype-struct"))]
+    fn test_read_parquet_json_expansion() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "id" => [1, 2, 3, 4, 5, 6],
+            "j" => [
+                Some(r#"{"a": 1, "b": "x"}"#),
+                Some(r#"{"a": 2}"#),
+                None,
+                Some(r#"{"a": 3.5}"#),
+                Some("not json"),
+                Some(r#"{"a": 4, "c": true}"#)
+            ]
+        )?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(3))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let read = |on_mismatch| {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_json_expansion(Some(
+                    JsonExpansion::new("j")
+                        .with_sample(None)
+                        .with_on_mismatch(on_mismatch),
+                ))
+                .finish()
+        };
+
+        let out = read(JsonMismatch::Null)?;
+        assert_eq!(out.get_column_names(), &["id", "j"]);
+        let fields = out.column("j")?.struct_()?.fields();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].name(), "a");
+        // the values of the second row group don't fit the schema of the first
+        assert_eq!(
+            Vec::from(fields[0].i64()?),
+            &[Some(1), Some(2), None, None, None, None]
+        );
+        assert_eq!(
+            Vec::from(fields[1].utf8()?),
+            &[Some("x"), None, None, None, None, None]
+        );
+
+        let err = read(JsonMismatch::Error).unwrap_err();
+        assert!(err.to_string().contains(r#"{"a": 3.5}"#));
+        Ok(())
+    }
+
+    #[test]
+    fn test_extend_hybrid_boolean_runs() -> Result<()> {
+        use arrow::bitmap::MutableBitmap;
+        use parquet2::encoding::hybrid_rle::HybridRleDecoder;
+
+        let runs = [
+            // 300 times true
+            0xD8,
+            0x04,
+            0x01, //
+            // 2 bit-packed groups of 8 values
+            0x05,
+            0b1011_0010,
+            0b0000_0111, //
+            // 20 times false
+            0x28,
+            0x00,
+        ];
+        for len in [0, 5, 300, 310, 330, 336] {
+            let mut bitmap = MutableBitmap::new();
+            bitmap.push(true);
+            let set = super::boolean::extend_hybrid(&mut bitmap, &runs, len)?;
+
+            let reference = HybridRleDecoder::new(&runs, 1, len)
+                .map(|value| value == 1)
+                .collect::<Vec<_>>();
+            let bits = (1..bitmap.len()).map(|i| bitmap.get(i)).collect::<Vec<_>>();
+            assert_eq!(bits, reference);
+            assert_eq!(set, reference.iter().filter(|value| **value).count());
+        }
+
+        let mut bitmap = MutableBitmap::new();
+        assert!(super::boolean::extend_hybrid(&mut bitmap, &runs, 337).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_boolean() -> Result<()> {
+        let n = 10_000;
+        let values = (0..n).map(|i| i % 3 == 0 || i % 7 == 0).collect::<Vec<_>>();
+        let nullable = (0..n)
+            .map(|i| (i % 5 != 0 && i < 9_000).then(|| i % 2 == 0))
+            .collect::<Vec<_>>();
+        let mut df = df!(
+            "values" => values,
+            "nullable" => nullable,
+            "nulls" => vec![None::<bool>; n]
+        )?;
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(3_000))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        let read = ParquetReader::new(Cursor::new(bytes.clone())).finish()?;
+        assert!(read.frame_equal_missing(&df));
+        for n_rows in [1, 2_999, 4_321] {
+            let read = ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_n_rows(Some(n_rows))
+                .finish()?;
+            assert!(read.frame_equal_missing(&df.head(Some(n_rows))));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_required_columns() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "a" => [Some(1), None, Some(3), Some(4), Some(5), Some(6), None, Some(8), Some(9)],
+            "b" => [Some("x"), Some("y"), None, Some("x"), Some("y"), Some("z"), Some("x"), Some("y"), Some("z")]
+        )?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(3))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let read = |columns: &[&str], on_null, n_rows| {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_required_columns(
+                    Some(columns.iter().map(|c| c.to_string()).collect()),
+                    on_null,
+                )
+                .with_n_rows(n_rows)
+                .finish()
+        };
+
+        let out = read(&["a"], RequiredColumnNulls::Drop, None)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[
+                Some(1),
+                Some(3),
+                Some(4),
+                Some(5),
+                Some(6),
+                Some(8),
+                Some(9)
+            ]
+        );
+        let out = read(&["a", "b"], RequiredColumnNulls::Drop, None)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(1), Some(4), Some(5), Some(6), Some(8), Some(9)]
+        );
+        // the dropped rows don't count towards the limit
+        let out = read(&["a"], RequiredColumnNulls::Drop, Some(4))?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(1), Some(3), Some(4), Some(5)]
+        );
+
+        let err = read(&["a"], RequiredColumnNulls::Error, None).unwrap_err();
+        assert!(err.to_string().contains("row group 0"));
+        assert!(read(&["c"], RequiredColumnNulls::Drop, None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_parallel_threshold() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "a" => (0..10_000i32).collect::<Vec<_>>(),
+            "b" => (0..10_000i64).map(|i| i * 3).collect::<Vec<_>>()
+        )?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(1_000))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        for threshold in [0, DEFAULT_PARALLEL_THRESHOLD_BYTES, usize::MAX] {
+            let out = ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_parallel_threshold(threshold)
+                .finish()?;
+            assert!(out.frame_equal(&df));
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn test_read_parquet_on_series() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "a" => [1, 2, 3, 4, 5],
+            "b" => ["x", "y", "z", "x", "y"]
+        )?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        let on_series: Arc<OnSeries> = Arc::new(|name: &str, s: Series| match name {
+            "a" => s.cast(&DataType::Float64).map(|s| s * 0.5),
+            _ => Ok(s),
+        });
+        let out = ParquetReader::new(buf)
+            .with_on_series(Some(on_series))
+            ._finish_with_scan_ops(Some(Arc::new(ColumnGt("a", 1))), None, None)?;
+        // the predicate sees the transformed values
+        assert_eq!(
+            Vec::from(out.column("a")?.f64()?),
+            &[Some(1.5), Some(2.0), Some(2.5)]
+        );
+        assert_eq!(
+            Vec::from(out.column("b")?.utf8()?),
+            &[Some("z"), Some("x"), Some("y")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_row_count_collision() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "row_nr" => [10, 20, 30],
+            "row_nr_1" => [1, 2, 3],
+            "a" => [4, 5, 6]
+        )?;
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let read = |columns: Option<Vec<String>>, policy| {
+            ParquetReader::new(Cursor::new(bytes.clone()))
+                .with_columns(columns)
+                .with_row_count(Some(crate::RowCount {
+                    name: "row_nr".to_string(),
+                    offset: 0,
+                }))
+                .on_row_count_collision(policy)
+                .finish()
+        };
+
+        let err = read(None, RowCountCollision::Error).unwrap_err();
+        assert!(matches!(err, PolarsError::Duplicate(_)));
+        // the colliding column is not read
+        let out = read(Some(vec!["a".to_string()]), RowCountCollision::Error)?;
+        assert_eq!(out.get_column_names(), &["row_nr", "a"]);
+
+        let out = read(None, RowCountCollision::Suffix)?;
+        assert_eq!(
+            out.get_column_names(),
+            &["row_nr_2", "row_nr", "row_nr_1", "a"]
+        );
+        assert_eq!(
+            Vec::from(out.column("row_nr_2")?.idx()?),
+            &[Some(0), Some(1), Some(2)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_statistics_decoder() -> Result<()> {
+        use crate::parquet::predicates::{
+            register_statistics_decoder, row_group_bounds, unregister_statistics_decoder,
+            StatisticsDecoder, StatisticsType,
+        };
+        use arrow::io::parquet::read::{
+            self, ColumnChunkMetaData, PrimitiveLogicalType, RowGroupMetaData,
+        };
+        use std::convert::TryInto;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1i64, 2, 3, 300, 400, 500])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(3))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+        let mut metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+        // a custom type of which the bounds are big endian
+        for md in metadata.row_groups.iter_mut() {
+            let column = &md.columns()[0];
+            let mut chunk = column.column_chunk().clone();
+            let stats = chunk
+                .meta_data
+                .as_mut()
+                .and_then(|meta| meta.statistics.as_mut())
+                .unwrap();
+            let to_big_endian = |value: &Option<Vec<u8>>| {
+                let value = i64::from_le_bytes(value.as_ref()?[..8].try_into().ok()?);
+                Some(value.to_be_bytes().to_vec())
+            };
+            stats.min_value = to_big_endian(&stats.min_value);
+            stats.max_value = to_big_endian(&stats.max_value);
+            stats.min = None;
+            stats.max = None;
+            let mut descriptor = column.descriptor().clone();
+            descriptor.descriptor.primitive_type.logical_type = Some(PrimitiveLogicalType::Uuid);
+            let column = ColumnChunkMetaData::try_from_thrift(descriptor, chunk).unwrap();
+            *md = RowGroupMetaData::new(vec![column], md.num_rows(), md.total_byte_size());
+        }
+        let schema = read::infer_schema(&metadata)?;
+
+        let decoder: Arc<StatisticsDecoder> = Arc::new(|bytes: &[u8]| {
+            let bytes = bytes.try_into().ok()?;
+            Some(AnyValue::Int64(i64::from_be_bytes(bytes)))
+        });
+        let statistics_type = StatisticsType::Logical(PrimitiveLogicalType::Uuid);
+        register_statistics_decoder(statistics_type.clone(), decoder);
+        let bounds = row_group_bounds(&metadata, &schema);
+        assert!(unregister_statistics_decoder(&statistics_type));
+        let bounds = bounds?;
+        assert_eq!(bounds[0]["a"], (AnyValue::Int64(1), AnyValue::Int64(3)));
+        assert_eq!(bounds[1]["a"], (AnyValue::Int64(300), AnyValue::Int64(500)));
+
+        // the built-in decoding reads the bounds as little endian
+        let bounds = row_group_bounds(&metadata, &schema)?;
+        assert_ne!(bounds[0]["a"], (AnyValue::Int64(1), AnyValue::Int64(3)));
+        assert!(!unregister_statistics_decoder(&statistics_type));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_partitioned_by() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "region" => ["eu", "us", "eu", "us", "us", "apac"],
+            "a" => [1, 2, 3, 4, 5, 6]
+        )?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        let partitions =
+            ParquetReader::new(Cursor::new(bytes.clone())).finish_partitioned_by("region")?;
+        assert_eq!(partitions.len(), 3);
+        let a = |region| -> Result<Vec<Option<i32>>> {
+            Ok(Vec::from(
+                partitions[&AnyValue::Utf8Owned(region)]
+                    .column("a")?
+                    .i32()?,
+            ))
+        };
+        assert_eq!(a("eu".into())?, &[Some(1), Some(3)]);
+        assert_eq!(a("us".into())?, &[Some(2), Some(4), Some(5)]);
+        assert_eq!(a("apac".into())?, &[Some(6)]);
+
+        let partitions = ParquetReader::new(Cursor::new(bytes.clone()))
+            .with_n_rows(Some(3))
+            .finish_partitioned_by("region")?;
+        assert_eq!(partitions.values().map(|df| df.height()).sum::<usize>(), 3);
+        assert!(ParquetReader::new(Cursor::new(bytes))
+            .finish_partitioned_by("b")
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-date")]
+    fn test_read_parquet_partitioned_by_date() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = DataFrame::new(vec![
+            Int32Chunked::new("d", &[19000, 19001, 19000, 19000])
+                .into_date()
+                .into_series(),
+            Series::new("a", &[1, 2, 3, 4]),
+        ])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        let partitions = ParquetReader::new(buf).finish_partitioned_by("d")?;
+        assert_eq!(partitions.len(), 2);
+        let a = |d| -> Result<Vec<Option<i32>>> {
+            Ok(Vec::from(
+                partitions[&AnyValue::Date(d)].column("a")?.i32()?,
+            ))
+        };
+        assert_eq!(a(19000)?, &[Some(1), Some(3), Some(4)]);
+        assert_eq!(a(19001)?, &[Some(2)]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_read_parquet_partitioned_by_categorical() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "region" => ["eu", "us", "us", "eu"],
+            "a" => [1, 2, 3, 4]
+        )?;
+        df.try_apply("region", |s| s.cast(&DataType::Categorical(None)))?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        // the categories of the row groups are stacked under the same string cache
+        let partitions = ParquetReader::new(buf)
+            .with_string_cache(Some(polars_core::StringCacheHolder::default()))
+            .finish_partitioned_by("region")?;
+        assert_eq!(partitions.len(), 2);
+        let eu = &partitions[&AnyValue::Utf8Owned("eu".into())];
+        assert!(matches!(
+            eu.column("region")?.dtype(),
+            DataType::Categorical(_)
+        ));
+        assert_eq!(Vec::from(eu.column("a")?.i32()?), &[Some(1), Some(4)]);
+        let us = &partitions[&AnyValue::Utf8Owned("us".into())];
+        assert_eq!(Vec::from(us.column("a")?.i32()?), &[Some(2), Some(3)]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-datetime")]
+    fn test_read_parquet_datetime_unit() -> Result<()> {
+        let datetime = |values: &[i64]| {
+            Int64Chunked::new("t", values)
+                .into_datetime(TimeUnit::Milliseconds, None)
+                .into_series()
+        };
+        let write = |s: Series| -> Result<Vec<u8>> {
+            let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+            let d = Int64Chunked::new("d", &vec![1_500i64; s.len()])
+                .into_duration(TimeUnit::Milliseconds)
+                .into_series();
+            let mut df = DataFrame::new(vec![s, d])?;
+            ParquetWriter::new(&mut buf)
+                .with_row_group_size(Some(2))
+                .finish(&mut df)?;
+            Ok(buf.into_inner())
+        };
+        let read = |bytes: &[u8], tu| {
+            ParquetReader::new(Cursor::new(bytes.to_vec()))
+                .with_datetime_unit(Some(tu))
+                .finish()
+        };
+
+        let bytes = write(datetime(&[0, 1_001, -1_500]))?;
+        let out = read(&bytes, TimeUnit::Nanoseconds)?;
+        assert_eq!(
+            out.column("t")?.dtype(),
+            &DataType::Datetime(TimeUnit::Nanoseconds, None)
+        );
+        assert_eq!(
+            out.column("d")?.dtype(),
+            &DataType::Duration(TimeUnit::Nanoseconds)
+        );
+        assert_eq!(
+            Vec::from(out.column("t")?.to_physical_repr().i64()?),
+            &[Some(0), Some(1_001_000_000), Some(-1_500_000_000)]
+        );
+        let out = read(&bytes, TimeUnit::Milliseconds)?;
+        assert!(out
+            .column("t")?
+            .series_equal(&datetime(&[0, 1_001, -1_500])));
+
+        // in the year 2286, which doesn't fit in nanoseconds
+        let bytes = write(datetime(&[0, 10_000_000_000_000]))?;
+        assert!(read(&bytes, TimeUnit::Microseconds).is_ok());
+        let err = read(&bytes, TimeUnit::Nanoseconds).unwrap_err();
+        assert!(err.to_string().contains("10000000000000"));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "ffi")]
+    fn test_read_parquet_arrow_stream() -> Result<()> {
+        use arrow::ffi::ArrowArrayStreamReader;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "a" => [1, 2, 3, 4, 5],
+            "b" => ["x", "y", "z", "x", "y"]
+        )?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        let stream = ParquetReader::new(buf).finish_arrow_stream()?;
+        let mut reader = unsafe { ArrowArrayStreamReader::try_new(stream)? };
+        assert!(matches!(
+            reader.field().data_type(),
+            ArrowDataType::Struct(fields) if fields.len() == 2
+        ));
+        let mut lengths = vec![];
+        while let Some(arr) = unsafe { reader.next() } {
+            lengths.push(arr?.len());
+        }
+        assert_eq!(lengths, &[2, 2, 1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_limit_before_agg() -> Result<()> {
+        use crate::aggregations::ScanAggregation;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4, 5, 6, 7, 8])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(3))
+            .finish(&mut df)?;
+
+        let aggregate = [ScanAggregation::Sum {
+            column: "a".to_string(),
+            alias: None,
+        }];
+        let read = |limit_before_agg| {
+            ParquetReader::new(buf.clone())
+                .with_n_rows(Some(4))
+                .with_limit_before_agg(limit_before_agg)
+                .finish_impl(None, Some(&aggregate), None, ReadDiagnostics::default())
+        };
+
+        // the first 4 rows are aggregated
+        let out = read(true)?;
+        assert_eq!(out.height(), 1);
+        assert_eq!(out.column("a")?.i32()?.get(0), Some(10));
+
+        // all rows are aggregated
+        let out = read(false)?;
+        assert_eq!(out.height(), 1);
+        assert_eq!(out.column("a")?.i32()?.get(0), Some(36));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_parquet_null_fill_dtypes() -> Result<()> {
+        use arrow::io::parquet::read::{self, RowGroupMetaData};
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = DataFrame::new(vec![
+            Series::new("a", &[1, 2, 3, 4]),
+            Series::new("b", &[1i16, 2, 3, 4]),
+            Series::full_null("n", 4, &DataType::Null),
+        ])?;
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        let bytes = buf.into_inner();
+
+        // the first row group lacks column "b"
+        let mut metadata = read::read_metadata(&mut Cursor::new(&bytes))?;
+        let md = &metadata.row_groups[0];
+        metadata.row_groups[0] = RowGroupMetaData::new(
+            vec![md.columns()[0].clone(), md.columns()[2].clone()],
+            md.num_rows(),
+            md.total_byte_size(),
+        );
+
+        let mut dtypes = PlHashMap::new();
+        dtypes.insert("b".to_string(), DataType::Int64);
+        dtypes.insert("n".to_string(), DataType::Int64);
+        let out = ParquetReader::new(Cursor::new(bytes))
+            .with_metadata(Some(metadata))
+            .allow_missing_columns(true)
+            .with_null_fill_dtypes(Some(dtypes))
+            .finish()?;
+        // the second row group is cast to the dtype of the null-filled first one
+        assert_eq!(
+            Vec::from(out.column("b")?.i64()?),
+            &[None, None, Some(3), Some(4)]
+        );
+        assert_eq!(out.column("n")?.dtype(), &DataType::Int64);
+        assert_eq!(out.column("n")?.null_count(), 4);
+
+        // the null-filled columns stack with populated ones
+        let populated = df!(
+            "a" => [5],
+            "b" => [5i64],
+            "n" => [5i64]
+        )?;
+        let stacked = out.vstack(&populated)?;
+        assert_eq!(
+            Vec::from(stacked.column("n")?.i64()?),
+            &[None, None, None, None, Some(5)]
+        );
+        assert_eq!(
+            Vec::from(stacked.column("b")?.i64()?),
+            &[None, None, Some(3), Some(4), Some(5)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn test_row_group_decisions() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4, 5, 6, 7, 8])?;
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        let (out, decisions) = ParquetReader::new(buf)
+            .with_n_rows(Some(2))
+            .finish_with_row_group_decisions(Some(Arc::new(ColumnGt("a", 4))))?;
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(5), Some(6)]);
+        let pruned_by = decisions
+            .iter()
+            .map(|decision| (decision.row_group, decision.pruned_by))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pruned_by,
+            &[
+                (0, Some(PruneReason::Statistics)),
+                (1, Some(PruneReason::Statistics)),
+                (2, None),
+                (3, Some(PruneReason::Limit)),
+            ]
+        );
+        assert!(decisions.iter().all(|decision| decision.num_rows == 2));
+        Ok(())
+    }
 }
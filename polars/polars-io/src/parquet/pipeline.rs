@@ -0,0 +1,109 @@
+use crate::parquet::mmap::{self, FileBytes};
+use crate::parquet::pool::DecompressionPool;
+use crate::parquet::read_impl::catch_malformed;
+use arrow::datatypes::ArrowSchema;
+use arrow::io::parquet::read::RowGroupMetaData;
+use polars_core::prelude::*;
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::sync::mpsc::sync_channel;
+
+/// Decompresses the column chunks of a row group on one thread pool and decodes them on
+/// another, so that decompressing the next column chunks overlaps with decoding the
+/// current ones.
+///
+/// Decompressed column chunks are passed to the decoders through a channel that holds
+/// at most `capacity` of them. A decompressing thread that finds the channel full waits
+/// until a column chunk is decoded, so at most `capacity` plus the number of
+/// decompressing and decoding threads decompressed column chunks are held in memory at
+/// a time. Decompressed column chunks are often several times larger than their
+/// compressed bytes.
+///
+/// The threads of the pipeline are its own, but the pages are decompressed into the
+/// buffers of the [`DecompressionPool`] of the read, if any, and every decompressed page
+/// takes its size from the budget of
+/// [`ParquetReader::with_max_decompressed_bytes`](super::ParquetReader::with_max_decompressed_bytes).
+pub struct DecodePipeline {
+    decompress: ThreadPool,
+    decode: ThreadPool,
+    capacity: usize,
+}
+
+fn build_pool(num_threads: usize, name: &'static str) -> Result<ThreadPool> {
+    ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .thread_name(move |i| format!("parquet-{}-{}", name, i))
+        .build()
+        .map_err(|e| PolarsError::ComputeError(format!("could not start the pool: {}", e).into()))
+}
+
+impl DecodePipeline {
+    /// Create a pipeline with `decompress_threads` threads that decompress, and
+    /// `decode_threads` threads that decode. The channel between them holds at most
+    /// `capacity` decompressed column chunks.
+    pub fn new(decompress_threads: usize, decode_threads: usize, capacity: usize) -> Result<Self> {
+        if decompress_threads == 0 || decode_threads == 0 {
+            return Err(PolarsError::ValueError(
+                "a decode pipeline needs at least one thread per stage".into(),
+            ));
+        }
+        Ok(DecodePipeline {
+            decompress: build_pool(decompress_threads, "decompress")?,
+            decode: build_pool(decode_threads, "decode")?,
+            capacity,
+        })
+    }
+
+    /// Decompress and decode `columns` of the row group `md`, into the buffers of `pool`.
+    /// The results are in the order of `columns`.
+    pub(super) fn run<F>(
+        &self,
+        columns: &[usize],
+        bytes: FileBytes,
+        md: &RowGroupMetaData,
+        schema: &ArrowSchema,
+        pool: Option<&DecompressionPool>,
+        decode: F,
+    ) -> Vec<Result<Series>>
+    where
+        F: Fn(usize, FileBytes) -> Result<Series> + Sync,
+    {
+        let (tx, rx) = sync_channel(self.capacity);
+        let mut out: Vec<Option<Result<Series>>> = (0..columns.len()).map(|_| None).collect();
+
+        self.decompress.in_place_scope(|scope| {
+            scope.spawn(move |_| {
+                columns
+                    .par_iter()
+                    .enumerate()
+                    .for_each_with(tx, |tx, (i, column_i)| {
+                        let field = &schema.fields[*column_i];
+                        let chunks = catch_malformed(|| {
+                            mmap::decompress_columns(bytes, md.columns(), &field.name, pool)
+                        });
+                        // the receiver only hangs up if a decoder panicked
+                        let _ = tx.send((i, chunks));
+                    });
+            });
+
+            let decoded = self.decode.install(|| {
+                rx.into_iter()
+                    .par_bridge()
+                    .map(|(i, chunks)| {
+                        let s = chunks.and_then(|chunks| {
+                            decode(columns[i], bytes.with_decompressed(&chunks))
+                        });
+                        (i, s)
+                    })
+                    .collect::<Vec<_>>()
+            });
+            for (i, s) in decoded {
+                out[i] = Some(s);
+            }
+        });
+
+        out.into_iter()
+            .map(|s| s.expect("every column is decoded"))
+            .collect()
+    }
+}
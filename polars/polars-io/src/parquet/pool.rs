@@ -0,0 +1,44 @@
+use std::sync::Mutex;
+
+/// A pool of the buffers that pages are decompressed into.
+///
+/// Every column chunk decompresses its pages into a single buffer. Without a pool that
+/// buffer is allocated for every column chunk and freed once the chunk is decoded; with a
+/// pool it is reused by the next column chunk, also across row groups and reads. A pool
+/// holds at most as many buffers as column chunks were decoded concurrently.
+///
+/// A [`DecodePipeline`](super::DecodePipeline) decompresses the pages of a column chunk
+/// before they are decoded, into a buffer per page, so a pool shared with it holds up to
+/// as many buffers as pages were held by the pipeline.
+#[derive(Debug, Default)]
+pub struct DecompressionPool {
+    buffers: Mutex<Vec<Vec<u8>>>,
+}
+
+impl DecompressionPool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of buffers that are not in use.
+    pub fn len(&self) -> usize {
+        self.buffers.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Take the largest buffer, or an empty one if the pool is empty.
+    pub(super) fn take(&self) -> Vec<u8> {
+        self.buffers.lock().unwrap().pop().unwrap_or_default()
+    }
+
+    pub(super) fn put(&self, mut buffer: Vec<u8>) {
+        buffer.clear();
+        let mut buffers = self.buffers.lock().unwrap();
+        // keep the buffers sorted by capacity, so that the largest is taken first
+        let idx = buffers.partition_point(|buf| buf.capacity() < buffer.capacity());
+        buffers.insert(idx, buffer);
+    }
+}
@@ -1,46 +1,98 @@
+use crate::parquet::read::DEFAULT_STATISTICS_TRUNCATED_LEN;
+use crate::parquet::read_impl::catch_malformed;
+use crate::predicates::{PhysicalIoExpr, StatsEvaluation, StatsEvaluator};
 use crate::ArrowResult;
-use arrow::array::Array;
+use arrow::array::{Array, UInt64Array};
 use arrow::compute::concatenate::concatenate;
+use arrow::error::Error as ArrowError;
 use arrow::io::parquet::read::statistics::{self, deserialize, Statistics};
-use arrow::io::parquet::read::RowGroupMetaData;
+use arrow::io::parquet::read::{
+    get_field_columns, FileMetaData, PrimitiveConvertedType, PrimitiveLogicalType, RowGroupMetaData,
+};
+use once_cell::sync::Lazy;
 use polars_core::prelude::*;
+use std::sync::RwLock;
 
 /// The statistics for a column in a Parquet file
 /// they typically hold
 /// - max value
 /// - min value
 /// - null_count
+///
+/// If the max value may have been truncated by the writer, it is replaced by an upper
+/// bound of all values that start with it.
+///
+/// The statistics don't record whether a float column has `NaN`s. Writers are expected to
+/// leave them out of the min and max, so `NaN`s may hide between any bounds.
+///
+/// A min value that is greater than the max value shows that the statistics are corrupt;
+/// the bounds of such a column chunk are then ignored.
 #[cfg_attr(debug_assertions, derive(Debug))]
-pub struct ColumnStats(Statistics, Field);
+pub struct ColumnStats {
+    stats: Statistics,
+    field: Field,
+    max_is_truncated: bool,
+    bounds_are_inverted: bool,
+}
 
 impl ColumnStats {
     pub fn dtype(&self) -> DataType {
-        self.1.data_type().clone()
+        self.field.data_type().clone()
     }
 
     pub fn null_count(&self) -> Option<usize> {
-        match &self.0.null_count {
-            statistics::Count::Single(arr) => {
-                if arr.is_valid(0) {
-                    Some(arr.value(0) as usize)
-                } else {
-                    None
-                }
-            }
-            _ => None,
-        }
+        single_count(&self.stats.null_count)
+    }
+
+    /// The number of distinct values, if the writer stored it.
+    pub fn distinct_count(&self) -> Option<usize> {
+        single_count(&self.stats.distinct_count)
+    }
+
+    /// Whether the writer may have truncated the max value, see
+    /// [`ParquetReader::with_statistics_truncated_len`](super::ParquetReader::with_statistics_truncated_len).
+    /// A truncated min value is still a valid lower bound.
+    pub fn max_is_truncated(&self) -> bool {
+        self.max_is_truncated
     }
 
+    /// Whether the min value is greater than the max value. The bounds are then not
+    /// returned by [`ColumnStats::to_min_max`].
+    pub fn bounds_are_inverted(&self) -> bool {
+        self.bounds_are_inverted
+    }
+
+    /// Whether the column may have `NaN` values, i.e. whether it is a float column. As
+    /// `NaN` compares `false` to any value, a comparison of the bounds then doesn't
+    /// show that all rows pass.
+    pub fn may_contain_nan(&self) -> bool {
+        matches!(
+            self.field.data_type(),
+            DataType::Float32 | DataType::Float64
+        )
+    }
+
+    /// The `[min, max]` bounds, of numeric, string and temporal columns. A `NaN` bound
+    /// doesn't bound anything, in that case `None` is returned.
     pub fn to_min_max(&self) -> Option<Series> {
-        let max_val = &*self.0.max_value;
-        let min_val = &*self.0.min_value;
+        if self.bounds_are_inverted {
+            return None;
+        }
+        let max_val = &*self.stats.max_value;
+        let min_val = &*self.stats.min_value;
 
         let dtype = DataType::from(min_val.data_type());
-        if dtype.is_numeric() || matches!(dtype, DataType::Utf8) {
-            let arr = concatenate(&[min_val, max_val]).unwrap();
-            let s = Series::try_from(("", arr)).unwrap();
-            if s.null_count() > 0 {
+        if has_bounds(&dtype) {
+            let arr = concatenate(&[min_val, max_val]).ok()?;
+            let s = Series::try_from(("", arr)).ok()?;
+            // the column may be missing in the row group
+            if s.len() != 2 || s.null_count() > 0 || s.is_nan().ok()?.any() {
                 None
+            } else if self.max_is_truncated {
+                // the values may be greater than the truncated max, but they all start with it
+                let ca = s.utf8().ok()?;
+                let upper = prefix_upper_bound(ca.get(1)?)?;
+                Some(Series::new("", &[ca.get(0)?, upper.as_str()]))
             } else {
                 Some(s)
             }
@@ -48,12 +100,81 @@ impl ColumnStats {
             None
         }
     }
+
+    /// The `(min, max)` values of the statistics. The statistics should
+    /// be collected from a single row group.
+    pub fn to_min_max_values(&self) -> Option<(AnyValue<'static>, AnyValue<'static>)> {
+        let s = self.to_min_max()?;
+        let min = s.get(0).into_static().ok()?;
+        let max = s.get(1).into_static().ok()?;
+        Some((min, max))
+    }
+}
+
+/// Whether [`ColumnStats::to_min_max`] returns the bounds of a column of `dtype`.
+fn has_bounds(dtype: &DataType) -> bool {
+    dtype.is_numeric()
+        || matches!(
+            dtype,
+            DataType::Utf8 | DataType::Date | DataType::Datetime(_, _) | DataType::Duration(_)
+        )
+}
+
+/// An error if the `(min, max)` values of `field` are not supported, i.e. if
+/// [`ColumnStats::to_min_max_values`] never returns them, as the bounds of the column are
+/// then not unknown but unsupported.
+fn check_bounds_dtype(field: &ArrowField) -> Result<()> {
+    // the statistics of a dictionary column are of its values
+    let dtype = match &field.data_type {
+        ArrowDataType::Dictionary(_, values, _) => DataType::from(values.as_ref()),
+        dtype => DataType::from(dtype),
+    };
+    // a time zone can't be borrowed for `'static`
+    if has_bounds(&dtype) && !matches!(dtype, DataType::Datetime(_, Some(_))) {
+        Ok(())
+    } else {
+        Err(PolarsError::InvalidOperation(
+            format!(
+                "the bounds of column '{}' of dtype {:?} are not supported",
+                field.name, dtype
+            )
+            .into(),
+        ))
+    }
+}
+
+fn single_count(count: &statistics::Count) -> Option<usize> {
+    match count {
+        statistics::Count::Single(arr) => {
+            if arr.is_valid(0) {
+                Some(arr.value(0) as usize)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The smallest string that is greater than every string that starts with `prefix`,
+/// or `None` if there is no such string.
+pub fn prefix_upper_bound(prefix: &str) -> Option<String> {
+    let mut chars = prefix.chars().collect::<Vec<_>>();
+    while let Some(c) = chars.pop() {
+        // `char::from_u32` skips the surrogate range
+        if let Some(next) = (c as u32 + 1..=char::MAX as u32).find_map(char::from_u32) {
+            chars.push(next);
+            return Some(chars.into_iter().collect());
+        }
+    }
+    None
 }
 
 /// A collection of column stats with a known schema.
 pub struct BatchStats {
     schema: Schema,
     stats: Vec<ColumnStats>,
+    num_rows: usize,
 }
 
 impl BatchStats {
@@ -64,25 +185,485 @@ impl BatchStats {
     pub fn schema(&self) -> &Schema {
         &self.schema
     }
+
+    /// The number of rows the statistics are collected from.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Iterate over the column names and their stats.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &ColumnStats)> {
+        self.schema.iter_names().zip(self.stats.iter())
+    }
+}
+
+/// The parquet type of the columns of which a [`StatisticsDecoder`] decodes the statistics.
+#[derive(Clone, Debug, PartialEq)]
+pub enum StatisticsType {
+    Logical(PrimitiveLogicalType),
+    Converted(PrimitiveConvertedType),
+}
+
+/// Decodes the plain encoded bytes of a min or max value of the statistics of a column
+/// chunk into a value that compares like the values of the column, or `None` if the
+/// bytes are not a valid value.
+pub type StatisticsDecoder = dyn Fn(&[u8]) -> Option<AnyValue<'static>> + Send + Sync;
+
+static STATISTICS_DECODERS: Lazy<RwLock<Vec<(StatisticsType, Arc<StatisticsDecoder>)>>> =
+    Lazy::new(Default::default);
+
+/// Register `decoder` for the statistics of the columns of type `statistics_type`, e.g.
+/// a fixed point type of which the bounds don't compare as their bytes. The decoder
+/// replaces the built-in decoding of the statistics of these columns in every read, so
+/// that their row groups can be pruned. A decoder that was registered for the type
+/// before is replaced. The logical type of a column takes precedence over its converted
+/// type.
+pub fn register_statistics_decoder(
+    statistics_type: StatisticsType,
+    decoder: Arc<StatisticsDecoder>,
+) {
+    let mut decoders = STATISTICS_DECODERS.write().unwrap();
+    decoders.retain(|(registered, _)| registered != &statistics_type);
+    decoders.push((statistics_type, decoder));
+}
+
+/// Remove the decoder registered for `statistics_type`. Returns whether there was one.
+pub fn unregister_statistics_decoder(statistics_type: &StatisticsType) -> bool {
+    let mut decoders = STATISTICS_DECODERS.write().unwrap();
+    let len = decoders.len();
+    decoders.retain(|(registered, _)| registered != statistics_type);
+    decoders.len() < len
+}
+
+/// The registered decoder of the type of the single column chunk of `column`, if any.
+fn registered_decoder(md: &[RowGroupMetaData], column: &str) -> Option<Arc<StatisticsDecoder>> {
+    let decoders = STATISTICS_DECODERS.read().unwrap();
+    if decoders.is_empty() {
+        return None;
+    }
+    let chunks = get_field_columns(md.first()?.columns(), column);
+    let primitive_type = match chunks.as_slice() {
+        [chunk] => &chunk.descriptor().descriptor.primitive_type,
+        _ => return None,
+    };
+    let find = |statistics_type: StatisticsType| {
+        decoders
+            .iter()
+            .find(|(registered, _)| registered == &statistics_type)
+            .map(|(_, decoder)| decoder.clone())
+    };
+    let logical = primitive_type.logical_type.as_ref();
+    let converted = primitive_type.converted_type.as_ref();
+    logical
+        .and_then(|logical| find(StatisticsType::Logical(logical.clone())))
+        .or_else(|| {
+            converted.and_then(|converted| find(StatisticsType::Converted(converted.clone())))
+        })
+}
+
+/// Decode the statistics of `column` in the row groups `md` with a registered decoder.
+/// The bounds of a row group that the decoder can't decode are null.
+fn decode_statistics(
+    md: &[RowGroupMetaData],
+    column: &str,
+    decoder: &StatisticsDecoder,
+) -> Result<Statistics> {
+    let mut min_values = Vec::with_capacity(md.len());
+    let mut max_values = Vec::with_capacity(md.len());
+    let mut null_counts = Vec::with_capacity(md.len());
+    let mut distinct_counts = Vec::with_capacity(md.len());
+    for rg in md {
+        let chunks = get_field_columns(rg.columns(), column);
+        let stats = chunks
+            .first()
+            .and_then(|chunk| chunk.column_chunk().meta_data.as_ref())
+            .and_then(|meta| meta.statistics.as_ref());
+        // the deprecated `min` and `max` are written by old writers
+        let decode = |value: &Option<Vec<u8>>, deprecated: &Option<Vec<u8>>| {
+            value
+                .as_ref()
+                .or_else(|| deprecated.as_ref())
+                .and_then(|bytes| decoder(bytes))
+                .unwrap_or(AnyValue::Null)
+        };
+        min_values.push(stats.map_or(AnyValue::Null, |s| decode(&s.min_value, &s.min)));
+        max_values.push(stats.map_or(AnyValue::Null, |s| decode(&s.max_value, &s.max)));
+        null_counts.push(stats.and_then(|s| s.null_count).map(|count| count as u64));
+        distinct_counts.push(
+            stats
+                .and_then(|s| s.distinct_count)
+                .map(|count| count as u64),
+        );
+    }
+    Ok(Statistics {
+        distinct_count: statistics::Count::Single(UInt64Array::from(distinct_counts)),
+        null_count: statistics::Count::Single(UInt64Array::from(null_counts)),
+        min_value: Series::from_any_values("", &min_values)?.to_arrow(0),
+        max_value: Series::from_any_values("", &max_values)?.to_arrow(0),
+    })
 }
 
 /// Collect the statistics in a column chunk.
+///
+/// String max values of at least `truncated_len` bytes are considered to be truncated.
 pub(crate) fn collect_statistics(
     md: &[RowGroupMetaData],
     arrow_schema: &ArrowSchema,
+    truncated_len: Option<usize>,
 ) -> ArrowResult<Option<BatchStats>> {
     let mut schema = Schema::with_capacity(arrow_schema.fields.len());
     let mut stats = vec![];
 
     for fld in &arrow_schema.fields {
-        let st = deserialize(fld, md)?;
+        let st = match registered_decoder(md, &fld.name) {
+            Some(decoder) => decode_statistics(md, &fld.name, &*decoder)
+                .map_err(|e| ArrowError::ExternalFormat(e.to_string()))?,
+            None => catch_malformed(|| Ok(deserialize(fld, md)?))
+                .map_err(|e| ArrowError::ExternalFormat(e.to_string()))?,
+        };
         schema.with_column(fld.name.to_string(), (&fld.data_type).into());
-        stats.push(ColumnStats(st, Field::from(fld)));
+        let max_is_truncated = match truncated_len {
+            Some(len) => max_str_len(st.max_value.as_ref()).map_or(false, |max| max >= len),
+            None => false,
+        };
+        let mut column = ColumnStats {
+            stats: st,
+            field: Field::from(fld),
+            max_is_truncated,
+            bounds_are_inverted: false,
+        };
+        column.bounds_are_inverted = bounds_are_inverted(&column);
+        if column.bounds_are_inverted && std::env::var("POLARS_VERBOSE").is_ok() {
+            eprintln!(
+                "the min value of column '{}' is greater than its max value, its statistics are ignored",
+                fld.name
+            );
+        }
+        stats.push(column);
     }
 
     Ok(if stats.is_empty() {
         None
     } else {
-        Some(BatchStats { schema, stats })
+        Some(BatchStats {
+            schema,
+            stats,
+            num_rows: md.iter().map(|rg| rg.num_rows()).sum(),
+        })
     })
 }
+
+/// Whether the min value of a single row group is greater than its max value. A truncated
+/// max value may be smaller than the min value, but its upper bound may not.
+fn bounds_are_inverted(column: &ColumnStats) -> bool {
+    match column.to_min_max() {
+        Some(s) => s
+            .slice(0, 1)
+            .gt(&s.slice(1, 1))
+            .map_or(false, |gt| gt.get(0) == Some(true)),
+        None => false,
+    }
+}
+
+/// The byte length of the largest string of a single row group, if the statistics are of
+/// strings.
+fn max_str_len(max_value: &dyn Array) -> Option<usize> {
+    use arrow::array::Utf8Array;
+
+    let any = max_value.as_any();
+    if let Some(arr) = any.downcast_ref::<Utf8Array<i32>>() {
+        arr.iter().flatten().map(str::len).max()
+    } else if let Some(arr) = any.downcast_ref::<Utf8Array<i64>>() {
+        arr.iter().flatten().map(str::len).max()
+    } else {
+        None
+    }
+}
+
+/// The statistics of all row groups in a file.
+///
+/// Collecting the statistics requires deserializing them from the file
+/// metadata. For repeated reads of the same file they can be collected once
+/// and passed to every read.
+pub struct FileStatistics {
+    row_groups: Vec<Option<BatchStats>>,
+}
+
+impl FileStatistics {
+    /// Collect the statistics of all row groups in a file. String max values may be
+    /// truncated, see [`DEFAULT_STATISTICS_TRUNCATED_LEN`].
+    pub fn collect(metadata: &FileMetaData, schema: &ArrowSchema) -> Result<Self> {
+        Self::collect_truncated(metadata, schema, DEFAULT_STATISTICS_TRUNCATED_LEN)
+    }
+
+    /// Collect the statistics of all row groups in a file, of which string max values of
+    /// at least `truncated_len` bytes may be truncated.
+    pub fn collect_truncated(
+        metadata: &FileMetaData,
+        schema: &ArrowSchema,
+        truncated_len: Option<usize>,
+    ) -> Result<Self> {
+        let row_groups = metadata
+            .row_groups
+            .iter()
+            .map(|md| collect_statistics(std::slice::from_ref(md), schema, truncated_len))
+            .collect::<ArrowResult<Vec<_>>>()?;
+        Ok(FileStatistics { row_groups })
+    }
+
+    /// Number of row groups these statistics were collected from.
+    pub fn num_row_groups(&self) -> usize {
+        self.row_groups.len()
+    }
+
+    pub fn row_group(&self, rg: usize) -> Option<&BatchStats> {
+        self.row_groups.get(rg).and_then(|stats| stats.as_ref())
+    }
+}
+
+/// Get the `(min, max)` statistics of every column per row group. The bounds of a
+/// temporal column are of its logical dtype, e.g. `AnyValue::Date`.
+///
+/// Columns that lack statistics in a row group are omitted from the map of that row group,
+/// as are boolean, nested and time zone aware datetime columns.
+pub fn row_group_bounds(
+    metadata: &FileMetaData,
+    schema: &ArrowSchema,
+) -> Result<Vec<PlHashMap<String, (AnyValue<'static>, AnyValue<'static>)>>> {
+    metadata
+        .row_groups
+        .iter()
+        .map(|md| {
+            let mut bounds = PlHashMap::new();
+            if let Some(stats) = collect_statistics(std::slice::from_ref(md), schema, None)? {
+                for (name, st) in stats.iter() {
+                    if let Some(min_max) = st.to_min_max_values() {
+                        bounds.insert(name.clone(), min_max);
+                    }
+                }
+            }
+            Ok(bounds)
+        })
+        .collect()
+}
+
+/// Get the `(min, max)` statistics of `column` across all row groups of a file. The
+/// bounds of numeric, string and temporal columns are supported, an error is returned
+/// for the columns of other dtypes, e.g. boolean or time zone aware datetime columns.
+///
+/// Returns `None` if a row group lacks statistics of the column, or has values that
+/// don't compare, e.g. `NaN`, as the bounds of the file are then unknown.
+pub fn file_bounds(
+    metadata: &FileMetaData,
+    schema: &ArrowSchema,
+    column: &str,
+) -> Result<Option<(AnyValue<'static>, AnyValue<'static>)>> {
+    use std::cmp::Ordering::*;
+
+    let column_i = schema.try_index_of(column)?;
+    check_bounds_dtype(&schema.fields[column_i])?;
+    let schema = ArrowSchema::from(vec![schema.fields[column_i].clone()]);
+    let mut bounds: Option<(AnyValue<'static>, AnyValue<'static>)> = None;
+    for md in &metadata.row_groups {
+        let stats = collect_statistics(std::slice::from_ref(md), &schema, None)?;
+        let (min, max) = match stats.and_then(|stats| stats.stats[0].to_min_max_values()) {
+            Some(min_max) => min_max,
+            None => return Ok(None),
+        };
+        bounds = match bounds {
+            None => Some((min, max)),
+            Some((lo, hi)) => {
+                let lo = match compare_values(&min, &lo) {
+                    Some(Less) => min,
+                    Some(_) => lo,
+                    None => return Ok(None),
+                };
+                let hi = match compare_values(&max, &hi) {
+                    Some(Greater) => max,
+                    Some(_) => hi,
+                    None => return Ok(None),
+                };
+                Some((lo, hi))
+            }
+        };
+    }
+    Ok(bounds)
+}
+
+/// Per row group, whether it holds some of the `k` rows with the smallest values of
+/// `column`, or the largest if not `ascending`.
+///
+/// The row groups must be sorted by `column` according to their statistics: the max of
+/// every row group is at most the min of the next, or the min is at least the max of
+/// the next. Parquet2 doesn't expose the sorting columns that writers may declare, so
+/// the statistics are the declaration. The rows within a row group may be in any order.
+/// The dtype of `column` must be supported by [`file_bounds`].
+pub(super) fn top_k_row_groups(
+    metadata: &FileMetaData,
+    schema: &ArrowSchema,
+    column: &str,
+    k: usize,
+    ascending: bool,
+) -> Result<Vec<bool>> {
+    use std::cmp::Ordering::*;
+
+    let column_i = schema.try_index_of(column)?;
+    check_bounds_dtype(&schema.fields[column_i])?;
+    let field_schema = ArrowSchema::from(vec![schema.fields[column_i].clone()]);
+    let not_sorted = || {
+        PolarsError::ComputeError(
+            format!(
+                "the statistics don't show that the row groups are sorted by '{}' without nulls, sort the frame instead",
+                column
+            )
+            .into(),
+        )
+    };
+
+    // the `(row group, min, max)` of the row groups with rows
+    let mut bounds = Vec::with_capacity(metadata.row_groups.len());
+    for (rg, md) in metadata.row_groups.iter().enumerate() {
+        if md.num_rows() == 0 {
+            continue;
+        }
+        let stats = collect_statistics(std::slice::from_ref(md), &field_schema, None)?;
+        let column = stats.as_ref().map(|stats| &stats.stats[0]);
+        match column {
+            Some(column) if column.null_count() == Some(0) => {
+                let (min, max) = column.to_min_max_values().ok_or_else(not_sorted)?;
+                bounds.push((rg, min, max));
+            }
+            _ => return Err(not_sorted()),
+        }
+    }
+
+    let at_most = |l: &AnyValue, r: &AnyValue| matches!(compare_values(l, r), Some(Less | Equal));
+    let from_start = if bounds.windows(2).all(|w| at_most(&w[0].2, &w[1].1)) {
+        ascending
+    } else if bounds.windows(2).all(|w| at_most(&w[1].2, &w[0].1)) {
+        !ascending
+    } else {
+        return Err(not_sorted());
+    };
+    if !from_start {
+        bounds.reverse();
+    }
+
+    let mut selected = vec![false; metadata.row_groups.len()];
+    let mut rows = 0;
+    for (rg, _, _) in bounds {
+        if rows >= k {
+            break;
+        }
+        rows += metadata.row_groups[rg].num_rows();
+        selected[rg] = true;
+    }
+    Ok(selected)
+}
+
+/// Inclusive `(lower, upper)` bounds per column, used to prune row groups without
+/// a physical expression. A bound of `None` is unbounded. A bound is cast to the dtype of
+/// its column, e.g. a `Date` bound of a `Datetime` column is its midnight, and an error
+/// is returned if it can't be.
+///
+/// Only row groups are pruned, the rows of the row groups that are read are not filtered.
+#[derive(Clone, Debug, Default)]
+pub struct ColumnRanges(
+    pub PlHashMap<String, (Option<AnyValue<'static>>, Option<AnyValue<'static>>)>,
+);
+
+fn compare_values(left: &AnyValue, right: &AnyValue) -> Option<std::cmp::Ordering> {
+    match (left, right) {
+        (AnyValue::Utf8Owned(l), AnyValue::Utf8Owned(r)) => l.partial_cmp(r),
+        (AnyValue::Utf8Owned(l), AnyValue::Utf8(r)) => l.as_str().partial_cmp(*r),
+        (AnyValue::Utf8(l), AnyValue::Utf8Owned(r)) => (*l).partial_cmp(r.as_str()),
+        _ => left.partial_cmp(right),
+    }
+}
+
+/// `value` cast to `dtype` in its physical representation, in which it compares to the
+/// physical bounds of a column of `dtype`, e.g. a `Datetime` in milliseconds to the
+/// microseconds of the statistics of a column.
+fn to_physical_value(
+    column: &str,
+    value: &AnyValue,
+    dtype: &DataType,
+) -> Result<AnyValue<'static>> {
+    let s = Series::from_any_values("", std::slice::from_ref(value))?.cast(dtype)?;
+    let physical = s.to_physical_repr();
+    match physical.get(0) {
+        AnyValue::Null => Err(PolarsError::ComputeError(
+            format!(
+                "the bound {:?} of column '{}' can not be cast to its dtype {:?}",
+                value, column, dtype
+            )
+            .into(),
+        )),
+        value => value.into_static(),
+    }
+}
+
+impl StatsEvaluator for ColumnRanges {
+    fn evaluate_stats(&self, stats: &BatchStats) -> Result<StatsEvaluation> {
+        use std::cmp::Ordering::*;
+
+        let mut eval = StatsEvaluation::DefinitelyTrue;
+        for (name, (lower, upper)) in &self.0 {
+            let column = stats.get_stats(name)?;
+            let column_eval = match column.to_min_max() {
+                Some(min_max) => {
+                    let bound = |value: &Option<AnyValue>| {
+                        value
+                            .as_ref()
+                            .map(|value| to_physical_value(name, value, min_max.dtype()))
+                            .transpose()
+                    };
+                    let (lower, upper) = (bound(lower)?, bound(upper)?);
+                    let min_max = min_max.to_physical_repr();
+                    let (min, max) = (min_max.get(0), min_max.get(1));
+                    let cmp_lower = |v: &AnyValue| lower.as_ref().map(|lo| compare_values(v, lo));
+                    let cmp_upper = |v: &AnyValue| upper.as_ref().map(|hi| compare_values(v, hi));
+
+                    if matches!(cmp_lower(&max), Some(Some(Less)))
+                        || matches!(cmp_upper(&min), Some(Some(Greater)))
+                    {
+                        StatsEvaluation::DefinitelyFalse
+                    } else if matches!(cmp_lower(&min), None | Some(Some(Equal | Greater)))
+                        && matches!(cmp_upper(&max), None | Some(Some(Less | Equal)))
+                        && column.null_count() == Some(0)
+                        && !column.may_contain_nan()
+                    {
+                        StatsEvaluation::DefinitelyTrue
+                    } else {
+                        StatsEvaluation::Unknown
+                    }
+                }
+                None => StatsEvaluation::Unknown,
+            };
+            eval = eval.and(column_eval);
+        }
+        Ok(eval)
+    }
+}
+
+impl PhysicalIoExpr for ColumnRanges {
+    fn evaluate(&self, _df: &DataFrame) -> Result<Series> {
+        Err(PolarsError::InvalidOperation(
+            "column ranges can only be used to prune row groups".into(),
+        ))
+    }
+
+    fn display(&self) -> Option<String> {
+        let mut ranges = self
+            .0
+            .iter()
+            .map(|(name, range)| format!("{}: {:?}", name, range))
+            .collect::<Vec<_>>();
+        ranges.sort();
+        Some(ranges.join(", "))
+    }
+
+    fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
+        Some(self)
+    }
+}
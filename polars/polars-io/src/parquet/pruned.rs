@@ -0,0 +1,104 @@
+use crate::parquet::fingerprint::ReadFingerprint;
+use crate::parquet::predicates::FileStatistics;
+use crate::parquet::read_impl::evaluate_row_group;
+use crate::predicates::{PhysicalIoExpr, StatsEvaluation};
+use arrow::datatypes::ArrowSchema;
+use arrow::io::parquet::read::FileMetaData;
+use polars_core::prelude::*;
+use std::sync::Arc;
+
+/// The outcome of evaluating a predicate against the statistics of every row group of
+/// a file, returned by [`ParquetReader::finish_with_pruned_row_groups`].
+///
+/// Reads of the same file with the same predicate can pass it to
+/// [`ParquetReader::with_pruned_row_groups`] to skip evaluating the statistics again,
+/// e.g. when only the projection differs.
+///
+/// [`ParquetReader::finish_with_pruned_row_groups`]: super::ParquetReader::finish_with_pruned_row_groups
+/// [`ParquetReader::with_pruned_row_groups`]: super::ParquetReader::with_pruned_row_groups
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrunedRowGroups {
+    metadata_id: u64,
+    predicate: Option<String>,
+    evaluations: Vec<StatsEvaluation>,
+}
+
+/// Identify a file by its row groups and the location of their column chunks.
+fn metadata_id(metadata: &FileMetaData) -> u64 {
+    let mut id = ReadFingerprint::default();
+    id.write_usize(metadata.num_rows);
+    id.write_usize(metadata.row_groups.len());
+    for md in &metadata.row_groups {
+        id.write_usize(md.num_rows());
+        id.write_usize(md.columns().len());
+        for column in md.columns() {
+            let (start, len) = column.byte_range();
+            id.write_usize(start as usize);
+            id.write_usize(len as usize);
+        }
+    }
+    id.value()
+}
+
+impl PrunedRowGroups {
+    pub(super) fn evaluate(
+        metadata: &FileMetaData,
+        schema: &ArrowSchema,
+        predicate: Option<&Arc<dyn PhysicalIoExpr>>,
+        statistics: Option<&FileStatistics>,
+        truncated_len: Option<usize>,
+    ) -> Result<Self> {
+        let evaluations = (0..metadata.row_groups.len())
+            .map(|rg| {
+                evaluate_row_group(predicate, rg, metadata, schema, statistics, truncated_len)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(PrunedRowGroups {
+            metadata_id: metadata_id(metadata),
+            predicate: predicate.and_then(|pred| pred.display()),
+            evaluations,
+        })
+    }
+
+    /// Check that these row groups were pruned in `metadata` with `predicate`. Predicates
+    /// without a [`PhysicalIoExpr::display`] representation can not be checked.
+    pub(super) fn validate(
+        &self,
+        metadata: &FileMetaData,
+        predicate: Option<&Arc<dyn PhysicalIoExpr>>,
+    ) -> Result<()> {
+        if self.metadata_id != metadata_id(metadata) {
+            return Err(PolarsError::InvalidOperation(
+                "the pruned row groups are of a different file".into(),
+            ));
+        }
+        if let (Some(expected), Some(Some(repr))) =
+            (&self.predicate, predicate.map(|pred| pred.display()))
+        {
+            if *expected != repr {
+                return Err(PolarsError::InvalidOperation(
+                    format!(
+                        "the row groups were pruned with predicate {}, not with {}",
+                        expected, repr
+                    )
+                    .into(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn evaluation(&self, rg: usize) -> StatsEvaluation {
+        self.evaluations[rg]
+    }
+
+    /// The indexes of the row groups that are read.
+    pub fn row_groups(&self) -> Vec<usize> {
+        self.evaluations
+            .iter()
+            .enumerate()
+            .filter(|(_, eval)| eval.should_read())
+            .map(|(rg, _)| rg)
+            .collect()
+    }
+}
@@ -1,13 +1,54 @@
 use crate::aggregations::ScanAggregation;
 use crate::mmap::MmapBytesReader;
-use crate::parquet::read_impl::read_parquet;
+use crate::parquet::deferred::DeferredParquetFrame;
+use crate::parquet::fingerprint::ReadFingerprint;
+use crate::parquet::interval::{set_interval_dtypes, ParquetInterval};
+#[cfg(all(feature = "json", feature = "dtype-struct"))]
+use crate::parquet::json_column::JsonExpansion;
+use crate::parquet::metadata_column::MetadataColumn;
+use crate::parquet::pipeline::DecodePipeline;
+use crate::parquet::pool::DecompressionPool;
+use crate::parquet::predicates::{top_k_row_groups, ColumnRanges, FileStatistics};
+use crate::parquet::pruned::PrunedRowGroups;
+#[cfg(feature = "dtype-categorical")]
+use crate::parquet::read_impl::is_dictionary_encoded;
+use crate::parquet::read_impl::{
+    catch_malformed, read_parquet, ColumnError, ConstraintViolation, OnSeries, ParquetReadOptions,
+    PostDecode, ReadDiagnostics, RowGroupChunks, RowGroupDecision,
+};
+use crate::parquet::sample::SampleSpec;
+use crate::parquet::spec::ReadSpec;
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
-use crate::RowCount;
+use crate::{ArrowResult, RowCount};
+#[cfg(feature = "dtype-categorical")]
+use arrow::datatypes::IntegerType;
 use arrow::io::parquet::read;
+use arrow::io::parquet::read::FileMetaData;
+use arrow::io::parquet::read::ParquetType;
+use arrow::io::parquet::read::{PrimitiveConvertedType, PrimitiveLogicalType};
 use polars_core::prelude::*;
-use std::io::{Read, Seek};
+use polars_core::utils::accumulate_dataframes_vertical;
+#[cfg(feature = "dtype-categorical")]
+use polars_core::StringCacheHolder;
+use std::fs::OpenOptions;
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Instant;
+
+/// The compressed size of a file below which [`ParquetReader`] reads serially, see
+/// [`ParquetReader::with_parallel_threshold`].
+pub const DEFAULT_PARALLEL_THRESHOLD_BYTES: usize = 2 << 20;
+
+/// The gap up to which the column chunks of a row group are read from a [`ParquetSource`]
+/// as a single range, see [`ParquetReader::with_coalesce_gap`].
+pub const DEFAULT_COALESCE_GAP_BYTES: u64 = 1 << 20;
+
+/// The length from which string max statistics are considered to be truncated, see
+/// [`ParquetReader::with_statistics_truncated_len`]. Every string max may be truncated
+/// by default, as the file does not tell whether its writer truncates them.
+pub const DEFAULT_STATISTICS_TRUNCATED_LEN: Option<usize> = Some(0);
 
 /// Read Apache parquet format into a DataFrame.
 #[must_use]
@@ -18,48 +59,825 @@ pub struct ParquetReader<R: Read + Seek> {
     columns: Option<Vec<String>>,
     projection: Option<Vec<usize>>,
     parallel: bool,
+    parallel_threshold_bytes: usize,
+    coalesce_gap_bytes: u64,
+    reverse: bool,
     row_count: Option<RowCount>,
+    row_count_collision: RowCountCollision,
+    datetime_unit: Option<TimeUnit>,
+    use_arrow_metadata: bool,
+    limit_after_predicate: bool,
+    limit_before_agg: bool,
+    metadata: Option<FileMetaData>,
+    statistics: Option<Arc<FileStatistics>>,
+    null_dtypes: Option<Schema>,
+    null_fill_dtypes: Option<PlHashMap<String, DataType>>,
+    max_categories: Option<(usize, CategoricalOverflow)>,
+    dictionary_columns: DictionaryColumns,
+    offset_widths: Option<PlHashMap<String, OffsetWidth>>,
+    #[cfg(feature = "dtype-categorical")]
+    string_cache: Option<StringCacheHolder>,
+    interval: ParquetInterval,
+    read_spec: Option<ReadSpec>,
+    pad_ragged_columns: bool,
+    verify_row_counts: bool,
+    windowed_mmap: bool,
+    prune_only: bool,
+    sample: Option<SampleSpec>,
+    allow_missing_columns: bool,
+    column_ranges: Option<ColumnRanges>,
+    since: Option<(String, AnyValue<'static>)>,
+    top_k: Option<(String, usize, bool)>,
+    limit_distribution: LimitDistribution,
+    constraints: Option<Vec<(String, Arc<dyn PhysicalIoExpr>)>>,
+    required_columns: Option<(Vec<String>, RequiredColumnNulls)>,
+    chunk_lengths: Option<Vec<usize>>,
+    chunk_length: Option<usize>,
+    post_predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    post_decode: Option<Arc<PostDecode>>,
+    on_series: Option<Arc<OnSeries>>,
+    #[cfg(all(feature = "json", feature = "dtype-struct"))]
+    json_expansion: Option<JsonExpansion>,
+    distinct_on: Option<Vec<String>>,
+    row_count_i64: bool,
+    predicate_only_columns: Option<Vec<String>>,
+    deadline: Option<Instant>,
+    row_group_id: Option<String>,
+    metadata_columns: Option<Vec<MetadataColumn>>,
+    duplicate_columns: Option<DuplicateColumns>,
+    statistics_truncated_len: Option<usize>,
+    decompression_pool: Option<Arc<DecompressionPool>>,
+    decode_pipeline: Option<Arc<DecodePipeline>>,
+    max_decompressed_bytes: Option<usize>,
+    row_selection: Option<BooleanChunked>,
+    pruned_row_groups: Option<PrunedRowGroups>,
+}
+
+/// Infer the arrow schema of a parquet file. If `use_arrow_metadata` is set, the
+/// `ARROW:schema` key embedded by arrow writers (such as polars) takes precedence
+/// over the schema derived from the parquet types.
+pub(super) fn infer_schema(
+    metadata: &FileMetaData,
+    use_arrow_metadata: bool,
+    interval: ParquetInterval,
+) -> ArrowResult<ArrowSchema> {
+    let mut schema = if use_arrow_metadata {
+        read::infer_schema(metadata)?
+    } else {
+        let fields = read::schema::parquet_to_arrow_schema(metadata.schema().fields());
+        ArrowSchema::from(fields)
+    };
+    #[cfg(feature = "dtype-categorical")]
+    enum_to_dictionary(metadata.schema().fields(), &mut schema);
+    json_to_utf8(metadata.schema().fields(), &mut schema);
+    widen_small_integers(&mut schema);
+    set_interval_dtypes(metadata.schema().fields(), &mut schema.fields, interval);
+    Ok(schema)
+}
+
+/// Read the 8 and 16 bit integer columns of which polars is compiled without the dtype
+/// as 32 bit integers of the same signedness, so that unsigned values stay positive.
+// with all integer dtypes enabled only the wildcard arm remains
+#[allow(clippy::match_single_binding)]
+fn widen_small_integers(schema: &mut ArrowSchema) {
+    for field in schema.fields.iter_mut() {
+        match field.data_type {
+            #[cfg(not(feature = "dtype-u8"))]
+            ArrowDataType::UInt8 => field.data_type = ArrowDataType::UInt32,
+            #[cfg(not(feature = "dtype-u16"))]
+            ArrowDataType::UInt16 => field.data_type = ArrowDataType::UInt32,
+            #[cfg(not(feature = "dtype-i8"))]
+            ArrowDataType::Int8 => field.data_type = ArrowDataType::Int32,
+            #[cfg(not(feature = "dtype-i16"))]
+            ArrowDataType::Int16 => field.data_type = ArrowDataType::Int32,
+            _ => {}
+        }
+    }
+}
+
+/// Find the top level parquet field of an arrow field. The order of the fields in the
+/// arrow schema stored in the metadata may differ from the order in the file, so
+/// fields are matched by name.
+pub(super) fn find_parquet_field<'a>(
+    parquet_fields: &'a [ParquetType],
+    name: &str,
+) -> Option<&'a ParquetType> {
+    parquet_fields
+        .iter()
+        .find(|fld| fld.get_field_info().name == name)
+}
+
+/// Read string columns annotated with the parquet `ENUM` logical type as dictionaries,
+/// so that they become `Categorical` with the categories in the order of the
+/// parquet dictionary.
+#[cfg(feature = "dtype-categorical")]
+pub(super) fn enum_to_dictionary(parquet_fields: &[ParquetType], schema: &mut ArrowSchema) {
+    for field in schema.fields.iter_mut() {
+        let is_enum = match find_parquet_field(parquet_fields, &field.name) {
+            Some(ParquetType::PrimitiveType(primitive)) => {
+                matches!(primitive.logical_type, Some(PrimitiveLogicalType::Enum))
+                    || matches!(primitive.converted_type, Some(PrimitiveConvertedType::Enum))
+            }
+            _ => false,
+        };
+        if is_enum
+            && matches!(
+                field.data_type,
+                ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 | ArrowDataType::Binary
+            )
+        {
+            field.data_type =
+                ArrowDataType::Dictionary(IntegerType::UInt32, ArrowDataType::Utf8.into(), false);
+        }
+    }
+}
+
+/// Set the dtypes of the columns that are dictionaries in the schema or dictionary encoded
+/// in the file, see [`ParquetReader::with_dictionary_columns`].
+#[cfg_attr(not(feature = "dtype-categorical"), allow(unused_variables))]
+fn set_dictionary_dtypes(
+    metadata: &FileMetaData,
+    schema: &mut ArrowSchema,
+    dictionary_columns: DictionaryColumns,
+) {
+    for field in schema.fields.iter_mut() {
+        match (dictionary_columns, &field.data_type) {
+            (DictionaryColumns::Values, ArrowDataType::Dictionary(_, values, _)) => {
+                field.data_type = values.as_ref().clone();
+            }
+            #[cfg(feature = "dtype-categorical")]
+            (DictionaryColumns::Categorical, ArrowDataType::Utf8 | ArrowDataType::LargeUtf8) => {
+                if metadata
+                    .row_groups
+                    .iter()
+                    .any(|md| is_dictionary_encoded(md, &field.name))
+                {
+                    field.data_type = ArrowDataType::Dictionary(
+                        IntegerType::UInt32,
+                        ArrowDataType::Utf8.into(),
+                        false,
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Decode the string and binary columns with 64 bit offsets if a column chunk of them
+/// may not fit 32 bit offsets or `offset_widths` sets them to be large, and with 32 bit
+/// offsets if `offset_widths` sets them to be regular.
+pub(super) fn set_offset_widths(
+    metadata: &FileMetaData,
+    schema: &mut ArrowSchema,
+    offset_widths: Option<&PlHashMap<String, OffsetWidth>>,
+) {
+    for field in schema.fields.iter_mut() {
+        let width = match offset_widths.and_then(|widths| widths.get(&field.name)) {
+            Some(width) => *width,
+            None => {
+                // the values of a column chunk are at most its uncompressed size
+                let overflows = metadata.row_groups.iter().any(|md| {
+                    read::get_field_columns(md.columns(), &field.name)
+                        .iter()
+                        .any(|column| column.uncompressed_size() > i32::MAX as i64)
+                });
+                if !overflows {
+                    continue;
+                }
+                OffsetWidth::Large
+            }
+        };
+        field.data_type = match (width, &field.data_type) {
+            (OffsetWidth::Large, ArrowDataType::Utf8) => ArrowDataType::LargeUtf8,
+            (OffsetWidth::Large, ArrowDataType::Binary) => ArrowDataType::LargeBinary,
+            (OffsetWidth::Regular, ArrowDataType::LargeUtf8) => ArrowDataType::Utf8,
+            (OffsetWidth::Regular, ArrowDataType::LargeBinary) => ArrowDataType::Binary,
+            (_, dtype) => dtype.clone(),
+        };
+    }
+}
+
+/// Read binary columns annotated with the parquet `JSON` logical type as `Utf8`; JSON
+/// is UTF-8 encoded, but arrow reads the column as `Binary`, which polars can not hold.
+///
+/// `BSON` columns are left as `Binary`, as polars has no binary dtype to read them into.
+pub(super) fn json_to_utf8(parquet_fields: &[ParquetType], schema: &mut ArrowSchema) {
+    for field in schema.fields.iter_mut() {
+        let is_json = match find_parquet_field(parquet_fields, &field.name) {
+            Some(ParquetType::PrimitiveType(primitive)) => {
+                matches!(primitive.logical_type, Some(PrimitiveLogicalType::Json))
+                    || matches!(primitive.converted_type, Some(PrimitiveConvertedType::Json))
+            }
+            _ => false,
+        };
+        if is_json
+            && matches!(
+                field.data_type,
+                ArrowDataType::Binary | ArrowDataType::LargeBinary
+            )
+        {
+            field.data_type = ArrowDataType::Utf8;
+        }
+    }
+}
+
+/// Split the columns into chunks of `lengths`; see [`ParquetReader::with_chunk_lengths`].
+fn to_chunk_lengths(df: DataFrame, lengths: &[usize]) -> Result<DataFrame> {
+    let height = df.height();
+    let mut bounds = Vec::with_capacity(lengths.len() + 1);
+    let mut offset = 0;
+    for len in lengths {
+        let len = (*len).min(height - offset);
+        if len > 0 {
+            bounds.push((offset, len));
+        }
+        offset += len;
+    }
+    if offset < height {
+        bounds.push((offset, height - offset));
+    }
+    to_chunks(df, &bounds)
+}
+
+/// Split the columns into chunks of `length`; see [`ParquetReader::with_chunk_length`].
+fn to_chunk_length(df: DataFrame, length: usize) -> Result<DataFrame> {
+    let height = df.height();
+    let bounds = (0..height)
+        .step_by(length)
+        .map(|offset| (offset, length.min(height - offset)))
+        .collect::<Vec<_>>();
+    to_chunks(df, &bounds)
+}
+
+/// Split the columns into one chunk for every `(offset, len)` bound. The chunks are
+/// slices of the columns; only a chunk that spans multiple chunks of a column is copied.
+fn to_chunks(df: DataFrame, bounds: &[(usize, usize)]) -> Result<DataFrame> {
+    let slice = |s: &Series, offset: usize, len: usize| {
+        let mut out = s.slice(offset as i64, len);
+        if out.n_chunks() > 1 {
+            out = out.rechunk();
+        }
+        out
+    };
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|s| {
+            let mut bounds = bounds.iter();
+            match bounds.next() {
+                Some((offset, len)) => {
+                    let mut out = slice(s, *offset, *len);
+                    for (offset, len) in bounds {
+                        out.append(&slice(s, *offset, *len))?;
+                    }
+                    Ok(out)
+                }
+                None => Ok(s.clone()),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(DataFrame::new_no_checks(columns))
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
     #[cfg(feature = "lazy")]
     // todo! hoist to lazy crate
     pub fn _finish_with_scan_ops(
-        mut self,
+        self,
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
         aggregate: Option<&[ScanAggregation]>,
         projection: Option<&[usize]>,
     ) -> Result<DataFrame> {
         // this path takes predicates and parallelism into account
-        let metadata = read::read_metadata(&mut self.reader)?;
-        let schema = read::schema::infer_schema(&metadata)?;
+        self.finish_impl(predicate, aggregate, projection, ReadDiagnostics::default())
+    }
+
+    /// Read the file and return a fingerprint of what was read. The fingerprint
+    /// covers the projection, the predicate, the limit and the row groups that
+    /// were read, so that two reads can be proven to have read the same slice of
+    /// the file. The data itself is not hashed.
+    ///
+    /// Predicates are fingerprinted by their [`PhysicalIoExpr::display`]
+    /// representation. Predicates without one can not be told apart.
+    pub fn finish_with_fingerprint(
+        self,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    ) -> Result<(DataFrame, ReadFingerprint)> {
+        let mut fingerprint = ReadFingerprint::default();
+        let df = self.finish_impl(
+            predicate,
+            None,
+            None,
+            ReadDiagnostics {
+                fingerprint: Some(&mut fingerprint),
+                ..Default::default()
+            },
+        )?;
+        Ok((df, fingerprint))
+    }
+
+    /// Read the file and split the rows into `target_partitions` contiguous partitions of
+    /// roughly equal length, regardless of the row group sizes. The partitions are in
+    /// row order and are zero-copy slices of the data that is read.
+    /// If `None`, a single partition is returned.
+    pub fn finish_partitioned(self, target_partitions: Option<usize>) -> Result<Vec<DataFrame>> {
+        let n = target_partitions.unwrap_or(1);
+        if n == 0 {
+            return Err(PolarsError::ComputeError(
+                "the number of partitions must be greater than 0".into(),
+            ));
+        }
+        let df = self.finish_impl(None, None, None, ReadDiagnostics::default())?;
+        Ok(polars_core::utils::split_offsets(df.height(), n)
+            .into_iter()
+            .map(|(offset, len)| df.slice(offset as i64, len))
+            .collect())
+    }
+
+    /// Read the row groups that can be read before `deadline`. The deadline is checked
+    /// before every row group is read. Returns whether all row groups were read.
+    /// This overrides [`ParquetReader::with_deadline`].
+    pub fn finish_with_deadline(mut self, deadline: Instant) -> Result<(DataFrame, bool)> {
+        self.deadline = Some(deadline);
+        let mut timed_out = false;
+        let df = self.finish_impl(
+            None,
+            None,
+            None,
+            ReadDiagnostics {
+                timed_out: Some(&mut timed_out),
+                ..Default::default()
+            },
+        )?;
+        Ok((df, !timed_out))
+    }
+
+    /// Read the file and report how many arrow chunks every column of every row group that
+    /// was read decoded into. Many chunks indicate a fragmented column, which is slower to
+    /// decode.
+    pub fn finish_with_chunk_counts(self) -> Result<(DataFrame, Vec<RowGroupChunks>)> {
+        let mut chunk_counts = vec![];
+        let df = self.finish_impl(
+            None,
+            None,
+            None,
+            ReadDiagnostics {
+                chunk_counts: Some(&mut chunk_counts),
+                ..Default::default()
+            },
+        )?;
+        Ok((df, chunk_counts))
+    }
+
+    /// Read the file and return the metadata of the file as well, so that it doesn't have
+    /// to be read again. If the metadata was set with [`ParquetReader::with_metadata`],
+    /// that metadata is returned.
+    pub fn finish_with_metadata(mut self) -> Result<(DataFrame, FileMetaData)> {
+        let metadata = self.get_metadata()?;
+        self.metadata = Some(metadata.clone());
+        let df = self.finish()?;
+        Ok((df, metadata))
+    }
+
+    /// Read the file and export the frame as an Arrow C stream, for a zero copy handoff to
+    /// other Arrow implementations. Every array of the stream is a struct array of the
+    /// columns of a row group, unless the columns have chunks of different lengths, e.g.
+    /// after [`ParquetReader::with_chunk_lengths`], in which case they are rechunked into
+    /// a single array.
+    ///
+    /// The file is read before the stream is returned, so the stream holds no file or
+    /// memory map. The buffers of an array are released when the consumer releases the
+    /// array, those of the arrays that were not consumed when it releases the stream.
+    #[cfg(feature = "ffi")]
+    pub fn finish_arrow_stream(self) -> Result<Box<arrow::ffi::ArrowArrayStream>> {
+        use arrow::array::{Array, StructArray};
+
+        let mut df = self.finish()?;
+        if df.should_rechunk() {
+            df.rechunk();
+        }
+        let dtype = ArrowDataType::Struct(df.schema().to_arrow().fields);
+        let batches = df
+            .iter_chunks()
+            .map(|chunk| {
+                let arr = StructArray::from_data(dtype.clone(), chunk.into_arrays(), None);
+                Ok(Box::new(arr) as Box<dyn Array>)
+            })
+            .collect::<Vec<_>>();
+        let field = ArrowField::new("", dtype, false);
+        Ok(Box::new(arrow::ffi::export_iterator(
+            Box::new(batches.into_iter()),
+            field,
+        )))
+    }
+
+    /// Read the file into a frame per value of `column`, e.g. of a low cardinality column
+    /// such as a region. The frame of every row group is partitioned as soon as it is
+    /// read and the partitions of the same value are stacked at the end, which saves a
+    /// partition pass over the whole frame. A categorical partition is keyed by its string.
+    /// Float, nested and time zone aware columns can't be partitioned by.
+    ///
+    /// The partitions of all row groups are held until the end of the read, as frames of
+    /// their own: a column with many values leaves many small frames, each with a buffer
+    /// per column, that take more memory than a single frame with the same rows would.
+    pub fn finish_partitioned_by(
+        self,
+        column: &str,
+    ) -> Result<PlHashMap<AnyValue<'static>, DataFrame>> {
+        let mut partitions = PlHashMap::new();
+        self.finish_impl(
+            None,
+            None,
+            None,
+            ReadDiagnostics {
+                partitions: Some((column, &mut partitions)),
+                ..Default::default()
+            },
+        )?;
+        partitions
+            .into_iter()
+            .map(|(value, dfs)| Ok((value, accumulate_dataframes_vertical(dfs)?)))
+            .collect()
+    }
+
+    /// Read the file and return whether the limit set with [`SerReader::with_n_rows`]
+    /// left rows unread, i.e. whether there may be a next page.
+    ///
+    /// Row groups after the limit are not evaluated, so the flag is also set if all of
+    /// them would have been pruned by the predicate.
+    pub fn finish_with_limit_reached(self) -> Result<(DataFrame, bool)> {
+        let mut limit_reached = false;
+        let df = self.finish_impl(
+            None,
+            None,
+            None,
+            ReadDiagnostics {
+                limit_reached: Some(&mut limit_reached),
+                ..Default::default()
+            },
+        )?;
+        Ok((df, limit_reached))
+    }
+
+    /// Read the metadata and prune the row groups with the statistics, but defer decoding
+    /// the columns until they are accessed. Columns that are never accessed are never
+    /// decoded.
+    ///
+    /// The `predicate` only prunes row groups, the rows of the remaining row groups are
+    /// not filtered. Of the options of the reader, only the columns, the projection, the
+    /// interval and whether to use the arrow metadata are taken into account.
+    pub fn finish_deferred(
+        mut self,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    ) -> Result<DeferredParquetFrame<R>> {
+        let metadata = self.get_metadata()?;
+        let mut schema = infer_schema(&metadata, self.use_arrow_metadata, self.interval)?;
+        set_dictionary_dtypes(&metadata, &mut schema, self.dictionary_columns);
+        set_offset_widths(&metadata, &mut schema, self.offset_widths.as_ref());
+        let projection = match (self.columns.take(), self.projection.take()) {
+            (Some(cols), _) => columns_to_projection(cols, &schema)?,
+            (None, Some(projection)) => projection,
+            (None, None) => (0..schema.fields.len()).collect(),
+        };
+        DeferredParquetFrame::new(self.reader, metadata, schema, projection, predicate)
+    }
+
+    /// Read the file and return the number of values that could not be cast to the dtypes
+    /// of the [`ReadSpec`]. These values are saturated or null, see
+    /// [`ReadSpec::with_overflow`].
+    pub fn finish_with_cast_failures(self) -> Result<(DataFrame, usize)> {
+        let mut cast_failures = 0;
+        let df = self.finish_impl(
+            None,
+            None,
+            None,
+            ReadDiagnostics {
+                cast_failures: Some(&mut cast_failures),
+                ..Default::default()
+            },
+        )?;
+        Ok((df, cast_failures))
+    }
+
+    /// Read the file and return the columns that could not be decoded instead of failing.
+    /// A column that fails in a row group is null in that row group, or with
+    /// [`FailedColumns::Omit`] left out of the frame.
+    ///
+    /// The failed columns are null before the predicate is applied, so a predicate on
+    /// them doesn't select any row of the row groups they failed in.
+    pub fn finish_with_column_errors(
+        self,
+        failed: FailedColumns,
+    ) -> Result<(DataFrame, Vec<ColumnError>)> {
+        let mut column_errors = vec![];
+        let df = self.finish_impl(
+            None,
+            None,
+            None,
+            ReadDiagnostics {
+                column_errors: Some((failed, &mut column_errors)),
+                ..Default::default()
+            },
+        )?;
+        Ok((df, column_errors))
+    }
+
+    /// Read the file and return the violations of the constraints set by
+    /// [`ParquetReader::with_constraints`] instead of failing on the first one.
+    pub fn finish_with_constraint_violations(
+        self,
+    ) -> Result<(DataFrame, Vec<ConstraintViolation>)> {
+        let mut violations = vec![];
+        let df = self.finish_impl(
+            None,
+            None,
+            None,
+            ReadDiagnostics {
+                violations: Some(&mut violations),
+                ..Default::default()
+            },
+        )?;
+        Ok((df, violations))
+    }
+
+    /// Read the file and return the row groups that the statistics did not prune. Later
+    /// reads of the same file with the same predicate can skip evaluating the statistics
+    /// by passing them to [`ParquetReader::with_pruned_row_groups`].
+    ///
+    /// If pruned row groups are set on this reader, they are used and returned.
+    pub fn finish_with_pruned_row_groups(
+        self,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    ) -> Result<(DataFrame, PrunedRowGroups)> {
+        let mut pruned = None;
+        let df = self.finish_impl(
+            predicate,
+            None,
+            None,
+            ReadDiagnostics {
+                pruned_row_groups: Some(&mut pruned),
+                ..Default::default()
+            },
+        )?;
+        Ok((df, pruned.unwrap()))
+    }
+
+    /// Read the file and report for every row group whether it was read or pruned, and by
+    /// which mechanism, e.g. to explain a scan as "pruned 40 of 50 row groups: 30 by the
+    /// statistics, 10 by the limit". The decisions are recorded as the row groups are
+    /// pruned, nothing is evaluated for them alone.
+    pub fn finish_with_row_group_decisions(
+        self,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    ) -> Result<(DataFrame, Vec<RowGroupDecision>)> {
+        let mut decisions = vec![];
+        let df = self.finish_impl(
+            predicate,
+            None,
+            None,
+            ReadDiagnostics {
+                decisions: Some(&mut decisions),
+                ..Default::default()
+            },
+        )?;
+        Ok((df, decisions))
+    }
+
+    /// If `projection` is `None`, the columns set on the reader are read. The reports that
+    /// are set in `diagnostics` are collected.
+    pub(super) fn finish_impl(
+        mut self,
+        mut predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        aggregate: Option<&[ScanAggregation]>,
+        projection: Option<&[usize]>,
+        mut diagnostics: ReadDiagnostics,
+    ) -> Result<DataFrame> {
+        // the string cache is held until the categoricals are built
+        #[cfg(feature = "dtype-categorical")]
+        let _string_cache = self.string_cache.take();
+        if let Some(ranges) = self.column_ranges.take() {
+            if predicate.is_some() {
+                return Err(PolarsError::InvalidOperation(
+                    "column ranges and a predicate can not be combined".into(),
+                ));
+            }
+            predicate = Some(Arc::new(ranges));
+            self.prune_only = true;
+        }
+        #[cfg(all(feature = "json", feature = "dtype-struct"))]
+        if let Some(expansion) = self.json_expansion.take() {
+            // the schema of the struct is inferred from the first row group
+            let dtype = std::sync::Mutex::new(None);
+            let post_decode = self.post_decode.take();
+            self.post_decode = Some(Arc::new(move |df: &mut DataFrame| {
+                expansion.expand(df, &mut dtype.lock().unwrap())?;
+                match &post_decode {
+                    Some(post_decode) => post_decode(df),
+                    None => Ok(()),
+                }
+            }));
+        }
+        let mut metadata = self.get_metadata()?;
+        let mut schema = infer_schema(&metadata, self.use_arrow_metadata, self.interval)?;
+        set_dictionary_dtypes(&metadata, &mut schema, self.dictionary_columns);
+        set_offset_widths(&metadata, &mut schema, self.offset_widths.as_ref());
+        if let Some(policy) = self.duplicate_columns {
+            check_duplicate_columns(&schema, policy)?;
+            if policy == DuplicateColumns::Suffix {
+                suffix_duplicate_columns(&mut metadata, &mut schema)?;
+            }
+        }
+        let since = match self.since.take() {
+            Some((column, bound)) => {
+                schema.try_index_of(&column)?;
+                let ranges = ColumnRanges(PlHashMap::from_iter([(column, (Some(bound), None))]));
+                Some(Arc::new(ranges) as Arc<dyn PhysicalIoExpr>)
+            }
+            None => None,
+        };
+        let top_k = self.top_k.take();
+        let top_k_row_groups = match &top_k {
+            Some(_) if predicate.is_some() => {
+                return Err(PolarsError::InvalidOperation(
+                    "a top k and a predicate can not be combined".into(),
+                ));
+            }
+            Some((column, k, ascending)) => Some(top_k_row_groups(
+                &metadata, &schema, column, *k, *ascending,
+            )?),
+            None => None,
+        };
+        if let Some(out) = diagnostics.pruned_row_groups.take() {
+            if self.pruned_row_groups.is_none() {
+                self.pruned_row_groups = Some(PrunedRowGroups::evaluate(
+                    &metadata,
+                    &schema,
+                    predicate.as_ref(),
+                    self.statistics.as_deref(),
+                    self.statistics_truncated_len,
+                )?);
+            }
+            *out = self.pruned_row_groups.clone();
+        }
+
+        if let Some(spec) = &self.read_spec {
+            self.projection = Some(spec.to_projection(&metadata, &schema)?);
+        } else if let Some(cols) = self.columns.take() {
+            self.projection = Some(columns_to_projection(cols, &schema)?);
+        }
+        let projection = projection.or(self.projection.as_deref());
+        if let Some(row_count) = &mut self.row_count {
+            check_row_count_name(row_count, &schema, projection, self.row_count_collision)?;
+        }
+        let read_spec = self.read_spec.take();
+        let row_count_offset = self.row_count.is_some() as usize;
 
         let rechunk = self.rechunk;
+        let chunk_lengths = self.chunk_lengths.take();
+        let failed_columns = diagnostics
+            .column_errors
+            .as_ref()
+            .map(|(failed, _)| *failed);
+        let cast_failures = diagnostics.cast_failures.take();
+        let chunk_length = self.chunk_length;
+        if chunk_length == Some(0) {
+            return Err(PolarsError::ComputeError(
+                "the chunk length must be greater than 0".into(),
+            ));
+        }
+        let options = ParquetReadOptions {
+            limit: self.n_rows.unwrap_or(usize::MAX),
+            projection,
+            aggregate,
+            parallel: self.parallel,
+            parallel_threshold_bytes: self.parallel_threshold_bytes,
+            coalesce_gap_bytes: self.coalesce_gap_bytes,
+            row_count: self.row_count,
+            limit_after_predicate: self.limit_after_predicate,
+            statistics: self.statistics.as_deref(),
+            null_dtypes: self.null_dtypes.as_ref(),
+            max_categories: self.max_categories,
+            pad_ragged_columns: self.pad_ragged_columns,
+            prune_only: self.prune_only,
+            sample: self.sample.as_ref(),
+            allow_missing_columns: self.allow_missing_columns,
+            post_predicate: self.post_predicate.as_deref(),
+            row_count_i64: self.row_count_i64,
+            predicate_only_columns: self.predicate_only_columns.as_deref(),
+            deadline: self.deadline,
+            row_group_id: self.row_group_id.as_deref(),
+            statistics_truncated_len: self.statistics_truncated_len,
+            decompression_pool: self.decompression_pool.as_deref(),
+            row_selection: self.row_selection.as_ref(),
+            pruned_row_groups: self.pruned_row_groups.as_ref(),
+            post_decode: self.post_decode.as_deref(),
+            windowed_mmap: self.windowed_mmap,
+            distinct_on: self.distinct_on.as_deref(),
+            since: since.as_ref(),
+            limit_distribution: self.limit_distribution,
+            constraints: self.constraints.as_deref(),
+            pipeline: self.decode_pipeline.as_deref(),
+            max_decompressed_bytes: self.max_decompressed_bytes,
+            metadata_columns: self.metadata_columns.as_deref(),
+            verify: self.verify_row_counts,
+            selected_row_groups: top_k_row_groups.as_deref(),
+            reverse: self.reverse,
+            required_columns: self
+                .required_columns
+                .as_ref()
+                .map(|(columns, on_null)| (columns.as_slice(), *on_null)),
+            on_series: self.on_series.as_deref(),
+            datetime_unit: self.datetime_unit,
+            limit_before_agg: self.limit_before_agg,
+            null_fill_dtypes: self.null_fill_dtypes.as_ref(),
+        };
         read_parquet(
             self.reader,
-            self.n_rows.unwrap_or(usize::MAX),
-            projection,
-            &schema,
             Some(metadata),
+            &schema,
             predicate,
-            aggregate,
-            self.parallel,
-            self.row_count,
+            options,
+            &mut diagnostics,
         )
-        .map(|mut df| {
-            if rechunk {
-                df.rechunk();
-            };
-            df
+        .and_then(|mut df| {
+            if let (Some(FailedColumns::Omit), Some((_, errors))) =
+                (failed_columns, &diagnostics.column_errors)
+            {
+                for error in errors.iter() {
+                    if df.find_idx_by_name(&error.column).is_some() {
+                        df = df.drop(&error.column)?;
+                    }
+                }
+            }
+            Ok(df)
+        })
+        .and_then(|df| match &top_k {
+            // the rows within the row groups may be in any order
+            Some((column, k, ascending)) => {
+                Ok(df.sort([column.as_str()], !*ascending)?.head(Some(*k)))
+            }
+            None => Ok(df),
+        })
+        .and_then(|df| match &read_spec {
+            Some(spec) => {
+                let (df, n_failed) = spec.apply(df, row_count_offset)?;
+                if let Some(cast_failures) = cast_failures {
+                    *cast_failures = n_failed;
+                }
+                Ok(df)
+            }
+            None => Ok(df),
+        })
+        .and_then(|mut df| {
+            if let Some(length) = chunk_length {
+                to_chunk_length(df, length)
+            } else if let Some(lengths) = &chunk_lengths {
+                to_chunk_lengths(df, lengths)
+            } else {
+                if rechunk {
+                    df.rechunk();
+                };
+                Ok(df)
+            }
         })
     }
 
     /// Read the parquet file in parallel (default). The single threaded reader consumes less memory.
+    /// Files smaller than the threshold set by [`ParquetReader::with_parallel_threshold`]
+    /// are read serially regardless.
     pub fn read_parallel(mut self, parallel: bool) -> Self {
         self.parallel = parallel;
         self
     }
 
+    /// Read files of which the column chunks are compressed to fewer than `bytes` serially,
+    /// as for small files dispatching the columns to the thread pool costs more than
+    /// decoding them in parallel gains. Defaults to [`DEFAULT_PARALLEL_THRESHOLD_BYTES`].
+    pub fn with_parallel_threshold(mut self, bytes: usize) -> Self {
+        self.parallel_threshold_bytes = bytes;
+        self
+    }
+
+    /// Read the column chunks of a row group that are at most `bytes` apart from a
+    /// [`ParquetSource`] as a single range, of which the bytes in between are discarded.
+    /// A larger gap takes fewer requests, but reads more bytes of the columns that are
+    /// not decoded. A gap of 0 only merges adjacent column chunks. Defaults to
+    /// [`DEFAULT_COALESCE_GAP_BYTES`]. Only applies to readers with a source.
+    pub fn with_coalesce_gap(mut self, bytes: u64) -> Self {
+        self.coalesce_gap_bytes = bytes;
+        self
+    }
+
+    /// Read the row groups from the last to the first, and the rows of every row group
+    /// from its last to its first, so that the frame holds the rows of the file in
+    /// reverse. The row count still counts from the start of the file, so it decreases.
+    /// A limit set with [`ParquetReader::with_n_rows`] then keeps the last rows.
+    pub fn read_reverse(mut self, reverse: bool) -> Self {
+        self.reverse = reverse;
+        self
+    }
+
     /// Stop parsing when `n` rows are parsed. By settings this parameter the csv will be parsed
     /// sequentially.
     pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
@@ -67,6 +885,54 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Check that the rows of every row group satisfy the constraints, as `(column,
+    /// constraint)` pairs of which the constraint evaluates to a boolean mask, e.g.
+    /// `age >= 0`. The rows that pass the predicate are checked while the frame of the row
+    /// group is still small and the read fails on the first violation, with the row group
+    /// and the value of the column of the first row that violates it. Rows for which a
+    /// constraint is null satisfy it.
+    ///
+    /// A constraint that the statistics of a row group show to hold for all rows is not
+    /// evaluated. See [`ParquetReader::finish_with_constraint_violations`] to collect all
+    /// violations instead.
+    pub fn with_constraints(
+        mut self,
+        constraints: Option<Vec<(String, Arc<dyn PhysicalIoExpr>)>>,
+    ) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
+    /// Enforce that the `columns` have no nulls, even though the file may have written
+    /// them as optional. The rows of a row group with a null in one of the columns are
+    /// dropped, or the read fails, depending on `on_null`. The columns of a row group of
+    /// which the statistics show no nulls are not checked.
+    ///
+    /// Dropped rows don't count towards the limit set by [`ParquetReader::with_n_rows`].
+    pub fn with_required_columns(
+        mut self,
+        columns: Option<Vec<String>>,
+        on_null: RequiredColumnNulls,
+    ) -> Self {
+        self.required_columns = columns.map(|columns| (columns, on_null));
+        self
+    }
+
+    /// Set how the number of rows set by [`ParquetReader::with_n_rows`] is taken from the
+    /// row groups. Defaults to [`LimitDistribution::Head`].
+    pub fn with_limit_distribution(mut self, distribution: LimitDistribution) -> Self {
+        self.limit_distribution = distribution;
+        self
+    }
+
+    /// Let the number of rows set by [`ParquetReader::with_n_rows`] count the rows that pass the
+    /// predicate instead of the rows that are read. Row groups are then read until that many rows
+    /// satisfy the predicate.
+    pub fn limit_after_predicate(mut self, toggle: bool) -> Self {
+        self.limit_after_predicate = toggle;
+        self
+    }
+
     /// Columns to select/ project
     pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
         self.columns = columns;
@@ -80,20 +946,681 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Read the columns described by a [`ReadSpec`], which also renames and casts them.
+    /// This overrides [`ParquetReader::with_columns`] and [`ParquetReader::with_projection`].
+    pub fn with_read_spec(mut self, spec: Option<ReadSpec>) -> Self {
+        self.read_spec = spec;
+        self
+    }
+
+    /// Pad columns with nulls if the columns of a row group have different lengths,
+    /// which happens with malformed files. By default such files return an error.
+    pub fn pad_ragged_columns(mut self, toggle: bool) -> Self {
+        self.pad_ragged_columns = toggle;
+        self
+    }
+
+    /// Check that every decoded column of a row group has as many rows as the metadata
+    /// of the row group declares, or as the limit leaves of it, and return an error
+    /// otherwise. This only compares lengths and is meant to catch decoding bugs early,
+    /// it is checked before ragged columns are padded.
+    pub fn verify_row_counts(mut self, toggle: bool) -> Self {
+        self.verify_row_counts = toggle;
+        self
+    }
+
+    /// Memory map only the column chunks of the row group that is decoded instead of the
+    /// whole file, so that the mapped address space is bounded by the largest row group.
+    /// This allows reading files larger than the address space, e.g. on 32 bit targets.
+    /// Mapping the whole file (default) is faster when it fits. Only applies to files.
+    pub fn windowed_mmap(mut self, toggle: bool) -> Self {
+        self.windowed_mmap = toggle;
+        self
+    }
+
+    /// Only use the predicate to skip row groups based on their statistics, but don't
+    /// filter the rows of the row groups that are read. This is useful if the rows are
+    /// filtered later on anyway.
+    pub fn prune_only(mut self, toggle: bool) -> Self {
+        self.prune_only = toggle;
+        self
+    }
+
+    /// Only read a random sample of the row groups. The row count still counts
+    /// the rows of the row groups that are not sampled.
+    pub fn with_sample(mut self, sample: Option<SampleSpec>) -> Self {
+        self.sample = sample;
+        self
+    }
+
+    /// Read columns that are missing in a row group as nulls instead of returning
+    /// an error. Such files are malformed, but may be produced by appending writers.
+    ///
+    /// The columns of the file schema are the union of the columns of all row groups, so
+    /// a column that only some row groups have can be projected. The statistics of a
+    /// missing column are unknown, so they never prune a row group.
+    pub fn allow_missing_columns(mut self, toggle: bool) -> Self {
+        self.allow_missing_columns = toggle;
+        self
+    }
+
+    /// Skip row groups whose statistics show that no value of a column lies within the given
+    /// inclusive bounds. These ranges are an alternative to a predicate and can not be combined
+    /// with one. The rows of the row groups that are read are not filtered.
+    pub fn with_column_ranges(mut self, ranges: Option<ColumnRanges>) -> Self {
+        self.column_ranges = ranges;
+        self
+    }
+
+    /// Skip row groups whose statistics show that the max of the column is below the
+    /// bound, e.g. to only read the rows that were appended since a timestamp of a
+    /// sorted column. Unlike [`ParquetReader::with_column_ranges`] this can be combined
+    /// with a predicate. The rows of the row groups that are read are not filtered.
+    pub fn with_since(mut self, since: Option<(String, AnyValue<'static>)>) -> Self {
+        self.since = since;
+        self
+    }
+
+    /// Only read the `k` rows with the smallest values of `column`, or the largest if
+    /// not `ascending`, in that order. This is `ORDER BY column LIMIT k`, of which only the
+    /// row groups at one end of the file are read.
+    ///
+    /// The statistics of the file must show that its row groups are sorted by `column`
+    /// and have no nulls in it, otherwise an error is returned and the frame should be
+    /// sorted instead. The column must be part of the projection, and a predicate can't
+    /// be set as the rows that pass it are unknown up front.
+    pub fn with_top_k(mut self, top_k: Option<(String, usize, bool)>) -> Self {
+        self.top_k = top_k;
+        self
+    }
+
+    /// Split the columns into chunks of the given lengths, for instance the
+    /// `chunk_lengths` of a frame the result is appended to, so that the chunks of both
+    /// line up. Rows beyond the sum of the lengths form a last chunk.
+    pub fn with_chunk_lengths(mut self, lengths: Option<Vec<usize>>) -> Self {
+        self.chunk_lengths = lengths;
+        self
+    }
+
+    /// Split the columns into chunks of `length` rows, of which the last may be shorter,
+    /// for kernels that perform best at a specific chunk length. Unlike
+    /// [`SerReader::set_rechunk`] this produces uniform chunks rather than a single one.
+    /// This overrides [`ParquetReader::with_chunk_lengths`].
+    pub fn with_chunk_length(mut self, length: Option<usize>) -> Self {
+        self.chunk_length = length;
+        self
+    }
+
+    /// Call `post_decode` with the frame of every row group before the frames are stacked,
+    /// e.g. to add columns that are computed from other columns while the row group is
+    /// still small. The frame has the row count and the row group id columns and only the
+    /// rows of [`ParquetReader::with_row_selection`]; the predicate, the post predicate and
+    /// the aggregations are applied after it, so they can refer to the added columns.
+    ///
+    /// Row groups are only pruned by the statistics of the columns in the file, so a
+    /// predicate on added columns doesn't skip row groups. If no row group is read, the
+    /// function is not called and the empty frame lacks the added columns.
+    pub fn with_post_decode(mut self, post_decode: Option<Arc<PostDecode>>) -> Self {
+        self.post_decode = post_decode;
+        self
+    }
+
+    /// Call `on_series` with the name and the `Series` of every column of every row group
+    /// as soon as it is decoded, and put the returned `Series` in the frame instead, e.g.
+    /// to normalize or hash a column without a second pass over the frame. The returned
+    /// `Series` may have another dtype, which becomes the dtype of the column; it needs
+    /// the same dtype for every row group and as many rows as it was called with.
+    ///
+    /// Like [`ParquetReader::with_post_decode`], the predicate sees the returned columns
+    /// but row groups are pruned by the statistics of the columns in the file.
+    pub fn with_on_series(mut self, on_series: Option<Arc<OnSeries>>) -> Self {
+        self.on_series = on_series;
+        self
+    }
+
+    /// Expand a string column that holds JSON objects into a `Struct` column while the
+    /// row groups are read, with a schema that is inferred from the first row group.
+    /// The expansion happens before the function of [`ParquetReader::with_post_decode`]
+    /// is called, so the function and the predicate see the `Struct` column.
+    #[cfg(all(feature = "json", feature = "dtype-struct"))]
+    pub fn with_json_expansion(mut self, expansion: Option<JsonExpansion>) -> Self {
+        self.json_expansion = expansion;
+        self
+    }
+
+    /// Drop the rows that are duplicates of an earlier row in the `distinct_on` columns,
+    /// keeping the first occurrence. The rows are deduplicated within every row group
+    /// after the predicate is applied, which is cheap when the row groups are small and
+    /// shrinks the frames before they are stacked.
+    ///
+    /// Duplicates in different row groups remain after that, so if more than one row
+    /// group is read the stacked frame is deduplicated again. That final pass hashes all
+    /// rows that are read, but it's cheaper when many duplicates were dropped per row
+    /// group. The limit of [`ParquetReader::with_n_rows`] counts the rows of a row group
+    /// before they are deduplicated, or after it with
+    /// [`ParquetReader::limit_after_predicate`]; the final pass may leave fewer rows.
+    pub fn with_distinct_on(mut self, columns: Option<Vec<String>>) -> Self {
+        self.distinct_on = columns;
+        self
+    }
+
+    /// Add the row count as an `Int64` column instead of an `IdxSize` column, so that the
+    /// row count of files with more rows than an `IdxSize` can hold doesn't overflow.
+    pub fn row_count_i64(mut self, toggle: bool) -> Self {
+        self.row_count_i64 = toggle;
+        self
+    }
+
+    /// A predicate that only filters the rows after they are decoded and is not used to
+    /// skip row groups, for instance because it references computed values. It is applied
+    /// in addition to the predicate passed to the read.
+    pub fn with_post_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
+        self.post_predicate = predicate;
+        self
+    }
+
+    /// Columns that are only read to evaluate the predicate. Row groups whose statistics show
+    /// that all rows satisfy the predicate don't need these values, so they are not decoded
+    /// and are null instead. The post predicate should not reference these columns.
+    pub fn with_predicate_only_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.predicate_only_columns = columns;
+        self
+    }
+
+    /// Return a [`PolarsError::Timeout`] if the read is not finished before `deadline`.
+    /// The deadline is checked before every row group is read.
+    pub fn with_deadline(mut self, deadline: Option<Instant>) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
+    /// Append a column with this name that holds the index of the row group every row
+    /// was read from.
+    pub fn with_row_group_id(mut self, name: Option<String>) -> Self {
+        self.row_group_id = name;
+        self
+    }
+
+    /// Append columns of which the values are taken from the metadata of the row group
+    /// every row was read from, e.g. the compressed size or the min value of a column.
+    /// See [`MetadataField`](super::MetadataField) for the available fields. The pages
+    /// are not needed to materialize them.
+    ///
+    /// The columns are appended after the row group id, in the order given.
+    pub fn with_metadata_columns(mut self, columns: Option<Vec<MetadataColumn>>) -> Self {
+        self.metadata_columns = columns;
+        self
+    }
+
+    /// Check whether the file has multiple columns with the same name. Columns are
+    /// selected by name, so only the first of them can be read, unless they are renamed
+    /// with [`DuplicateColumns::Suffix`]. Defaults to [`DuplicateColumns::Warn`], `None`
+    /// reads the first of them without checking.
+    pub fn on_duplicate_columns(mut self, policy: Option<DuplicateColumns>) -> Self {
+        self.duplicate_columns = policy;
+        self
+    }
+
+    /// Writers may truncate long string min and max statistics. String max values of at
+    /// least `len` bytes are considered to be truncated, so row groups are only pruned
+    /// if none of their values can start with the max value. Defaults to
+    /// [`DEFAULT_STATISTICS_TRUNCATED_LEN`], which treats every string max as truncated.
+    /// Pass `None` to trust the max values as exact, if the writer is known not to
+    /// truncate them.
+    ///
+    /// This does not apply to statistics set with [`ParquetReader::with_cached_statistics`],
+    /// see [`FileStatistics::collect_truncated`].
+    pub fn with_statistics_truncated_len(mut self, len: Option<usize>) -> Self {
+        self.statistics_truncated_len = len;
+        self
+    }
+
+    /// Reuse the buffers that pages are decompressed into from this pool. The pool can
+    /// be shared by multiple reads.
+    pub fn with_decompression_pool(mut self, pool: Option<Arc<DecompressionPool>>) -> Self {
+        self.decompression_pool = pool;
+        self
+    }
+
+    /// Decompress and decode the column chunks of every row group in `pipeline`, which
+    /// decompresses on other threads than it decodes. The pipeline can be shared by
+    /// multiple reads; it replaces the parallel decoding of the columns.
+    pub fn with_decode_pipeline(mut self, pipeline: Option<Arc<DecodePipeline>>) -> Self {
+        self.decode_pipeline = pipeline;
+        self
+    }
+
+    /// Fail the read once its pages decompress to more than `max` bytes in total, as a
+    /// guard against files of which small pages decompress to very large ones. The
+    /// decompressed size that the header of a page declares is checked before the page
+    /// is decompressed.
+    pub fn with_max_decompressed_bytes(mut self, max: Option<usize>) -> Self {
+        self.max_decompressed_bytes = max;
+        self
+    }
+
+    /// Only read the rows that are `true` in `selection`, which has a value for every row
+    /// of the file. Row groups without selected rows are not decoded; the selected rows of
+    /// the other row groups are filtered after decoding.
+    ///
+    /// The selection is applied before the predicate and after the row count is added, and
+    /// the limit counts the selected rows.
+    pub fn with_row_selection(mut self, selection: Option<BooleanChunked>) -> Self {
+        self.row_selection = selection;
+        self
+    }
+
+    /// Use the row groups returned by [`ParquetReader::finish_with_pruned_row_groups`]
+    /// instead of evaluating the predicate against the statistics. The row groups must be
+    /// of the same file and pruned with the same predicate; a different file returns an
+    /// error, as does a different predicate if both have a [`PhysicalIoExpr::display`]
+    /// representation.
+    pub fn with_pruned_row_groups(mut self, pruned: Option<PrunedRowGroups>) -> Self {
+        self.pruned_row_groups = pruned;
+        self
+    }
+
     /// Add a `row_count` column.
     pub fn with_row_count(mut self, row_count: Option<RowCount>) -> Self {
         self.row_count = row_count;
         self
     }
 
+    /// Convert the top level `Datetime` and `Duration` columns to the time unit `tu` while
+    /// the row groups are decoded, so that every read has the same time unit regardless
+    /// of the unit the file stores. Converting to a finer unit fails on the first value
+    /// that overflows, e.g. a far future timestamp in milliseconds read as nanoseconds;
+    /// converting to a coarser unit truncates.
+    pub fn with_datetime_unit(mut self, tu: Option<TimeUnit>) -> Self {
+        self.datetime_unit = tu;
+        self
+    }
+
+    /// Whether the limit of `with_n_rows` is applied before the scan aggregations, the
+    /// default, or after them. Before, only the first `n_rows` rows are aggregated, e.g.
+    /// the sum of the first `n_rows` values. After, all rows are read and aggregated and
+    /// the limit only bounds the rows of the aggregated frame. Without aggregations both
+    /// are the same.
+    pub fn with_limit_before_agg(mut self, limit_before_agg: bool) -> Self {
+        self.limit_before_agg = limit_before_agg;
+        self
+    }
+
+    /// Set what to do when the row count column of [`ParquetReader::with_row_count`] has
+    /// the name of a column that is read. Defaults to [`RowCountCollision::Error`].
+    pub fn on_row_count_collision(mut self, policy: RowCountCollision) -> Self {
+        self.row_count_collision = policy;
+        self
+    }
+
+    /// Use the arrow schema embedded in the file metadata instead of the schema derived
+    /// from the parquet types (default). This preserves data types like `Categorical`,
+    /// `Duration` and timezone aware `Datetime` for files written by polars.
+    pub fn use_arrow_metadata(mut self, toggle: bool) -> Self {
+        self.use_arrow_metadata = toggle;
+        self
+    }
+
+    /// Use metadata that was read before instead of reading it from the file.
+    pub fn with_metadata(mut self, metadata: Option<FileMetaData>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Use statistics that were collected before to prune row groups. This saves
+    /// deserializing the statistics from the metadata on every read of the same file.
+    pub fn with_cached_statistics(mut self, statistics: Option<Arc<FileStatistics>>) -> Self {
+        self.statistics = statistics;
+        self
+    }
+
+    /// Set the dtypes of columns of the parquet null type. These columns have
+    /// no stored values and are read as all-null columns. Columns that are not
+    /// in `dtypes` get the `Null` dtype.
+    pub fn with_null_column_dtypes(mut self, dtypes: Option<Schema>) -> Self {
+        self.null_dtypes = dtypes;
+        self
+    }
+
+    /// Set the dtypes of the columns that are filled with nulls: the columns of the
+    /// parquet null type and, with `allow_missing_columns`, the columns that a row group
+    /// lacks. These dtypes take precedence over `with_null_column_dtypes` and the dtype of
+    /// the file schema, so that null-filled columns of different files can be stacked
+    /// with populated ones. The row groups that do have such a column are cast to its
+    /// dtype.
+    pub fn with_null_fill_dtypes(mut self, dtypes: Option<PlHashMap<String, DataType>>) -> Self {
+        self.null_fill_dtypes = dtypes;
+        self
+    }
+
+    /// Limit the number of categories of a row group when reading dictionary
+    /// columns as `Categorical`. This guards against high cardinality columns
+    /// that would otherwise blow up memory.
+    pub fn with_max_categories(
+        mut self,
+        max_categories: Option<usize>,
+        overflow: CategoricalOverflow,
+    ) -> Self {
+        self.max_categories = max_categories.map(|max| (max, overflow));
+        self
+    }
+
+    /// Set how columns that are dictionaries in the schema or dictionary encoded in the
+    /// file are read. Defaults to [`DictionaryColumns::Schema`].
+    ///
+    /// A column that the schema marks as a dictionary is also read if the writer fell
+    /// back to plain encoding, by decoding its values and casting them.
+    pub fn with_dictionary_columns(mut self, dictionary_columns: DictionaryColumns) -> Self {
+        self.dictionary_columns = dictionary_columns;
+        self
+    }
+
+    /// Set the width of the offsets that string and binary columns are decoded with, by
+    /// column name. The other columns are decoded with 64 bit offsets if the uncompressed
+    /// size of one of their column chunks exceeds what 32 bit offsets can address, and
+    /// with the width of the schema otherwise.
+    ///
+    /// The width only matters while decoding, polars stores all strings with 64 bit
+    /// offsets. Forcing 32 bit offsets on a column chunk with more than 2GB of values
+    /// makes the read fail.
+    pub fn with_offset_widths(mut self, widths: Option<PlHashMap<String, OffsetWidth>>) -> Self {
+        self.offset_widths = widths;
+        self
+    }
+
+    /// Build the `Categorical` columns against the global string cache, which the holder
+    /// keeps enabled until the read is finished. Categoricals that are read from several
+    /// files while the string cache is held share their categories, so they can be
+    /// concatenated without remapping.
+    #[cfg(feature = "dtype-categorical")]
+    pub fn with_string_cache(mut self, string_cache: Option<StringCacheHolder>) -> Self {
+        self.string_cache = string_cache;
+        self
+    }
+
+    /// Set how columns of the parquet `INTERVAL` type are read.
+    /// Defaults to [`ParquetInterval::Duration`].
+    pub fn with_interval(mut self, interval: ParquetInterval) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    fn get_metadata(&mut self) -> Result<FileMetaData> {
+        match self.metadata.take() {
+            Some(metadata) => Ok(metadata),
+            None => catch_malformed(|| Ok(read::read_metadata(&mut self.reader)?)),
+        }
+    }
+
     pub fn schema(mut self) -> Result<Schema> {
-        let metadata = read::read_metadata(&mut self.reader)?;
+        let mut metadata = self.get_metadata()?;
 
-        let schema = read::infer_schema(&metadata)?;
+        let mut schema = infer_schema(&metadata, self.use_arrow_metadata, self.interval)?;
+        set_dictionary_dtypes(&metadata, &mut schema, self.dictionary_columns);
+        set_offset_widths(&metadata, &mut schema, self.offset_widths.as_ref());
+        if self.duplicate_columns == Some(DuplicateColumns::Suffix) {
+            suffix_duplicate_columns(&mut metadata, &mut schema)?;
+        }
         Ok((&schema.fields).into())
     }
 }
 
+/// What to do when a dictionary column has more categories than allowed
+/// by [`ParquetReader::with_max_categories`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CategoricalOverflow {
+    /// Read the whole column as `Utf8` instead of `Categorical`.
+    Utf8,
+    /// Return an error.
+    Error,
+}
+
+/// What to do with the rows that have a null in a column set by
+/// [`ParquetReader::with_required_columns`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RequiredColumnNulls {
+    /// Drop the rows.
+    Drop,
+    /// Return an error.
+    Error,
+}
+
+/// How the rows of a limit are taken from the row groups, see
+/// [`ParquetReader::with_limit_distribution`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LimitDistribution {
+    /// Take the first rows of the file.
+    Head,
+    /// Take the first rows of every row group that is read, in proportion to its number
+    /// of rows, for a preview that is representative of files of which the row groups
+    /// hold different time or key ranges. The row groups are pruned up front and the
+    /// shares sum to the limit.
+    ///
+    /// With [`ParquetReader::limit_after_predicate`] a share counts the rows of the row
+    /// group that pass the predicate, and a row group with fewer such rows leaves its
+    /// share unfilled.
+    Even,
+}
+
+/// What to do with the columns that could not be decoded, see
+/// [`ParquetReader::finish_with_column_errors`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FailedColumns {
+    /// Fill the rows of the row groups the column failed in with nulls.
+    Null,
+    /// Leave the column out of the frame.
+    Omit,
+}
+
+/// The width of the offsets that the values of a string or binary column are decoded
+/// with, see [`ParquetReader::with_offset_widths`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OffsetWidth {
+    /// 32 bit offsets, as `Utf8` and `Binary`. The values of a column chunk can be at
+    /// most 2GB.
+    Regular,
+    /// 64 bit offsets, as `LargeUtf8` and `LargeBinary`.
+    Large,
+}
+
+/// How columns that are dictionaries in the schema or dictionary encoded in the file are
+/// read, see [`ParquetReader::with_dictionary_columns`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DictionaryColumns {
+    /// Read the columns with the dtypes of the schema, so the columns that the arrow
+    /// schema in the file marks as dictionaries are `Categorical`.
+    Schema,
+    /// Read dictionaries as their values, e.g. as `Utf8` instead of `Categorical`.
+    Values,
+    /// Also read string columns that are dictionary encoded in any row group as
+    /// `Categorical`.
+    #[cfg(feature = "dtype-categorical")]
+    Categorical,
+}
+
+/// What to do when a file has multiple columns with the same name, see
+/// [`ParquetReader::on_duplicate_columns`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DuplicateColumns {
+    /// Read the first column, and print a warning to stderr.
+    Warn,
+    /// Return an error.
+    Error,
+    /// Read every column. The second and later columns with the same name are renamed
+    /// to `name_1`, `name_2`, etc., skipping names that are already in the file, so
+    /// that they can be selected by name and their index is their position in the file.
+    Suffix,
+}
+
+/// What to do when the row count column has the name of a column that is read, see
+/// [`ParquetReader::on_row_count_collision`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RowCountCollision {
+    /// Return an error before anything is read.
+    Error,
+    /// Rename the row count column to `name_1`, `name_2`, etc., the first of them that
+    /// is not the name of a column of the file.
+    Suffix,
+}
+
+/// Check that the row count column doesn't have the name of one of the `projection`
+/// columns, or rename it.
+fn check_row_count_name(
+    row_count: &mut RowCount,
+    schema: &ArrowSchema,
+    projection: Option<&[usize]>,
+    policy: RowCountCollision,
+) -> Result<()> {
+    let collides = match projection {
+        Some(projection) => projection
+            .iter()
+            .any(|i| schema.fields[*i].name == row_count.name),
+        None => schema
+            .fields
+            .iter()
+            .any(|field| field.name == row_count.name),
+    };
+    if !collides {
+        return Ok(());
+    }
+    match policy {
+        RowCountCollision::Error => Err(PolarsError::Duplicate(
+            format!(
+                "the row count column '{}' has the name of a column of the parquet file",
+                row_count.name
+            )
+            .into(),
+        )),
+        RowCountCollision::Suffix => {
+            // the columns that are not read are skipped too, so the name is stable
+            // across projections
+            row_count.name = (1..)
+                .map(|i| format!("{}_{}", row_count.name, i))
+                .find(|name| schema.fields.iter().all(|field| &field.name != name))
+                .unwrap();
+            Ok(())
+        }
+    }
+}
+
+fn check_duplicate_columns(schema: &ArrowSchema, policy: DuplicateColumns) -> Result<()> {
+    let mut names = PlHashSet::with_capacity(schema.fields.len());
+    for field in &schema.fields {
+        if !names.insert(field.name.as_str()) {
+            let msg = format!(
+                "the parquet file has multiple columns named '{}', only the first is read",
+                field.name
+            );
+            match policy {
+                DuplicateColumns::Warn => eprintln!("{}", msg),
+                DuplicateColumns::Error => return Err(PolarsError::Duplicate(msg.into())),
+                DuplicateColumns::Suffix => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Rename the second and later columns with the same name in the schema and in the
+/// metadata of every row group, so that the column chunks are found by the new names.
+fn suffix_duplicate_columns(metadata: &mut FileMetaData, schema: &mut ArrowSchema) -> Result<()> {
+    let mut names = schema
+        .fields
+        .iter()
+        .map(|field| field.name.clone())
+        .collect::<PlHashSet<_>>();
+    let mut seen = PlHashSet::with_capacity(schema.fields.len());
+    // the new name of every leaf column, in the order of the column chunks
+    let mut leaf_names = Vec::with_capacity(metadata.schema().columns().len());
+    let mut renamed = false;
+    for field in schema.fields.iter_mut() {
+        if !seen.insert(field.name.clone()) {
+            let mut i = 1;
+            let name = loop {
+                let name = format!("{}_{}", field.name, i);
+                if !names.contains(&name) {
+                    break name;
+                }
+                i += 1;
+            };
+            names.insert(name.clone());
+            field.name = name;
+            renamed = true;
+        }
+        let n_leaves = read::n_columns(&field.data_type);
+        leaf_names.extend(std::iter::repeat(field.name.clone()).take(n_leaves));
+    }
+    if !renamed {
+        return Ok(());
+    }
+
+    for md in metadata.row_groups.iter_mut() {
+        if md.columns().len() != leaf_names.len() {
+            return Err(PolarsError::ComputeError(
+                format!(
+                    "a row group has {} column chunks, but the schema has {} columns",
+                    md.columns().len(),
+                    leaf_names.len()
+                )
+                .into(),
+            ));
+        }
+        let columns = md
+            .columns()
+            .iter()
+            .zip(leaf_names.iter())
+            .map(|(column, name)| {
+                let mut descriptor = column.descriptor().clone();
+                descriptor.path_in_schema[0] = name.clone();
+                read::ColumnChunkMetaData::try_from_thrift(
+                    descriptor,
+                    column.column_chunk().clone(),
+                )
+                .map_err(|e| PolarsError::ComputeError(e.to_string().into()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        *md = read::RowGroupMetaData::new(columns, md.num_rows(), md.total_byte_size());
+    }
+    Ok(())
+}
+
+/// Where to buffer a source that doesn't implement [`Seek`].
+pub enum NonSeekableBuffer {
+    /// Buffer the whole source in memory.
+    Memory,
+    /// Spill the whole source to a file at the given path.
+    File(PathBuf),
+}
+
+impl ParquetReader<Box<dyn MmapBytesReader>> {
+    /// Create a new [`ParquetReader`] from a source that doesn't implement [`Seek`], e.g. a pipe.
+    ///
+    /// The metadata of a parquet file is located at the end of the file, so the whole source is
+    /// buffered before anything is read. This defeats streaming and costs memory or disk
+    /// space equal to the size of the file.
+    pub fn from_non_seekable<S: Read>(mut source: S, buffer: NonSeekableBuffer) -> Result<Self> {
+        let reader: Box<dyn MmapBytesReader> = match buffer {
+            NonSeekableBuffer::Memory => {
+                let mut bytes = vec![];
+                source.read_to_end(&mut bytes)?;
+                Box::new(Cursor::new(bytes))
+            }
+            NonSeekableBuffer::File(path) => {
+                let mut file = OpenOptions::new()
+                    .read(true)
+                    .write(true)
+                    .create(true)
+                    .truncate(true)
+                    .open(path)?;
+                std::io::copy(&mut source, &mut file)?;
+                file.seek(SeekFrom::Start(0))?;
+                Box::new(file)
+            }
+        };
+        Ok(ParquetReader::new(reader))
+    }
+}
+
 impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
     /// Create a new [`ParquetReader`] from an existing `Reader`.
     fn new(reader: R) -> Self {
@@ -104,7 +1631,58 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             columns: None,
             projection: None,
             parallel: true,
+            parallel_threshold_bytes: DEFAULT_PARALLEL_THRESHOLD_BYTES,
+            coalesce_gap_bytes: DEFAULT_COALESCE_GAP_BYTES,
+            reverse: false,
             row_count: None,
+            row_count_collision: RowCountCollision::Error,
+            datetime_unit: None,
+            use_arrow_metadata: true,
+            limit_after_predicate: false,
+            limit_before_agg: true,
+            metadata: None,
+            statistics: None,
+            null_dtypes: None,
+            null_fill_dtypes: None,
+            max_categories: None,
+            dictionary_columns: DictionaryColumns::Schema,
+            offset_widths: None,
+            #[cfg(feature = "dtype-categorical")]
+            string_cache: None,
+            interval: ParquetInterval::default(),
+            read_spec: None,
+            pad_ragged_columns: false,
+            verify_row_counts: false,
+            windowed_mmap: false,
+            prune_only: false,
+            sample: None,
+            allow_missing_columns: false,
+            column_ranges: None,
+            since: None,
+            top_k: None,
+            limit_distribution: LimitDistribution::Head,
+            constraints: None,
+            required_columns: None,
+            chunk_lengths: None,
+            chunk_length: None,
+            post_predicate: None,
+            post_decode: None,
+            on_series: None,
+            #[cfg(all(feature = "json", feature = "dtype-struct"))]
+            json_expansion: None,
+            distinct_on: None,
+            row_count_i64: false,
+            predicate_only_columns: None,
+            deadline: None,
+            row_group_id: None,
+            metadata_columns: None,
+            duplicate_columns: Some(DuplicateColumns::Warn),
+            statistics_truncated_len: DEFAULT_STATISTICS_TRUNCATED_LEN,
+            decompression_pool: None,
+            decode_pipeline: None,
+            max_decompressed_bytes: None,
+            row_selection: None,
+            pruned_row_groups: None,
         }
     }
 
@@ -113,30 +1691,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
         self
     }
 
-    fn finish(mut self) -> Result<DataFrame> {
-        let metadata = read::read_metadata(&mut self.reader)?;
-        let schema = read::schema::infer_schema(&metadata)?;
-
-        if let Some(cols) = self.columns {
-            self.projection = Some(columns_to_projection(cols, &schema)?);
-        }
-
-        read_parquet(
-            self.reader,
-            self.n_rows.unwrap_or(usize::MAX),
-            self.projection.as_deref(),
-            &schema,
-            Some(metadata),
-            None,
-            None,
-            self.parallel,
-            self.row_count,
-        )
-        .map(|mut df| {
-            if self.rechunk {
-                df.rechunk();
-            }
-            df
-        })
+    fn finish(self) -> Result<DataFrame> {
+        self.finish_impl(None, None, None, ReadDiagnostics::default())
     }
 }
@@ -1,14 +1,33 @@
 use crate::aggregations::{apply_aggregations, ScanAggregation};
 use crate::mmap::{MmapBytesReader, ReaderBytes};
+use crate::parquet::boolean;
+use crate::parquet::fingerprint::ReadFingerprint;
+use crate::parquet::interval;
+use crate::parquet::interval::ParquetInterval;
+use crate::parquet::metadata_column::MetadataColumn;
 use crate::parquet::mmap;
-use crate::parquet::mmap::mmap_columns;
-use crate::parquet::predicates::collect_statistics;
-use crate::predicates::{apply_predicate, arrow_schema_to_empty_df, PhysicalIoExpr};
+use crate::parquet::mmap::{mmap_columns, DecompressionBudget, FileBytes};
+use crate::parquet::pipeline::DecodePipeline;
+use crate::parquet::pool::DecompressionPool;
+use crate::parquet::predicates::{collect_statistics, FileStatistics};
+use crate::parquet::pruned::PrunedRowGroups;
+use crate::parquet::read::{
+    infer_schema, CategoricalOverflow, FailedColumns, LimitDistribution, RequiredColumnNulls,
+    DEFAULT_COALESCE_GAP_BYTES,
+};
+use crate::parquet::sample::SampleSpec;
+use crate::parquet::source::ParquetSource;
+use crate::predicates::{
+    apply_predicate, arrow_schema_to_empty_df, PhysicalIoExpr, StatsEvaluation,
+};
 use crate::utils::apply_projection;
 use crate::RowCount;
-use arrow::array::new_empty_array;
+use arrow::array::{new_empty_array, DictionaryArray};
+use arrow::datatypes::IntegerType;
+use arrow::error::Error as ArrowError;
 use arrow::io::parquet::read;
-use arrow::io::parquet::read::{ArrayIter, FileMetaData};
+use arrow::io::parquet::read::{ArrayIter, FileMetaData, RowGroupMetaData};
+use arrow::io::parquet::write::Encoding;
 use polars_core::prelude::*;
 use polars_core::utils::accumulate_dataframes_vertical;
 use polars_core::POOL;
@@ -16,90 +35,1755 @@ use rayon::prelude::*;
 use std::borrow::Cow;
 use std::convert::TryFrom;
 use std::ops::Deref;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::Instant;
+
+/// Collects the arrow chunks of a single column, possibly over multiple
+/// row groups, so that a single `Series` is created when the buffer is finished.
+struct SeriesBuffer<'a> {
+    field: &'a ArrowField,
+    chunks: Vec<ArrayRef>,
+}
+
+impl<'a> SeriesBuffer<'a> {
+    fn new(field: &'a ArrowField) -> Self {
+        SeriesBuffer {
+            field,
+            chunks: Vec::with_capacity(2),
+        }
+    }
+
+    /// Append the arrays produced by `iter`. If `num_rows` is given, we stop
+    /// decoding once that many rows are appended. The last array is cut, so that
+    /// exactly `num_rows` rows are appended regardless of where the pages of the
+    /// column end.
+    fn extend(&mut self, iter: ArrayIter, num_rows: Option<usize>) -> Result<()> {
+        let mut total_count = 0;
+        for arr in iter {
+            let mut arr = arr?;
+            total_count += arr.len();
+            if let Some(n) = num_rows {
+                if total_count > n {
+                    arr = arr.slice(0, arr.len() - (total_count - n));
+                    total_count = n;
+                }
+            }
+            self.chunks.push(arr);
+
+            if matches!(num_rows, Some(n) if total_count >= n) {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Hand back the appended chunks as a `Series`.
+    fn finish(self) -> Result<Series> {
+        if self.chunks.is_empty() {
+            let arr = new_empty_array(self.field.data_type.clone());
+            Series::try_from((self.field.name.as_str(), arr))
+        } else {
+            Series::try_from((self.field.name.as_str(), self.chunks))
+        }
+    }
+}
 
 fn array_iter_to_series(
     iter: ArrayIter,
     field: &ArrowField,
     num_rows: Option<usize>,
 ) -> Result<Series> {
-    let mut total_count = 0;
-    let chunks = match num_rows {
-        None => iter.collect::<arrow::error::Result<Vec<_>>>()?,
-        Some(n) => {
-            let mut out = Vec::with_capacity(2);
-
-            for arr in iter {
-                let arr = arr?;
-                let len = arr.len();
-                out.push(arr);
-
-                total_count += len;
-                if total_count >= n {
-                    break;
+    let mut buf = SeriesBuffer::new(field);
+    buf.extend(iter, num_rows)?;
+    buf.finish()
+}
+
+pub(super) fn column_idx_to_array_iter<'a>(
+    column_i: usize,
+    md: &RowGroupMetaData,
+    remaining_rows: usize,
+    schema: &ArrowSchema,
+    bytes: FileBytes<'a>,
+    pool: Option<&'a DecompressionPool>,
+) -> Result<ArrayIter<'a>> {
+    let field = &schema.fields[column_i];
+    let columns = mmap_columns(bytes, md.columns(), &field.name)?;
+    let chunk_size = Some(md.num_rows());
+    if let Some(physical) = interval::interval_physical_field(&columns, field) {
+        let iter =
+            mmap::to_deserializer(columns, physical, remaining_rows, chunk_size, pool, bytes)?;
+        return Ok(interval::convert_intervals(iter, field.data_type.clone()));
+    }
+    let iter = mmap::to_deserializer(
+        columns,
+        field.clone(),
+        remaining_rows,
+        chunk_size,
+        pool,
+        bytes,
+    )?;
+    Ok(iter)
+}
+
+/// Options that determine how the column chunks of a row group become a `Series`.
+#[derive(Copy, Clone, Default)]
+struct ColumnOptions<'a> {
+    null_dtypes: Option<&'a Schema>,
+    null_fill_dtypes: Option<&'a PlHashMap<String, DataType>>,
+    #[cfg_attr(not(feature = "dtype-categorical"), allow(dead_code))]
+    max_categories: Option<(usize, CategoricalOverflow)>,
+    allow_missing_columns: bool,
+    decompression_pool: Option<&'a DecompressionPool>,
+}
+
+fn column_idx_to_series(
+    column_i: usize,
+    md: &RowGroupMetaData,
+    remaining_rows: usize,
+    schema: &ArrowSchema,
+    bytes: FileBytes,
+    options: ColumnOptions,
+) -> Result<Series> {
+    let field = &schema.fields[column_i];
+    let len = std::cmp::min(remaining_rows, md.num_rows());
+    // columns of the parquet null type have no values to decode
+    if field.data_type == ArrowDataType::Null {
+        let dtype = options
+            .null_fill_dtypes
+            .and_then(|dtypes| dtypes.get(&field.name))
+            .or_else(|| {
+                options
+                    .null_dtypes
+                    .and_then(|dtypes| dtypes.get(&field.name))
+            })
+            .unwrap_or(&DataType::Null);
+        return Ok(Series::full_null(&field.name, len, dtype));
+    }
+    if options.allow_missing_columns
+        && read::get_field_columns(md.columns(), &field.name).is_empty()
+    {
+        let dtype = match options
+            .null_fill_dtypes
+            .and_then(|dtypes| dtypes.get(&field.name))
+        {
+            Some(dtype) => dtype.clone(),
+            None => (&field.data_type).into(),
+        };
+        return Ok(Series::full_null(&field.name, len, &dtype));
+    }
+    let is_dictionary = matches!(field.data_type, ArrowDataType::Dictionary(..));
+    if is_dictionary && !is_dictionary_encoded(md, &field.name) {
+        return column_idx_to_values(column_i, md, remaining_rows, schema, bytes, options);
+    }
+    if field.data_type == ArrowDataType::Boolean && boolean::is_supported(md, &field.name) {
+        return boolean::decode(md, field, remaining_rows, options.decompression_pool, bytes);
+    }
+    // the bytes that the pages take from the budget, which are returned to it if the
+    // column chunk is decoded again
+    let charged = AtomicUsize::new(0);
+    let iter = column_idx_to_array_iter(
+        column_i,
+        md,
+        remaining_rows,
+        schema,
+        bytes.with_charges(&charged),
+        options.decompression_pool,
+    )?;
+
+    let num_rows = (remaining_rows < md.num_rows()).then(|| remaining_rows);
+    let s = match array_iter_to_series(iter, field, num_rows) {
+        // the writer fell back to plain encoding after the first pages
+        Err(_) if is_dictionary => {
+            bytes.release(charged.load(Ordering::Relaxed));
+            column_idx_to_values(column_i, md, remaining_rows, schema, bytes, options)?
+        }
+        s => s?,
+    };
+    #[cfg(feature = "dtype-categorical")]
+    if let Some((max, overflow)) = options.max_categories {
+        return limit_categories(s, max, overflow);
+    }
+    Ok(s)
+}
+
+/// Decode a column that the schema marks as a dictionary, but of which the column chunk
+/// has plain encoded pages, as its values and cast it to the dtype of the dictionary.
+fn column_idx_to_values(
+    column_i: usize,
+    md: &RowGroupMetaData,
+    remaining_rows: usize,
+    schema: &ArrowSchema,
+    bytes: FileBytes,
+    options: ColumnOptions,
+) -> Result<Series> {
+    let field = &schema.fields[column_i];
+    let mut values_schema = schema.clone();
+    if let ArrowDataType::Dictionary(_, values, _) = &field.data_type {
+        values_schema.fields[column_i].data_type = values.as_ref().clone();
+    }
+    let iter = column_idx_to_array_iter(
+        column_i,
+        md,
+        remaining_rows,
+        &values_schema,
+        bytes,
+        options.decompression_pool,
+    )?;
+    let num_rows = (remaining_rows < md.num_rows()).then(|| remaining_rows);
+    let s = array_iter_to_series(iter, &values_schema.fields[column_i], num_rows)?;
+    s.cast(&(&field.data_type).into())
+}
+
+/// Decode all rows of a column of a row group with the default options.
+pub(super) fn decode_column_chunk(
+    column_i: usize,
+    md: &RowGroupMetaData,
+    schema: &ArrowSchema,
+    bytes: &[u8],
+) -> Result<Series> {
+    column_idx_to_series(
+        column_i,
+        md,
+        usize::MAX,
+        schema,
+        bytes.into(),
+        ColumnOptions::default(),
+    )
+}
+
+/// Fall back to `Utf8` (or error) if a categorical `Series` has more than `max` categories.
+#[cfg(feature = "dtype-categorical")]
+fn limit_categories(s: Series, max: usize, overflow: CategoricalOverflow) -> Result<Series> {
+    if let DataType::Categorical(_) = s.dtype() {
+        let n_categories = s.categorical()?.get_rev_map().len();
+        if n_categories > max {
+            return match overflow {
+                CategoricalOverflow::Utf8 => s.cast(&DataType::Utf8),
+                CategoricalOverflow::Error => Err(PolarsError::ComputeError(
+                    format!(
+                        "column '{}' has {} categories, which exceeds the maximum of {}",
+                        s.name(),
+                        n_categories,
+                        max
+                    )
+                    .into(),
+                )),
+            };
+        }
+    }
+    Ok(s)
+}
+
+/// If a column fell back to `Utf8` in some row groups, cast it to `Utf8` in all
+/// row groups so that they can be stacked.
+#[cfg(feature = "dtype-categorical")]
+pub(super) fn unify_categorical_fallback(dfs: &mut [DataFrame]) -> Result<()> {
+    let width = dfs.first().map(|df| df.width()).unwrap_or(0);
+    for i in 0..width {
+        let dtype_in_any =
+            |f: &dyn Fn(&DataType) -> bool| dfs.iter().any(|df| f(df.get_columns()[i].dtype()));
+        if dtype_in_any(&|dt| matches!(dt, DataType::Utf8))
+            && dtype_in_any(&|dt| matches!(dt, DataType::Categorical(_)))
+        {
+            for df in dfs.iter_mut() {
+                let s = &mut df.get_columns_mut()[i];
+                if let DataType::Categorical(_) = s.dtype() {
+                    *s = s.cast(&DataType::Utf8)?;
                 }
             }
-            out
         }
+    }
+    Ok(())
+}
+
+/// Run `f`, of which a panic on a malformed file, e.g. in the decoding of a corrupt page
+/// or footer by arrow2 and parquet2, is returned as an error instead.
+pub(super) fn catch_malformed<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let msg = payload
+            .downcast_ref::<&str>()
+            .map(|msg| msg.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(PolarsError::ComputeError(
+            format!("invalid parquet file: {}", msg).into(),
+        ))
+    })
+}
+
+/// The smallest number of bytes of a page header, of which every page that holds values has
+/// one.
+const MIN_PAGE_HEADER_LEN: u64 = 8;
+
+/// Check that the row groups in the metadata are consistent with the schema and
+/// the file, so that malformed files return an error instead of panicking.
+///
+/// The number of rows is bounded by the file size: every page of a column chunk has a
+/// header and holds at most `i32::MAX` values, so the rows that a footer claims can't
+/// make a read allocate beyond what the file could hold.
+///
+/// If `allow_missing_columns` is set, row groups may lack column chunks.
+fn validate_metadata(
+    metadata: &FileMetaData,
+    file_len: usize,
+    allow_missing_columns: bool,
+) -> Result<()> {
+    let n_columns = metadata.schema().columns().len();
+    for (rg, md) in metadata.row_groups.iter().enumerate() {
+        let err = |msg: String| {
+            Err(PolarsError::ComputeError(
+                format!("invalid parquet file, row group {}: {}", rg, msg).into(),
+            ))
+        };
+        let n_chunks = md.columns().len();
+        if n_chunks > n_columns || (n_chunks < n_columns && !allow_missing_columns) {
+            return err(format!(
+                "expected {} column chunks, got {}",
+                n_columns,
+                md.columns().len()
+            ));
+        }
+        if n_chunks == 0 && md.num_rows() > 0 {
+            return err(format!("{} rows, but no column chunks", md.num_rows()));
+        }
+        for column in md.columns() {
+            let (start, len) = column.byte_range();
+            if start
+                .checked_add(len)
+                .map_or(true, |end| end > file_len as u64)
+            {
+                return err(format!(
+                    "column chunk at byte range {}..{} exceeds the file size of {} bytes",
+                    start,
+                    start.saturating_add(len),
+                    file_len
+                ));
+            }
+            // every row has at least one value (possibly a null) in every column
+            if column.num_values() < 0 || (column.num_values() as u64) < md.num_rows() as u64 {
+                return err(format!(
+                    "{} rows, but a column chunk has {} values",
+                    md.num_rows(),
+                    column.num_values()
+                ));
+            }
+            let max_values = (len / MIN_PAGE_HEADER_LEN).saturating_mul(i32::MAX as u64);
+            if column.num_values() as u64 > max_values {
+                return err(format!(
+                    "a column chunk of {} bytes can't hold its {} values",
+                    len,
+                    column.num_values()
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The columns of a malformed file may have different lengths within a row group.
+/// This returns an error, or pads the short columns with nulls if `pad` is set.
+pub(super) fn check_column_lengths(
+    mut columns: Vec<Series>,
+    rg: usize,
+    pad: bool,
+) -> Result<Vec<Series>> {
+    let max_len = columns.iter().map(|s| s.len()).max().unwrap_or(0);
+    if columns.iter().all(|s| s.len() == max_len) {
+        return Ok(columns);
+    }
+    if !pad {
+        let lengths = columns
+            .iter()
+            .map(|s| format!("'{}': {}", s.name(), s.len()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Err(PolarsError::ShapeMisMatch(
+            format!(
+                "the columns of row group {} have different lengths: {}",
+                rg, lengths
+            )
+            .into(),
+        ));
+    }
+    for s in columns.iter_mut() {
+        if s.len() < max_len {
+            let nulls = Series::full_null(s.name(), max_len - s.len(), s.dtype());
+            s.append(&nulls)?;
+        }
+    }
+    Ok(columns)
+}
+
+/// Check that every column of a row group has the `expected` number of rows, which is
+/// the number of rows in the metadata unless a limit cut the row group.
+pub(super) fn verify_row_counts(columns: &[Series], rg: usize, expected: usize) -> Result<()> {
+    let wrong = columns
+        .iter()
+        .filter(|s| s.len() != expected)
+        .map(|s| format!("'{}': {}", s.name(), s.len()))
+        .collect::<Vec<_>>();
+    if wrong.is_empty() {
+        Ok(())
+    } else {
+        Err(PolarsError::ShapeMisMatch(
+            format!(
+                "row group {} should have {} rows, but these columns decoded to: {}",
+                rg,
+                expected,
+                wrong.join(", ")
+            )
+            .into(),
+        ))
+    }
+}
+
+/// Evaluate the predicate against the statistics of a row group.
+///
+/// If `statistics` are given, they are used instead of collecting the statistics
+/// from the row group metadata. Otherwise string max values of at least
+/// `truncated_len` bytes are considered to be truncated.
+pub(super) fn evaluate_row_group(
+    predicate: Option<&Arc<dyn PhysicalIoExpr>>,
+    rg: usize,
+    file_metadata: &FileMetaData,
+    schema: &ArrowSchema,
+    statistics: Option<&FileStatistics>,
+    truncated_len: Option<usize>,
+) -> Result<StatsEvaluation> {
+    let pred = match predicate {
+        Some(pred) => pred,
+        None => return Ok(StatsEvaluation::DefinitelyTrue),
+    };
+    let pred = match pred.as_stats_evaluator() {
+        Some(pred) => pred,
+        None => return Ok(StatsEvaluation::Unknown),
     };
-    if chunks.is_empty() {
-        let arr = new_empty_array(field.data_type.clone());
-        Series::try_from((field.name.as_str(), arr))
+    let collected;
+    let stats = match statistics {
+        Some(statistics) => statistics.row_group(rg),
+        None => {
+            let md = &file_metadata.row_groups[rg];
+            collected = collect_statistics(std::slice::from_ref(md), schema, truncated_len)?;
+            collected.as_ref()
+        }
+    };
+    match stats {
+        // a parquet file may not have statistics of all columns
+        Some(stats) => match pred.evaluate_stats(stats) {
+            Err(PolarsError::NotFound(_)) => Ok(StatsEvaluation::Unknown),
+            eval => eval,
+        },
+        None => Ok(StatsEvaluation::Unknown),
+    }
+}
+
+/// Use the statistics in the file to determine if a row group must be read.
+fn read_this_row_group(
+    predicate: Option<&Arc<dyn PhysicalIoExpr>>,
+    rg: usize,
+    file_metadata: &FileMetaData,
+    schema: &ArrowSchema,
+    statistics: Option<&FileStatistics>,
+) -> Result<bool> {
+    // we only skip if the predicate is definitely false
+    evaluate_row_group(predicate, rg, file_metadata, schema, statistics, None)
+        .map(|eval| eval.should_read())
+}
+
+/// The number of arrow chunks that decoding produced per column of a row group.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RowGroupChunks {
+    /// The index of the row group in the file.
+    pub row_group: usize,
+    /// The name of every column that was read and its number of chunks.
+    pub columns: Vec<(String, usize)>,
+}
+
+/// Why a row group was not read, see [`RowGroupDecision`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PruneReason {
+    /// The row group has no rows.
+    Empty,
+    /// The row group is not in the sample.
+    Sample,
+    /// The row group can not hold the top k rows.
+    TopK,
+    /// None of the rows of the row group are in the row selection.
+    RowSelection,
+    /// The statistics show that the row group holds no rows past the bound of
+    /// `with_since`.
+    Since,
+    /// The statistics show that no row of the row group passes the predicate, either by
+    /// the min and max values or by the null counts.
+    Statistics,
+    /// The rows of the row group are not needed to reach the limit.
+    Limit,
+    /// The deadline passed before the row group was read.
+    Deadline,
+}
+
+/// Whether a row group was read or pruned, see
+/// [`ParquetReader::finish_with_row_group_decisions`](super::ParquetReader::finish_with_row_group_decisions).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RowGroupDecision {
+    /// The index of the row group in the file.
+    pub row_group: usize,
+    /// The number of rows of the row group in the file.
+    pub num_rows: usize,
+    /// Why the row group was not read, `None` if it was read.
+    pub pruned_by: Option<PruneReason>,
+}
+
+/// A column of a row group that could not be decoded, see
+/// [`ParquetReader::finish_with_column_errors`](super::ParquetReader::finish_with_column_errors).
+#[derive(Debug)]
+pub struct ColumnError {
+    /// The name of the column.
+    pub column: String,
+    /// The index of the row group in the file.
+    pub row_group: usize,
+    pub error: PolarsError,
+}
+
+/// The rows of a row group that violate a constraint, see
+/// [`ParquetReader::with_constraints`](super::ParquetReader::with_constraints).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstraintViolation {
+    /// The column the constraint is on.
+    pub column: String,
+    /// The index of the row group in the file.
+    pub row_group: usize,
+    /// The number of rows of the row group that violate the constraint.
+    pub rows: usize,
+    /// The value of the first row that violates the constraint.
+    pub example: String,
+}
+
+impl ConstraintViolation {
+    fn to_error(&self) -> PolarsError {
+        PolarsError::ComputeError(
+            format!(
+                "the constraint on column '{}' is violated by {} rows of row group {}, e.g. by {}",
+                self.column, self.rows, self.row_group, self.example
+            )
+            .into(),
+        )
+    }
+}
+
+/// Check the rows of a row group against the constraints. Constraints that the statistics
+/// show to hold for all rows are not evaluated.
+#[allow(clippy::too_many_arguments)]
+fn check_constraints(
+    df: &DataFrame,
+    constraints: &[(String, Arc<dyn PhysicalIoExpr>)],
+    rg: usize,
+    file_metadata: &FileMetaData,
+    schema: &ArrowSchema,
+    statistics: Option<&FileStatistics>,
+    truncated_len: Option<usize>,
+    mut violations: Option<&mut Vec<ConstraintViolation>>,
+) -> Result<()> {
+    for (column, constraint) in constraints {
+        let holds = evaluate_row_group(
+            Some(constraint),
+            rg,
+            file_metadata,
+            schema,
+            statistics,
+            truncated_len,
+        )? == StatsEvaluation::DefinitelyTrue;
+        if holds || df.is_empty() {
+            continue;
+        }
+        let mask = constraint.evaluate(df)?;
+        // like SQL, a constraint that evaluates to null holds
+        let violated = mask.bool()?.into_iter().map(|v| v == Some(false));
+        let mut first = None;
+        let mut rows = 0;
+        for (idx, violated) in violated.enumerate() {
+            if violated {
+                first.get_or_insert(idx);
+                rows += 1;
+            }
+        }
+        if let Some(idx) = first {
+            let violation = ConstraintViolation {
+                column: column.clone(),
+                row_group: rg,
+                rows,
+                example: format!("{}", df.column(column)?.get(idx)),
+            };
+            match violations.as_deref_mut() {
+                Some(violations) => violations.push(violation),
+                None => return Err(violation.to_error()),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Drop the rows of a row group with a null in one of the required `columns`, or fail.
+/// Columns of which the statistics show no nulls are not checked.
+fn check_required_columns(
+    df: &mut DataFrame,
+    columns: &[String],
+    on_null: RequiredColumnNulls,
+    rg: usize,
+    md: &RowGroupMetaData,
+    schema: &ArrowSchema,
+    statistics: Option<&FileStatistics>,
+) -> Result<()> {
+    let mut keep: Option<BooleanChunked> = None;
+    for column in columns {
+        let null_count = match statistics.and_then(|statistics| statistics.row_group(rg)) {
+            Some(stats) => stats.get_stats(column).ok().and_then(|s| s.null_count()),
+            None => {
+                let field = &schema.fields[schema.try_index_of(column)?];
+                let field_schema = ArrowSchema::from(vec![field.clone()]);
+                collect_statistics(std::slice::from_ref(md), &field_schema, None)?
+                    .and_then(|stats| stats.get_stats(column).ok().and_then(|s| s.null_count()))
+            }
+        };
+        if null_count == Some(0) {
+            continue;
+        }
+        let s = df.column(column)?;
+        if s.null_count() == 0 {
+            continue;
+        }
+        match on_null {
+            RequiredColumnNulls::Error => {
+                return Err(PolarsError::ComputeError(
+                    format!(
+                        "the required column '{}' has {} nulls in row group {}",
+                        column,
+                        s.null_count(),
+                        rg
+                    )
+                    .into(),
+                ))
+            }
+            RequiredColumnNulls::Drop => {
+                let is_not_null = s.is_not_null();
+                keep = Some(match keep {
+                    Some(keep) => keep & is_not_null,
+                    None => is_not_null,
+                });
+            }
+        }
+    }
+    if let Some(keep) = keep {
+        *df = df.filter(&keep)?;
+    }
+    Ok(())
+}
+
+/// The number of rows a read will produce, as resolved from the metadata by
+/// [`resolve_row_count`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedRowCount {
+    pub num_rows: usize,
+    /// If `false`, the statistics can't tell which rows pass the predicate and
+    /// `num_rows` is an upper bound.
+    pub is_exact: bool,
+}
+
+/// Resolve the number of rows a read with this `predicate` and `limit` produces,
+/// without decoding any data. Row groups are pruned with the same statistics as
+/// the read itself.
+pub fn resolve_row_count(
+    metadata: &FileMetaData,
+    schema: &ArrowSchema,
+    predicate: Option<&Arc<dyn PhysicalIoExpr>>,
+    limit: Option<usize>,
+) -> Result<ResolvedRowCount> {
+    let limit = limit.unwrap_or(usize::MAX);
+    let mut num_rows: usize = 0;
+    let mut is_exact = true;
+    for rg in 0..metadata.row_groups.len() {
+        if num_rows >= limit {
+            break;
+        }
+        match evaluate_row_group(predicate, rg, metadata, schema, None, None)? {
+            StatsEvaluation::DefinitelyFalse => {}
+            StatsEvaluation::DefinitelyTrue => {
+                num_rows = num_rows.saturating_add(metadata.row_groups[rg].num_rows())
+            }
+            StatsEvaluation::Unknown => {
+                num_rows = num_rows.saturating_add(metadata.row_groups[rg].num_rows());
+                is_exact = false;
+            }
+        }
+    }
+    Ok(ResolvedRowCount {
+        num_rows: num_rows.min(limit),
+        is_exact,
+    })
+}
+
+/// Like [`DataFrame::with_row_count_mut`], but the row count is an `Int64` column.
+fn with_row_count_i64(df: &mut DataFrame, name: &str, offset: i64) {
+    let mut ca = Int64Chunked::from_vec(name, (offset..offset + df.height() as i64).collect());
+    ca.set_sorted(false);
+    df.get_columns_mut().insert(0, ca.into_series());
+}
+
+/// A function that is called with the frame of every row group, see
+/// [`ParquetReader::with_post_decode`](super::ParquetReader::with_post_decode).
+pub type PostDecode = dyn Fn(&mut DataFrame) -> Result<()> + Send + Sync;
+
+/// A function that is called with the name and the `Series` of every decoded column,
+/// see [`ParquetReader::with_on_series`](super::ParquetReader::with_on_series).
+pub type OnSeries = dyn Fn(&str, Series) -> Result<Series> + Send + Sync;
+
+/// Reads from within the thread pool of files with fewer (uncompressed) bytes are serial.
+const SERIAL_IN_POOL_MAX_BYTES: usize = 1 << 20;
+
+/// The options of a read, taken from the fields of a [`ParquetReader`](super::ParquetReader).
+pub(super) struct ParquetReadOptions<'a> {
+    pub(super) limit: usize,
+    pub(super) projection: Option<&'a [usize]>,
+    pub(super) aggregate: Option<&'a [ScanAggregation]>,
+    pub(super) parallel: bool,
+    pub(super) parallel_threshold_bytes: usize,
+    pub(super) coalesce_gap_bytes: u64,
+    pub(super) row_count: Option<RowCount>,
+    pub(super) limit_after_predicate: bool,
+    pub(super) statistics: Option<&'a FileStatistics>,
+    pub(super) null_dtypes: Option<&'a Schema>,
+    pub(super) max_categories: Option<(usize, CategoricalOverflow)>,
+    pub(super) pad_ragged_columns: bool,
+    pub(super) prune_only: bool,
+    pub(super) sample: Option<&'a SampleSpec>,
+    pub(super) allow_missing_columns: bool,
+    pub(super) post_predicate: Option<&'a dyn PhysicalIoExpr>,
+    pub(super) row_count_i64: bool,
+    pub(super) predicate_only_columns: Option<&'a [String]>,
+    pub(super) deadline: Option<Instant>,
+    pub(super) row_group_id: Option<&'a str>,
+    pub(super) statistics_truncated_len: Option<usize>,
+    pub(super) decompression_pool: Option<&'a DecompressionPool>,
+    pub(super) row_selection: Option<&'a BooleanChunked>,
+    pub(super) pruned_row_groups: Option<&'a PrunedRowGroups>,
+    pub(super) post_decode: Option<&'a PostDecode>,
+    pub(super) windowed_mmap: bool,
+    pub(super) distinct_on: Option<&'a [String]>,
+    pub(super) since: Option<&'a Arc<dyn PhysicalIoExpr>>,
+    pub(super) limit_distribution: LimitDistribution,
+    pub(super) constraints: Option<&'a [(String, Arc<dyn PhysicalIoExpr>)]>,
+    pub(super) pipeline: Option<&'a DecodePipeline>,
+    pub(super) max_decompressed_bytes: Option<usize>,
+    pub(super) metadata_columns: Option<&'a [MetadataColumn]>,
+    pub(super) verify: bool,
+    pub(super) selected_row_groups: Option<&'a [bool]>,
+    pub(super) reverse: bool,
+    pub(super) required_columns: Option<(&'a [String], RequiredColumnNulls)>,
+    pub(super) on_series: Option<&'a OnSeries>,
+    pub(super) datetime_unit: Option<TimeUnit>,
+    pub(super) limit_before_agg: bool,
+    pub(super) null_fill_dtypes: Option<&'a PlHashMap<String, DataType>>,
+}
+
+/// What a read reports besides the frame. Only the reports that are set are collected,
+/// e.g. `ReadDiagnostics { chunk_counts: Some(&mut counts), ..Default::default() }`.
+///
+/// Some reports change the read: with `timed_out` a passed deadline stops the read
+/// instead of failing it, with `column_errors` a column that fails to decode is
+/// collected instead of failing the read, and with `partitions` the frames of the row
+/// groups are partitioned into it instead of being returned.
+#[derive(Default)]
+pub(super) struct ReadDiagnostics<'a> {
+    pub(super) fingerprint: Option<&'a mut ReadFingerprint>,
+    pub(super) timed_out: Option<&'a mut bool>,
+    pub(super) chunk_counts: Option<&'a mut Vec<RowGroupChunks>>,
+    pub(super) limit_reached: Option<&'a mut bool>,
+    pub(super) cast_failures: Option<&'a mut usize>,
+    pub(super) pruned_row_groups: Option<&'a mut Option<PrunedRowGroups>>,
+    pub(super) column_errors: Option<(FailedColumns, &'a mut Vec<ColumnError>)>,
+    pub(super) violations: Option<&'a mut Vec<ConstraintViolation>>,
+    pub(super) partitions: Option<(
+        &'a str,
+        &'a mut PlHashMap<AnyValue<'static>, Vec<DataFrame>>,
+    )>,
+    pub(super) decisions: Option<&'a mut Vec<RowGroupDecision>>,
+}
+
+/// Check the options of a read against the file, before any row group is read.
+fn validate_read(
+    file_metadata: &FileMetaData,
+    schema: &ArrowSchema,
+    predicate: Option<&Arc<dyn PhysicalIoExpr>>,
+    options: &ParquetReadOptions,
+    partition: Option<&str>,
+) -> Result<()> {
+    let row_group_len = file_metadata.row_groups.len();
+    if let Some(statistics) = options.statistics {
+        if statistics.num_row_groups() != row_group_len {
+            return Err(PolarsError::ComputeError(
+                format!(
+                    "the given statistics are collected from {} row groups, but the file has {} row groups",
+                    statistics.num_row_groups(),
+                    row_group_len
+                )
+                .into(),
+            ));
+        }
+    }
+
+    if let Some(pruned) = options.pruned_row_groups {
+        pruned.validate(file_metadata, predicate)?;
+    }
+    for column in options.metadata_columns.unwrap_or_default() {
+        column.validate(schema)?;
+    }
+    if let Some((columns, _)) = options.required_columns {
+        for column in columns {
+            schema.try_index_of(column)?;
+        }
+    }
+    if let Some(column) = partition {
+        let field = &schema.fields[schema.try_index_of(column)?];
+        check_partition_dtype(column, &(&field.data_type).into())?;
+    }
+
+    if let Some(selection) = options.row_selection {
+        if selection.len() != file_metadata.num_rows {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "the row selection has {} rows, but the file has {} rows",
+                    selection.len(),
+                    file_metadata.num_rows
+                )
+                .into(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The columns of the `projection` that are decoded. The aggregations replace the
+/// columns, so only the columns they refer to and the columns the row groups are checked,
+/// deduplicated or partitioned on before that have to be decoded; a predicate, a
+/// constraint or `post_decode` may refer to any column.
+fn decoded_columns<'p>(
+    projection: &'p [usize],
+    schema: &ArrowSchema,
+    has_predicate: bool,
+    options: &ParquetReadOptions,
+    partition: Option<&str>,
+) -> Cow<'p, [usize]> {
+    match options.aggregate {
+        Some(aggregate)
+            if !has_predicate
+                && options.post_predicate.is_none()
+                && options.constraints.is_none()
+                && options.post_decode.is_none() =>
+        {
+            let used = aggregate
+                .iter()
+                .map(|agg| agg.column())
+                .chain(
+                    options
+                        .distinct_on
+                        .unwrap_or_default()
+                        .iter()
+                        .map(|c| c.as_str()),
+                )
+                .chain(
+                    options
+                        .required_columns
+                        .map_or(&[][..], |(columns, _)| columns)
+                        .iter()
+                        .map(|c| c.as_str()),
+                )
+                .chain(partition)
+                .collect::<PlHashSet<_>>();
+            let referenced = projection
+                .iter()
+                .copied()
+                .filter(|i| used.contains(schema.fields[*i].name.as_str()))
+                .collect::<Vec<_>>();
+            if referenced.is_empty() {
+                Cow::Borrowed(projection)
+            } else {
+                Cow::Owned(referenced)
+            }
+        }
+        _ => Cow::Borrowed(projection),
+    }
+}
+
+/// Whether the `n_columns` decoded columns of a row group are decoded in parallel.
+fn decode_in_parallel(
+    file_metadata: &FileMetaData,
+    n_columns: usize,
+    options: &ParquetReadOptions,
+) -> bool {
+    if !options.parallel || n_columns == 1 {
+        return false;
+    }
+    let compressed_size = file_metadata
+        .row_groups
+        .iter()
+        .flat_map(|md| md.columns())
+        .map(|column| column.compressed_size() as usize)
+        .sum::<usize>();
+    if compressed_size < options.parallel_threshold_bytes {
+        return false;
+    }
+    // a read from within the thread pool, e.g. one of many scans of a query, competes with
+    // the other tasks of the pool; for small files the overhead of scheduling the columns
+    // outweighs decoding them in parallel
+    if POOL.current_thread_index().is_some() {
+        let size = file_metadata
+            .row_groups
+            .iter()
+            .map(|md| md.total_byte_size())
+            .sum::<usize>();
+        if size < SERIAL_IN_POOL_MAX_BYTES {
+            return false;
+        }
+    }
+    true
+}
+
+/// Write what determines the rows of a read to its fingerprint.
+fn write_fingerprint(
+    fingerprint: &mut ReadFingerprint,
+    projection: &[usize],
+    predicate: Option<&dyn PhysicalIoExpr>,
+    post_predicate: Option<&dyn PhysicalIoExpr>,
+    rows_limit: usize,
+    limit_after_predicate: bool,
+) {
+    fingerprint.write_usize(projection.len());
+    for i in projection.iter() {
+        fingerprint.write_usize(*i);
+    }
+    for predicate in [predicate, post_predicate] {
+        match predicate.map(|pred| pred.display()) {
+            Some(Some(repr)) => fingerprint.write_str(&repr),
+            Some(None) => fingerprint.write_str("<predicate>"),
+            None => fingerprint.write_str(""),
+        }
+    }
+    fingerprint.write_usize(rows_limit);
+    fingerprint.write_bool(limit_after_predicate);
+}
+
+/// The evaluation of the predicate and the selected rows of a row group that is read,
+/// or why it is not read.
+type Selection = std::result::Result<(StatsEvaluation, Option<BooleanChunked>), PruneReason>;
+
+/// The options that select the row groups that are read, see [`select_row_group`].
+struct SelectOptions<'a> {
+    predicate: Option<&'a Arc<dyn PhysicalIoExpr>>,
+    statistics: Option<&'a FileStatistics>,
+    statistics_truncated_len: Option<usize>,
+    sampled: Option<Vec<bool>>,
+    selected_row_groups: Option<&'a [bool]>,
+    row_selection: Option<&'a BooleanChunked>,
+    since: Option<&'a Arc<dyn PhysicalIoExpr>>,
+    pruned_row_groups: Option<&'a PrunedRowGroups>,
+}
+
+/// Select the row group `rg`, of which the first row is the row `previous_row_count` of
+/// the file.
+fn select_row_group(
+    rg: usize,
+    previous_row_count: u64,
+    file_metadata: &FileMetaData,
+    schema: &ArrowSchema,
+    options: &SelectOptions,
+) -> Result<Selection> {
+    let md = &file_metadata.row_groups[rg];
+    if matches!(&options.sampled, Some(sampled) if !sampled[rg]) {
+        return Ok(Err(PruneReason::Sample));
+    }
+    if matches!(options.selected_row_groups, Some(selected) if !selected[rg]) {
+        return Ok(Err(PruneReason::TopK));
+    }
+    let selected = options
+        .row_selection
+        .map(|selection| selection.slice(previous_row_count as i64, md.num_rows()));
+    if matches!(&selected, Some(selected) if !selected.any()) {
+        return Ok(Err(PruneReason::RowSelection));
+    }
+    if options.since.is_some()
+        && !evaluate_row_group(
+            options.since,
+            rg,
+            file_metadata,
+            schema,
+            options.statistics,
+            options.statistics_truncated_len,
+        )?
+        .should_read()
+    {
+        return Ok(Err(PruneReason::Since));
+    }
+    let eval = match options.pruned_row_groups {
+        Some(pruned) => pruned.evaluation(rg),
+        None => evaluate_row_group(
+            options.predicate,
+            rg,
+            file_metadata,
+            schema,
+            options.statistics,
+            options.statistics_truncated_len,
+        )?,
+    };
+    if eval.should_read() {
+        Ok(Ok((eval, selected)))
     } else {
-        Series::try_from((field.name.as_str(), chunks))
+        Ok(Err(PruneReason::Statistics))
+    }
+}
+
+/// Select all row groups of a file, and distribute the `limit` evenly over the rows of
+/// the row groups that are read.
+fn select_up_front(
+    file_metadata: &FileMetaData,
+    schema: &ArrowSchema,
+    options: &SelectOptions,
+    limit: usize,
+) -> Result<(Vec<Selection>, Vec<usize>)> {
+    let row_group_len = file_metadata.row_groups.len();
+    let mut previous_row_count = 0;
+    let mut selected = Vec::with_capacity(row_group_len);
+    let mut rows = Vec::with_capacity(row_group_len);
+    for md in &file_metadata.row_groups {
+        let selection = match md.num_rows() {
+            0 => Err(PruneReason::Empty),
+            _ => select_row_group(
+                selected.len(),
+                previous_row_count,
+                file_metadata,
+                schema,
+                options,
+            )?,
+        };
+        rows.push(if selection.is_ok() { md.num_rows() } else { 0 });
+        selected.push(selection);
+        previous_row_count += md.num_rows() as u64;
     }
+    let shares = distribute_limit(limit, &rows);
+    Ok((selected, shares))
+}
+
+/// Where the column chunks of a read are taken from, see [`fetch_row_group`].
+#[derive(Copy, Clone)]
+enum ReadFrom<'a> {
+    Source(&'a dyn ParquetSource),
+    Window(&'a std::fs::File),
+    Bytes(&'a [u8]),
+}
+
+/// The bytes of the column chunks of a row group.
+enum RowGroupBytes<'a> {
+    Ranges(Vec<(u64, Vec<u8>)>),
+    Window(Option<(u64, memmap::Mmap)>),
+    Bytes(&'a [u8]),
 }
 
+impl<'a> RowGroupBytes<'a> {
+    fn file_bytes<'b>(&'b self, budget: Option<&'b DecompressionBudget>) -> FileBytes<'b> {
+        let bytes = match self {
+            RowGroupBytes::Ranges(ranges) => FileBytes::ranges(ranges),
+            RowGroupBytes::Window(Some((offset, mmap))) => FileBytes::window(&mmap[..], *offset),
+            RowGroupBytes::Window(None) => FileBytes::from(&[][..]),
+            RowGroupBytes::Bytes(bytes) => FileBytes::from(*bytes),
+        };
+        match budget {
+            Some(budget) => bytes.with_budget(budget),
+            None => bytes,
+        }
+    }
+}
+
+/// Read the column chunks of the `columns` of the row group `md` from a source or map
+/// them from a file. The column chunks of a file that is in memory are not copied.
+fn fetch_row_group<'a>(
+    from: ReadFrom<'a>,
+    md: &RowGroupMetaData,
+    columns: &[usize],
+    schema: &ArrowSchema,
+    coalesce_gap_bytes: u64,
+) -> Result<RowGroupBytes<'a>> {
+    Ok(match from {
+        ReadFrom::Source(source) => RowGroupBytes::Ranges(read_row_group_ranges(
+            source,
+            md,
+            columns,
+            schema,
+            coalesce_gap_bytes,
+        )?),
+        ReadFrom::Window(file) => RowGroupBytes::Window(map_row_group(file, md, columns, schema)?),
+        ReadFrom::Bytes(bytes) => RowGroupBytes::Bytes(bytes),
+    })
+}
+
+/// The options that determine how the columns of a row group are decoded, see
+/// [`decode_row_group`].
+struct DecodeOptions<'a> {
+    columns: ColumnOptions<'a>,
+    predicate_only_columns: Option<&'a [String]>,
+    datetime_unit: Option<TimeUnit>,
+    on_series: Option<&'a OnSeries>,
+    pipeline: Option<&'a DecodePipeline>,
+    parallel: bool,
+    verify: bool,
+    pad_ragged_columns: bool,
+}
+
+/// Decode the `decoded` columns of the row group `rg` into a frame of at most `rows`
+/// rows. If `all_rows_pass` the predicate, the columns that only the predicate needs are
+/// null instead.
 #[allow(clippy::too_many_arguments)]
-pub fn read_parquet<R: MmapBytesReader>(
-    mut reader: R,
+fn decode_row_group(
+    rg: usize,
+    md: &RowGroupMetaData,
+    bytes: FileBytes,
+    decoded: &[usize],
+    schema: &ArrowSchema,
+    rows: usize,
+    all_rows_pass: bool,
+    options: &DecodeOptions,
+    column_errors: Option<&mut Vec<ColumnError>>,
+    chunk_counts: Option<&mut Vec<RowGroupChunks>>,
+) -> Result<DataFrame> {
+    let decode = |column_i: usize, bytes: FileBytes| {
+        let field = &schema.fields[column_i];
+        let values_needed = !all_rows_pass
+            || !matches!(options.predicate_only_columns, Some(names) if names.contains(&field.name));
+        #[cfg(test)]
+        crate::parquet::fault::check(rg, &field.name)?;
+        let s = if values_needed {
+            catch_malformed(|| {
+                column_idx_to_series(column_i, md, rows, schema, bytes, options.columns)
+            })?
+        } else {
+            let len = std::cmp::min(rows, md.num_rows());
+            Series::full_null(&field.name, len, &(&field.data_type).into())
+        };
+        // the row groups that have the column are stacked with the null-filled ones
+        let s = match options
+            .columns
+            .null_fill_dtypes
+            .and_then(|dtypes| dtypes.get(&field.name))
+        {
+            Some(dtype) if s.dtype() != dtype => s.cast(dtype)?,
+            _ => s,
+        };
+        let s = match options.datetime_unit {
+            Some(tu) => convert_time_unit(s, tu)?,
+            None => s,
+        };
+        match options.on_series {
+            Some(on_series) => on_series(&field.name, s),
+            None => Ok(s),
+        }
+    };
+    let columns = match options.pipeline {
+        Some(pipeline) => pipeline.run(
+            decoded,
+            bytes,
+            md,
+            schema,
+            options.columns.decompression_pool,
+            decode,
+        ),
+        None if options.parallel => POOL.install(|| {
+            decoded
+                .par_iter()
+                .map(|column_i| decode(*column_i, bytes))
+                .collect::<Vec<_>>()
+        }),
+        None => decoded
+            .iter()
+            .map(|column_i| decode(*column_i, bytes))
+            .collect(),
+    };
+    let columns = match column_errors {
+        None => columns.into_iter().collect::<Result<Vec<_>>>()?,
+        Some(column_errors) => {
+            // the columns that fail are null, so that the other columns can be read
+            columns
+                .into_iter()
+                .zip(decoded.iter())
+                .map(|(s, column_i)| {
+                    s.unwrap_or_else(|error| {
+                        let field = &schema.fields[*column_i];
+                        column_errors.push(ColumnError {
+                            column: field.name.clone(),
+                            row_group: rg,
+                            error,
+                        });
+                        let len = std::cmp::min(rows, md.num_rows());
+                        let mut dtype = DataType::from(&field.data_type);
+                        if let (Some(tu), DataType::Datetime(unit, _) | DataType::Duration(unit)) =
+                            (options.datetime_unit, &mut dtype)
+                        {
+                            *unit = tu;
+                        }
+                        Series::full_null(&field.name, len, &dtype)
+                    })
+                })
+                .collect()
+        }
+    };
+
+    if let Some(chunk_counts) = chunk_counts {
+        chunk_counts.push(RowGroupChunks {
+            row_group: rg,
+            columns: columns
+                .iter()
+                .map(|s| (s.name().to_string(), s.n_chunks()))
+                .collect(),
+        });
+    }
+    if options.verify {
+        verify_row_counts(&columns, rg, std::cmp::min(rows, md.num_rows()))?;
+    }
+    let columns = check_column_lengths(columns, rg, options.pad_ragged_columns)?;
+    Ok(DataFrame::new_no_checks(columns))
+}
+
+/// The options that are applied to the frame of a row group once it is decoded, see
+/// [`post_process_row_group`].
+struct PostProcessOptions<'a> {
+    predicate: Option<&'a dyn PhysicalIoExpr>,
+    prune_only: bool,
+    post_predicate: Option<&'a dyn PhysicalIoExpr>,
+    row_count: Option<&'a RowCount>,
+    row_count_i64: bool,
+    row_group_id: Option<&'a str>,
+    metadata_columns: Option<&'a [MetadataColumn]>,
+    reverse: bool,
+    post_decode: Option<&'a PostDecode>,
+    required_columns: Option<(&'a [String], RequiredColumnNulls)>,
+    constraints: Option<&'a [(String, Arc<dyn PhysicalIoExpr>)]>,
+    distinct_on: Option<&'a [String]>,
+    statistics: Option<&'a FileStatistics>,
+    statistics_truncated_len: Option<usize>,
+}
+
+/// Add the row count and the metadata columns to the frame of the row group `rg`, of
+/// which the first row is the row `previous_row_count` of the file, and filter it.
+#[allow(clippy::too_many_arguments)]
+fn post_process_row_group(
+    mut df: DataFrame,
+    rg: usize,
+    previous_row_count: u64,
+    selected: Option<&BooleanChunked>,
+    all_rows_pass: bool,
+    file_metadata: &FileMetaData,
+    schema: &ArrowSchema,
+    options: &PostProcessOptions,
+    violations: Option<&mut Vec<ConstraintViolation>>,
+) -> Result<DataFrame> {
+    let md = &file_metadata.row_groups[rg];
+    if let Some(rc) = options.row_count {
+        if options.row_count_i64 {
+            let offset = previous_row_count as i64 + rc.offset as i64;
+            with_row_count_i64(&mut df, &rc.name, offset);
+        } else {
+            let offset = previous_row_count + rc.offset as u64;
+            if offset + df.height() as u64 > IdxSize::MAX as u64 + 1 {
+                return Err(PolarsError::ComputeError(
+                    format!(
+                        "the row count of row group {} exceeds {}, read it as Int64 instead",
+                        rg,
+                        IdxSize::MAX
+                    )
+                    .into(),
+                ));
+            }
+            df.with_row_count_mut(&rc.name, Some(offset as IdxSize));
+        }
+    }
+    if let Some(name) = options.row_group_id {
+        let ca = IdxCa::full(name, rg as IdxSize, df.height());
+        df.get_columns_mut().push(ca.into_series());
+    }
+    for column in options.metadata_columns.unwrap_or_default() {
+        let s = column.materialize(md, schema, df.height())?;
+        df.get_columns_mut().push(s);
+    }
+
+    match selected {
+        Some(selected) if !selected.all() => df = df.filter(selected)?,
+        _ => {}
+    }
+    if options.reverse {
+        df = df.reverse();
+    }
+    if let Some(post_decode) = options.post_decode {
+        post_decode(&mut df)?;
+    }
+    if let Some((columns, on_null)) = options.required_columns {
+        check_required_columns(
+            &mut df,
+            columns,
+            on_null,
+            rg,
+            md,
+            schema,
+            options.statistics,
+        )?;
+    }
+    if !options.prune_only && !all_rows_pass {
+        apply_predicate(&mut df, options.predicate)?;
+    }
+    apply_predicate(&mut df, options.post_predicate)?;
+    if let Some(constraints) = options.constraints {
+        check_constraints(
+            &df,
+            constraints,
+            rg,
+            file_metadata,
+            schema,
+            options.statistics,
+            options.statistics_truncated_len,
+            violations,
+        )?;
+    }
+    if options.distinct_on.is_some() {
+        df = df.unique_stable(options.distinct_on, UniqueKeepStrategy::First)?;
+    }
+    Ok(df)
+}
+
+/// The rows of a read that are left to read, see [`limit_row_group`].
+struct LimitState {
+    remaining_rows: usize,
+    /// Whether rows were left unread because of the limit.
+    reached: bool,
+    /// The rows that every row group may add, if the limit is distributed evenly.
+    shares: Option<Vec<usize>>,
+    /// Whether the limit counts the rows of a row group that pass the predicate, instead
+    /// of all its rows.
+    after_predicate: bool,
+}
+
+impl LimitState {
+    /// The rows that the row group `rg` may add.
+    fn row_group_limit(&self, rg: usize) -> usize {
+        self.shares
+            .as_ref()
+            .map_or(self.remaining_rows, |shares| shares[rg])
+    }
+}
+
+/// Take the rows of the frame of the row group `md`, that may add `rg_limit` rows, from
+/// the limit and aggregate them.
+fn limit_row_group(
+    mut df: DataFrame,
+    md: &RowGroupMetaData,
+    rg_limit: usize,
+    limit: &mut LimitState,
+    aggregate: Option<&[ScanAggregation]>,
+    distinct_on: Option<&[String]>,
+) -> Result<DataFrame> {
+    let rows = if limit.after_predicate {
+        df.height()
+    } else {
+        md.num_rows()
+    };
+    limit.reached |= rows > rg_limit;
+    if limit.shares.is_some() {
+        // the shares of the other row groups are taken from them
+        df = df.slice(0, rg_limit);
+        limit.remaining_rows -= std::cmp::min(rows, rg_limit);
+    } else {
+        limit.remaining_rows = limit.remaining_rows.saturating_sub(rows);
+    }
+    if aggregate.is_some() {
+        // the rows past the limit are not aggregated, e.g. the rows that passed the
+        // predicate after the limit was reached
+        df = df.slice(0, rg_limit);
+    }
+    // the duplicates in different row groups are only dropped after stacking them, so
+    // the rows are aggregated once they are
+    if distinct_on.is_none() {
+        apply_aggregations(&mut df, aggregate)?;
+    }
+    Ok(df)
+}
+
+/// Partition the frame of a row group by the values of `column`.
+fn partition_row_group(
+    df: DataFrame,
+    column: &str,
+    partitions: &mut PlHashMap<AnyValue<'static>, Vec<DataFrame>>,
+) -> Result<()> {
+    for partition in df._partition_by_impl(&[column.to_string()], true)? {
+        let value = partition_key(partition.column(column)?)?;
+        partitions
+            .entry(value)
+            .or_insert_with(Vec::new)
+            .push(partition);
+    }
+    Ok(())
+}
+
+/// The options that are applied once all row groups are read, see [`finish_read`].
+struct FinishOptions<'a> {
     limit: usize,
-    projection: Option<&[usize]>,
+    aggregate: Option<&'a [ScanAggregation]>,
+    distinct_on: Option<&'a [String]>,
+    #[cfg_attr(not(feature = "dtype-categorical"), allow(dead_code))]
+    max_categories: Option<(usize, CategoricalOverflow)>,
+    null_dtypes: Option<&'a Schema>,
+    null_fill_dtypes: Option<&'a PlHashMap<String, DataType>>,
+    datetime_unit: Option<TimeUnit>,
+}
+
+/// Stack the frames of the row groups that were read, or create an empty frame of the
+/// `projection` if none were.
+fn finish_read(
+    mut dfs: Vec<DataFrame>,
     schema: &ArrowSchema,
+    projection: &Cow<[usize]>,
+    options: &FinishOptions,
+) -> Result<DataFrame> {
+    if dfs.is_empty() {
+        let schema = if let Cow::Borrowed(_) = projection {
+            Cow::Owned(apply_projection(schema, projection))
+        } else {
+            Cow::Borrowed(schema)
+        };
+        let mut df = arrow_schema_to_empty_df(&schema);
+        if let Some(dtypes) = options.null_dtypes {
+            for s in df.get_columns_mut() {
+                if let (DataType::Null, Some(dtype)) = (s.dtype(), dtypes.get(s.name())) {
+                    *s = Series::full_null(s.name(), 0, dtype);
+                }
+            }
+        }
+        if let Some(dtypes) = options.null_fill_dtypes {
+            for s in df.get_columns_mut() {
+                if let (DataType::Null, Some(dtype)) = (s.dtype(), dtypes.get(s.name())) {
+                    *s = Series::full_null(s.name(), 0, dtype);
+                }
+            }
+        }
+        if let Some(tu) = options.datetime_unit {
+            for s in df.get_columns_mut() {
+                *s = convert_time_unit(s.clone(), tu)?;
+            }
+        }
+        Ok(df)
+    } else {
+        let mut df = if dfs.len() == 1 {
+            // the common case of a single row group; there is nothing to stack
+            dfs.pop().unwrap()
+        } else {
+            #[cfg(feature = "dtype-categorical")]
+            if let Some((_, CategoricalOverflow::Utf8)) = options.max_categories {
+                unify_categorical_fallback(&mut dfs)?;
+            }
+            let df = accumulate_dataframes_vertical(dfs.into_iter())?;
+            // duplicates in different row groups
+            if options.distinct_on.is_some() {
+                df.unique_stable(options.distinct_on, UniqueKeepStrategy::First)?
+            } else {
+                df
+            }
+        };
+        apply_aggregations(&mut df, options.aggregate)?;
+        Ok(df.slice(0, options.limit))
+    }
+}
+
+/// Read a parquet file into a frame. Every row group is selected, fetched, decoded,
+/// post-processed and taken from the limit in turn, see [`select_row_group`],
+/// [`fetch_row_group`], [`decode_row_group`], [`post_process_row_group`] and
+/// [`limit_row_group`].
+pub(super) fn read_parquet<R: MmapBytesReader>(
+    mut reader: R,
     metadata: Option<FileMetaData>,
+    schema: &ArrowSchema,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
-    aggregate: Option<&[ScanAggregation]>,
-    mut parallel: bool,
-    row_count: Option<RowCount>,
+    options: ParquetReadOptions,
+    diagnostics: &mut ReadDiagnostics,
 ) -> Result<DataFrame> {
-    let file_metadata = metadata
-        .map(Ok)
-        .unwrap_or_else(|| read::read_metadata(&mut reader))?;
+    let mut fingerprint = diagnostics.fingerprint.as_deref_mut();
+    let timed_out = diagnostics.timed_out.as_deref_mut();
+    let mut chunk_counts = diagnostics.chunk_counts.as_deref_mut();
+    let limit_reached = diagnostics.limit_reached.as_deref_mut();
+    let mut column_errors = diagnostics
+        .column_errors
+        .as_mut()
+        .map(|(_, errors)| &mut **errors);
+    let mut violations = diagnostics.violations.as_deref_mut();
+    let mut partitions = diagnostics
+        .partitions
+        .as_mut()
+        .map(|(column, partitions)| (*column, &mut **partitions));
+    let partition = partitions.as_ref().map(|(column, _)| *column);
+    let mut decisions = diagnostics.decisions.as_deref_mut();
+    let file_metadata = match metadata {
+        Some(metadata) => metadata,
+        None => catch_malformed(|| Ok(read::read_metadata(&mut reader)?))?,
+    };
     let row_group_len = file_metadata.row_groups.len();
+    validate_read(
+        &file_metadata,
+        schema,
+        predicate.as_ref(),
+        &options,
+        partition,
+    )?;
 
-    let projection = projection
+    let projection = options
+        .projection
         .map(Cow::Borrowed)
         .unwrap_or_else(|| Cow::Owned((0usize..schema.fields.len()).collect::<Vec<_>>()));
+    let decoded = decoded_columns(
+        &projection,
+        schema,
+        predicate.is_some(),
+        &options,
+        partition,
+    );
+    let parallel = decode_in_parallel(&file_metadata, decoded.len(), &options);
 
-    if projection.len() == 1 {
-        parallel = false;
-    }
+    let ParquetReadOptions {
+        limit,
+        aggregate,
+        coalesce_gap_bytes,
+        row_count,
+        limit_after_predicate,
+        statistics,
+        null_dtypes,
+        max_categories,
+        pad_ragged_columns,
+        prune_only,
+        sample,
+        allow_missing_columns,
+        post_predicate,
+        row_count_i64,
+        predicate_only_columns,
+        deadline,
+        row_group_id,
+        statistics_truncated_len,
+        decompression_pool,
+        row_selection,
+        pruned_row_groups,
+        post_decode,
+        windowed_mmap,
+        distinct_on,
+        since,
+        limit_distribution,
+        constraints,
+        pipeline,
+        max_decompressed_bytes,
+        metadata_columns,
+        verify,
+        selected_row_groups,
+        reverse,
+        required_columns,
+        on_series,
+        datetime_unit,
+        limit_before_agg,
+        null_fill_dtypes,
+        ..
+    } = options;
 
     let mut dfs = Vec::with_capacity(row_group_len);
 
-    let mut remaining_rows = limit;
+    // a limit after the aggregations limits the aggregated rows, so all rows are read
+    let rows_limit = if aggregate.is_some() && !limit_before_agg {
+        usize::MAX
+    } else {
+        limit
+    };
+    // if the limit counts the rows that pass the predicate, we cannot stop
+    // decoding a row group early as we don't know how many rows will be filtered
+    // the limit always counts the selected rows
+    let limit_after_predicate = (limit_after_predicate
+        && ((predicate.is_some() && !prune_only) || post_predicate.is_some()))
+        || row_selection.is_some()
+        || matches!(required_columns, Some((_, RequiredColumnNulls::Drop)));
 
-    let reader = ReaderBytes::from(&reader);
-    let bytes = reader.deref();
+    if let Some(fingerprint) = fingerprint.as_deref_mut() {
+        write_fingerprint(
+            fingerprint,
+            &projection,
+            predicate.as_deref(),
+            post_predicate,
+            rows_limit,
+            limit_after_predicate,
+        );
+    }
 
-    let mut previous_row_count = 0;
-    for rg in 0..row_group_len {
+    // a windowed read maps the column chunks of one row group at a time and a source
+    // reads them
+    let source = reader.to_source();
+    let window_file = reader.to_file().filter(|_| windowed_mmap);
+    let reader_bytes = match (source, window_file) {
+        (None, None) => Some(ReaderBytes::try_from(&reader)?),
+        _ => None,
+    };
+    let file_bytes = reader_bytes.as_deref();
+    let (from, file_len) = match (source, window_file) {
+        (Some(source), _) => (ReadFrom::Source(source), source.file_len()? as usize),
+        (None, Some(file)) => (ReadFrom::Window(file), file.metadata()?.len() as usize),
+        (None, None) => {
+            let bytes = file_bytes.unwrap_or_default();
+            (ReadFrom::Bytes(bytes), bytes.len())
+        }
+    };
+    validate_metadata(&file_metadata, file_len, allow_missing_columns)?;
+
+    let budget = max_decompressed_bytes.map(DecompressionBudget::new);
+    let select_options = SelectOptions {
+        predicate: predicate.as_ref(),
+        statistics,
+        statistics_truncated_len,
+        sampled: sample.map(|sample| sample.select(&file_metadata)),
+        selected_row_groups,
+        row_selection,
+        since,
+        pruned_row_groups,
+    };
+    let decode_options = DecodeOptions {
+        columns: ColumnOptions {
+            null_dtypes,
+            null_fill_dtypes,
+            max_categories,
+            allow_missing_columns,
+            decompression_pool,
+        },
+        predicate_only_columns,
+        datetime_unit,
+        on_series,
+        pipeline,
+        parallel,
+        verify,
+        pad_ragged_columns,
+    };
+    let post_process_options = PostProcessOptions {
+        predicate: predicate.as_deref(),
+        prune_only,
+        post_predicate,
+        row_count: row_count.as_ref(),
+        row_count_i64,
+        row_group_id,
+        metadata_columns,
+        reverse,
+        post_decode,
+        required_columns,
+        constraints,
+        distinct_on,
+        statistics,
+        statistics_truncated_len,
+    };
+
+    // an even distribution of the limit selects all row groups up front to know their
+    // shares
+    let (mut selected_up_front, shares) =
+        if limit_distribution == LimitDistribution::Even && rows_limit < usize::MAX {
+            let (selected, shares) =
+                select_up_front(&file_metadata, schema, &select_options, rows_limit)?;
+            (Some(selected), Some(shares))
+        } else {
+            (None, None)
+        };
+    let mut limit_state = LimitState {
+        remaining_rows: rows_limit,
+        reached: false,
+        shares,
+        after_predicate: limit_after_predicate,
+    };
+
+    // not an `IdxSize`, as the row count of the file may not fit in one
+    let row_offsets = file_metadata
+        .row_groups
+        .iter()
+        .scan(0u64, |offset, md| {
+            let previous = *offset;
+            *offset += md.num_rows() as u64;
+            Some(previous)
+        })
+        .collect::<Vec<_>>();
+    let mut order: Box<dyn Iterator<Item = usize>> = if reverse {
+        Box::new((0..row_group_len).rev())
+    } else {
+        Box::new(0..row_group_len)
+    };
+    let mut decide = |rg: usize, pruned_by: Option<PruneReason>| {
+        if let Some(decisions) = decisions.as_deref_mut() {
+            decisions.push(RowGroupDecision {
+                row_group: rg,
+                num_rows: file_metadata.row_groups[rg].num_rows(),
+                pruned_by,
+            });
+        }
+    };
+    // why the row groups after the loop stopped are not read
+    let mut stopped_by = PruneReason::Limit;
+    for rg in order.by_ref() {
+        let previous_row_count = row_offsets[rg];
+        if limit_state.remaining_rows == 0 {
+            limit_state.reached = true;
+            decide(rg, Some(PruneReason::Limit));
+            break;
+        }
         let md = &file_metadata.row_groups[rg];
-        let current_row_count = md.num_rows() as IdxSize;
-        if let Some(pred) = &predicate {
-            if let Some(pred) = pred.as_stats_evaluator() {
-                if let Some(stats) = collect_statistics(&file_metadata.row_groups, schema)? {
-                    let should_read = pred.should_read(&stats);
-                    // a parquet file may not have statistics of all columns
-                    if matches!(should_read, Ok(false)) {
-                        previous_row_count += current_row_count;
-                        continue;
-                    } else if !matches!(should_read, Err(PolarsError::NotFound(_))) {
-                        let _ = should_read?;
-                    }
+        // some writers emit row groups without rows as placeholders
+        if md.num_rows() == 0 {
+            decide(rg, Some(PruneReason::Empty));
+            continue;
+        }
+        let selection = match &mut selected_up_front {
+            Some(selected) => std::mem::replace(&mut selected[rg], Err(PruneReason::Limit)),
+            None => select_row_group(
+                rg,
+                previous_row_count,
+                &file_metadata,
+                schema,
+                &select_options,
+            )?,
+        };
+        let rg_limit = limit_state.row_group_limit(rg);
+        let (eval, selected) = match selection {
+            Ok(selection) if rg_limit > 0 => selection,
+            Ok(_) => {
+                decide(rg, Some(PruneReason::Limit));
+                continue;
+            }
+            Err(reason) => {
+                decide(rg, Some(reason));
+                continue;
+            }
+        };
+        // the statistics show that all rows pass, so the predicate needs no values
+        let all_rows_pass = predicate.is_some() && eval == StatsEvaluation::DefinitelyTrue;
+
+        if matches!(deadline, Some(deadline) if Instant::now() >= deadline) {
+            match timed_out {
+                Some(timed_out) => {
+                    *timed_out = true;
+                    decide(rg, Some(PruneReason::Deadline));
+                    stopped_by = PruneReason::Deadline;
+                    break;
+                }
+                None => {
+                    return Err(PolarsError::Timeout(
+                        format!("the deadline passed before row group {} was read", rg).into(),
+                    ))
                 }
             }
         }
+        decide(rg, None);
+
+        if let Some(fingerprint) = fingerprint.as_deref_mut() {
+            fingerprint.write_usize(rg);
+        }
 
         // test we don't read the parquet file if this env var is set
         #[cfg(debug_assertions)]
@@ -107,76 +1791,488 @@ pub fn read_parquet<R: MmapBytesReader>(
             assert!(std::env::var("POLARS_PANIC_IF_PARQUET_PARSED").is_err())
         }
 
-        let chunk_size = md.num_rows() as usize;
-        let columns = if parallel {
-            POOL.install(|| {
-                projection
-                    .par_iter()
-                    .map(|column_i| {
-                        let field = &schema.fields[*column_i];
-                        let columns = mmap_columns(bytes, md.columns(), &field.name);
-                        let iter = mmap::to_deserializer(
-                            columns,
-                            field.clone(),
-                            remaining_rows,
-                            Some(chunk_size),
-                        )?;
-
-                        if remaining_rows < md.num_rows() {
-                            array_iter_to_series(iter, field, Some(remaining_rows))
-                        } else {
-                            array_iter_to_series(iter, field, None)
-                        }
-                    })
-                    .collect::<Result<Vec<_>>>()
-            })?
+        let fetched = fetch_row_group(from, md, &decoded, schema, coalesce_gap_bytes)?;
+        let bytes = fetched.file_bytes(budget.as_ref());
+
+        // in reverse the limit takes the last rows of the row group
+        let rows_to_decode = if limit_after_predicate || reverse {
+            usize::MAX
         } else {
+            rg_limit
+        };
+        let df = decode_row_group(
+            rg,
+            md,
+            bytes,
+            &decoded,
+            schema,
+            rows_to_decode,
+            all_rows_pass,
+            &decode_options,
+            column_errors.as_deref_mut(),
+            chunk_counts.as_deref_mut(),
+        )?;
+        let df = post_process_row_group(
+            df,
+            rg,
+            previous_row_count,
+            selected.as_ref(),
+            all_rows_pass,
+            &file_metadata,
+            schema,
+            &post_process_options,
+            violations.as_deref_mut(),
+        )?;
+        let df = limit_row_group(df, md, rg_limit, &mut limit_state, aggregate, distinct_on)?;
+        match partitions.as_mut() {
+            // the limit of the frames that are stacked is applied at the end
+            Some((column, partitions)) => {
+                partition_row_group(df.slice(0, rg_limit), column, partitions)?
+            }
+            None => dfs.push(df),
+        }
+    }
+
+    for rg in order {
+        decide(rg, Some(stopped_by));
+    }
+    if let Some(decisions) = decisions {
+        decisions.sort_by_key(|decision| decision.row_group);
+    }
+
+    if let Some(limit_reached) = limit_reached {
+        *limit_reached = limit_state.reached;
+    }
+
+    let finish_options = FinishOptions {
+        limit,
+        aggregate,
+        distinct_on,
+        max_categories,
+        null_dtypes,
+        null_fill_dtypes,
+        datetime_unit,
+    };
+    finish_read(dfs, schema, &projection, &finish_options)
+}
+
+/// Check that the values of a column of `dtype` can key the partitions of
+/// `finish_partitioned_by`. Floats have no total equality and the values of the other
+/// rejected dtypes can't be hashed or borrow from the column, e.g. the time zone of a
+/// `Datetime`.
+fn check_partition_dtype(column: &str, dtype: &DataType) -> Result<()> {
+    match dtype {
+        DataType::Float32 | DataType::Float64 => Err(PolarsError::InvalidOperation(
+            format!("can not partition by the float column '{}'", column).into(),
+        )),
+        DataType::Boolean
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::Utf8
+        | DataType::Null => Ok(()),
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => Ok(()),
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(_, None) => Ok(()),
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => Ok(()),
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_) => Ok(()),
+        dtype => Err(PolarsError::InvalidOperation(
+            format!(
+                "can not partition by the column '{}' of dtype {:?}",
+                column, dtype
+            )
+            .into(),
+        )),
+    }
+}
+
+/// The key of a partition of `finish_partitioned_by`, the value of its first row. A
+/// categorical is keyed by its string, as the indexes of the categories differ between
+/// row groups.
+fn partition_key(s: &Series) -> Result<AnyValue<'static>> {
+    let s = s.slice(0, 1);
+    match s.dtype() {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_) => s.cast(&DataType::Utf8)?.get(0).into_static(),
+        _ => s.get(0).into_static(),
+    }
+}
+
+/// The number of nanoseconds in a time unit.
+fn nanoseconds(tu: TimeUnit) -> i64 {
+    match tu {
+        TimeUnit::Nanoseconds => 1,
+        TimeUnit::Microseconds => 1_000,
+        TimeUnit::Milliseconds => 1_000_000,
+    }
+}
+
+/// Convert a `Datetime` or `Duration` column to the time unit `tu`. Converting to a finer
+/// unit fails on the first value that doesn't fit an `i64` in that unit; converting to a
+/// coarser unit truncates the values.
+fn convert_time_unit(s: Series, tu: TimeUnit) -> Result<Series> {
+    let (from, dtype) = match s.dtype() {
+        DataType::Datetime(from, tz) => (*from, DataType::Datetime(tu, tz.clone())),
+        DataType::Duration(from) => (*from, DataType::Duration(tu)),
+        _ => return Ok(s),
+    };
+    if nanoseconds(from) <= nanoseconds(tu) {
+        return s.cast(&dtype);
+    }
+    let factor = nanoseconds(from) / nanoseconds(tu);
+    let physical = s.to_physical_repr();
+    let ca = physical.i64()?;
+    if let Some(v) = ca
+        .into_iter()
+        .flatten()
+        .find(|v| v.checked_mul(factor).is_none())
+    {
+        return Err(PolarsError::ComputeError(
+            format!(
+                "the value {} of column '{}' overflows when it is converted from {} to {}",
+                v,
+                s.name(),
+                from,
+                tu
+            )
+            .into(),
+        ));
+    }
+    (ca * factor).into_series().cast(&dtype)
+}
+
+/// Read the column chunks of the `columns` of a row group from the source, as
+/// `(offset, bytes)` ranges. Column chunks that are adjacent or at most `gap` bytes
+/// apart are merged into one range, as reading the bytes in between costs less than
+/// another request. The ranges are taken from the footer, so they are checked against
+/// the file size before anything is read.
+fn read_row_group_ranges(
+    source: &dyn ParquetSource,
+    md: &RowGroupMetaData,
+    columns: &[usize],
+    schema: &ArrowSchema,
+    gap: u64,
+) -> Result<Vec<(u64, Vec<u8>)>> {
+    let mut chunks = columns
+        .iter()
+        .flat_map(|i| read::get_field_columns(md.columns(), &schema.fields[*i].name))
+        .map(|column| column.byte_range())
+        .collect::<Vec<_>>();
+    chunks.sort_unstable();
+    let file_len = source.file_len()?;
+    if let Some((start, len)) = chunks
+        .iter()
+        .find(|(start, len)| start.checked_add(*len).map_or(true, |end| end > file_len))
+    {
+        return Err(PolarsError::ComputeError(
+            format!(
+                "column chunk at byte range {}..{} exceeds the file size of {} bytes",
+                start,
+                start.saturating_add(*len),
+                file_len
+            )
+            .into(),
+        ));
+    }
+
+    let mut ranges: Vec<(u64, u64)> = Vec::with_capacity(chunks.len());
+    for (start, len) in chunks {
+        match ranges.last_mut() {
+            Some((offset, merged)) if start <= (*offset + *merged).saturating_add(gap) => {
+                *merged = std::cmp::max(*merged, start + len - *offset);
+            }
+            _ => ranges.push((start, len)),
+        }
+    }
+    ranges
+        .into_iter()
+        .map(|(offset, len)| Ok((offset, source.read_range(offset, len)?)))
+        .collect()
+}
+
+/// Split `limit` over the row groups in proportion to their number of `rows`. The shares
+/// sum to the limit, or are all rows if there are fewer.
+pub(super) fn distribute_limit(limit: usize, rows: &[usize]) -> Vec<usize> {
+    let total = rows.iter().sum::<usize>();
+    if limit >= total {
+        return rows.to_vec();
+    }
+    let mut shares = rows
+        .iter()
+        .map(|n| (limit as u128 * *n as u128 / total as u128) as usize)
+        .collect::<Vec<_>>();
+    // the rows that are lost by rounding down are taken from the last row groups
+    let mut left = limit - shares.iter().sum::<usize>();
+    for (share, n) in shares.iter_mut().zip(rows).rev() {
+        let extra = std::cmp::min(left, n - *share);
+        *share += extra;
+        left -= extra;
+    }
+    shares
+}
+
+/// Memory map the byte range of the column chunks of the `columns` of a row group, or
+/// return `None` if they have no column chunks.
+fn map_row_group(
+    file: &std::fs::File,
+    md: &RowGroupMetaData,
+    columns: &[usize],
+    schema: &ArrowSchema,
+) -> Result<Option<(u64, memmap::Mmap)>> {
+    let (start, end) = columns
+        .iter()
+        .flat_map(|i| read::get_field_columns(md.columns(), &schema.fields[*i].name))
+        .map(|column| {
+            let (start, len) = column.byte_range();
+            (start, start + len)
+        })
+        .fold((u64::MAX, 0), |(lo, hi), (start, end)| {
+            (lo.min(start), hi.max(end))
+        });
+    if start >= end {
+        return Ok(None);
+    }
+    let mmap = unsafe {
+        memmap::MmapOptions::new()
+            .offset(start)
+            .len((end - start) as usize)
+            .map(file)?
+    };
+    Ok(Some((start, mmap)))
+}
+
+/// The encodings of the pages of the `projection` columns, per row group and column.
+///
+/// The encodings of a nested column are those of all its leaf columns. This only
+/// reads the metadata, so it can be used to decide how to read a file before
+/// decoding anything.
+pub fn column_encodings(
+    metadata: &FileMetaData,
+    projection: Option<&[usize]>,
+) -> Result<Vec<Vec<Vec<Encoding>>>> {
+    let fields = metadata.schema().fields();
+    let projection = projection
+        .map(Cow::Borrowed)
+        .unwrap_or_else(|| Cow::Owned((0usize..fields.len()).collect::<Vec<_>>()));
+
+    metadata
+        .row_groups
+        .iter()
+        .map(|md| {
             projection
                 .iter()
-                .map(|column_i| {
-                    let field = &schema.fields[*column_i];
-                    let columns = mmap_columns(bytes, md.columns(), &field.name);
-                    let iter = mmap::to_deserializer(
-                        columns,
-                        field.clone(),
-                        remaining_rows,
-                        Some(chunk_size),
-                    )?;
-
-                    if remaining_rows < md.num_rows() {
-                        array_iter_to_series(iter, field, Some(remaining_rows))
-                    } else {
-                        array_iter_to_series(iter, field, None)
+                .map(|i| {
+                    let name = &fields[*i].get_field_info().name;
+                    let mut encodings = vec![];
+                    for column in read::get_field_columns(md.columns(), name) {
+                        for encoding in column.column_encoding() {
+                            let encoding =
+                                Encoding::try_from(*encoding).map_err(ArrowError::from)?;
+                            if !encodings.contains(&encoding) {
+                                encodings.push(encoding);
+                            }
+                        }
                     }
+                    Ok(encodings)
                 })
-                .collect::<Result<Vec<_>>>()?
-        };
+                .collect()
+        })
+        .collect()
+}
+
+/// The location of a column chunk in a file, see [`column_chunk_locations`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnChunkLocation {
+    /// The index of the row group in the file.
+    pub row_group: usize,
+    /// The path of the (leaf) column in the schema.
+    pub path: Vec<String>,
+    /// The byte offset of the column chunk in the file.
+    pub offset: u64,
+    /// The number of (compressed) bytes of the column chunk.
+    pub compressed_length: u64,
+}
+
+/// The location of every column chunk, per row group and in the order of the columns of
+/// the row group, so that a reader can fetch exactly the bytes of a column chunk. Nested
+/// columns have a column chunk for every leaf column.
+///
+/// This only reads the metadata.
+pub fn column_chunk_locations(metadata: &FileMetaData) -> Vec<ColumnChunkLocation> {
+    metadata
+        .row_groups
+        .iter()
+        .enumerate()
+        .flat_map(|(rg, md)| {
+            md.columns().iter().map(move |column| {
+                let (offset, compressed_length) = column.byte_range();
+                ColumnChunkLocation {
+                    row_group: rg,
+                    path: column.descriptor().path_in_schema.clone(),
+                    offset,
+                    compressed_length,
+                }
+            })
+        })
+        .collect()
+}
+
+/// Read a single column of a parquet file into a [`Series`].
+///
+/// The `predicate` is only used to prune row groups based on their statistics,
+/// the rows of the surviving row groups are not filtered.
+pub fn read_parquet_column<R: MmapBytesReader>(
+    mut reader: R,
+    column_name: &str,
+    limit: Option<usize>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+) -> Result<Series> {
+    let file_metadata = catch_malformed(|| Ok(read::read_metadata(&mut reader)?))?;
+    let schema = infer_schema(&file_metadata, true, ParquetInterval::default())?;
+    let column_i = schema.try_index_of(column_name)?;
+    let field = &schema.fields[column_i];
+
+    // a source reads the column chunks of the column only
+    let source = reader.to_source();
+    let reader_bytes = match source {
+        Some(_) => None,
+        None => Some(ReaderBytes::try_from(&reader)?),
+    };
+    let file_len = match (source, reader_bytes.as_deref()) {
+        (Some(source), _) => source.file_len()? as usize,
+        (None, bytes) => bytes.map_or(0, <[u8]>::len),
+    };
+    validate_metadata(&file_metadata, file_len, false)?;
 
-        remaining_rows =
-            remaining_rows.saturating_sub(file_metadata.row_groups[rg].num_rows() as usize);
+    let limit = limit.unwrap_or(usize::MAX);
+    let mut remaining_rows = limit;
+    let mut buf = SeriesBuffer::new(field);
+    let mut null_rows = 0;
 
-        let mut df = DataFrame::new_no_checks(columns);
-        if let Some(rc) = &row_count {
-            df.with_row_count_mut(&rc.name, Some(previous_row_count + rc.offset));
+    for (rg, md) in file_metadata.row_groups.iter().enumerate() {
+        if remaining_rows == 0 {
+            break;
+        }
+        if !read_this_row_group(predicate.as_ref(), rg, &file_metadata, &schema, None)? {
+            continue;
         }
 
-        apply_predicate(&mut df, predicate.as_deref())?;
-        apply_aggregations(&mut df, aggregate)?;
+        if field.data_type == ArrowDataType::Null {
+            null_rows += std::cmp::min(remaining_rows, md.num_rows());
+        } else {
+            let ranges;
+            let bytes = match source {
+                Some(source) => {
+                    ranges = read_row_group_ranges(
+                        source,
+                        md,
+                        &[column_i],
+                        &schema,
+                        DEFAULT_COALESCE_GAP_BYTES,
+                    )?;
+                    FileBytes::ranges(&ranges)
+                }
+                None => FileBytes::from(reader_bytes.as_deref().unwrap_or_default()),
+            };
+            let iter =
+                column_idx_to_array_iter(column_i, md, remaining_rows, &schema, bytes, None)?;
+            buf.extend(iter, Some(remaining_rows))?;
+        }
+        remaining_rows = remaining_rows.saturating_sub(md.num_rows());
+    }
 
-        previous_row_count += current_row_count;
-        dfs.push(df)
+    if field.data_type == ArrowDataType::Null {
+        return Ok(Series::full_null(&field.name, null_rows, &DataType::Null));
     }
+    Ok(buf.finish()?.slice(0, limit))
+}
 
-    if dfs.is_empty() {
-        let schema = if let Cow::Borrowed(_) = projection {
-            Cow::Owned(apply_projection(schema, &projection))
-        } else {
-            Cow::Borrowed(schema)
-        };
-        Ok(arrow_schema_to_empty_df(&schema))
-    } else {
-        let mut df = accumulate_dataframes_vertical(dfs.into_iter())?;
-        apply_aggregations(&mut df, aggregate)?;
-        Ok(df.slice(0, limit))
+/// Whether the column chunk of `column` has dictionary encoded pages. Nested columns
+/// consist of multiple column chunks, each with their own dictionary, and are not.
+pub(super) fn is_dictionary_encoded(md: &RowGroupMetaData, column: &str) -> bool {
+    match read::get_field_columns(md.columns(), column).as_slice() {
+        [chunk] => chunk.column_encoding().iter().any(|encoding| {
+            matches!(
+                Encoding::try_from(*encoding),
+                Ok(Encoding::RleDictionary | Encoding::PlainDictionary)
+            )
+        }),
+        _ => false,
+    }
+}
+
+/// Read the dictionary of the column chunk of `column` in `row_group` into a [`Series`]
+/// of its distinct values, or `None` if the column chunk is not dictionary encoded.
+///
+/// The dictionary page is read when the first data page is read, so of the data pages
+/// only the first is decoded.
+pub fn read_column_dictionary<R: MmapBytesReader>(
+    reader: R,
+    metadata: &FileMetaData,
+    row_group: usize,
+    column: &str,
+) -> Result<Option<Series>> {
+    let md = metadata.row_groups.get(row_group).ok_or_else(|| {
+        PolarsError::ComputeError(
+            format!(
+                "row group {} is out of bounds for a file with {} row groups",
+                row_group,
+                metadata.row_groups.len()
+            )
+            .into(),
+        )
+    })?;
+    let mut schema = infer_schema(metadata, true, ParquetInterval::default())?;
+    let column_i = schema.try_index_of(column)?;
+
+    if !is_dictionary_encoded(md, column) {
+        return Ok(None);
     }
+    let field = &mut schema.fields[column_i];
+    let values = match &field.data_type {
+        ArrowDataType::Dictionary(_, values, _) => values.as_ref().clone(),
+        dt => dt.clone(),
+    };
+    field.data_type = ArrowDataType::Dictionary(IntegerType::UInt32, Box::new(values), false);
+
+    // a source reads the column chunk only
+    let ranges;
+    let reader_bytes;
+    let bytes = match reader.to_source() {
+        Some(source) => {
+            validate_metadata(metadata, source.file_len()? as usize, false)?;
+            ranges = read_row_group_ranges(
+                source,
+                md,
+                &[column_i],
+                &schema,
+                DEFAULT_COALESCE_GAP_BYTES,
+            )?;
+            FileBytes::ranges(&ranges)
+        }
+        None => {
+            reader_bytes = ReaderBytes::try_from(&reader)?;
+            validate_metadata(metadata, reader_bytes.len(), false)?;
+            FileBytes::from(reader_bytes.deref())
+        }
+    };
+    let arr = match column_idx_to_array_iter(column_i, md, 1, &schema, bytes, None)?.next() {
+        Some(arr) => arr?,
+        None => return Ok(None),
+    };
+    let values = arr
+        .as_any()
+        .downcast_ref::<DictionaryArray<u32>>()
+        .expect("dictionary array")
+        .values()
+        .clone();
+    Series::try_from((column, values)).map(Some)
 }
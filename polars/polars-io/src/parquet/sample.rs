@@ -0,0 +1,71 @@
+use arrow::io::parquet::read::FileMetaData;
+
+/// The size of a [`SampleSpec`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SampleSize {
+    /// Sample this number of row groups.
+    RowGroups(usize),
+    /// Sample row groups until they have at least this number of rows.
+    Rows(usize),
+}
+
+/// Read a random sample of the row groups of a file instead of the whole file.
+///
+/// The same seed selects the same row groups of a file.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SampleSpec {
+    pub size: SampleSize,
+    pub seed: u64,
+}
+
+impl SampleSpec {
+    pub fn new(size: SampleSize, seed: u64) -> Self {
+        SampleSpec { size, seed }
+    }
+
+    /// Per row group, whether it is part of the sample.
+    pub(super) fn select(&self, metadata: &FileMetaData) -> Vec<bool> {
+        let n = metadata.row_groups.len();
+        let mut order = (0..n).collect::<Vec<_>>();
+        // Fisher-Yates shuffle
+        let mut rng = SplitMix64(self.seed);
+        for i in (1..n).rev() {
+            let j = (rng.next() % (i as u64 + 1)) as usize;
+            order.swap(i, j);
+        }
+
+        let mut selected = vec![false; n];
+        match self.size {
+            SampleSize::RowGroups(k) => {
+                for rg in order.into_iter().take(k) {
+                    selected[rg] = true;
+                }
+            }
+            SampleSize::Rows(n_rows) => {
+                let mut rows = 0;
+                for rg in order {
+                    if rows >= n_rows {
+                        break;
+                    }
+                    rows += metadata.row_groups[rg].num_rows();
+                    selected[rg] = true;
+                }
+            }
+        }
+        selected
+    }
+}
+
+/// A small deterministic random number generator, so that a seed selects the same
+/// row groups on every platform and version.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
@@ -0,0 +1,142 @@
+use crate::mmap::MmapBytesReader;
+use polars_core::prelude::*;
+use std::fs::File;
+use std::io::{Cursor, Read, Seek, SeekFrom};
+
+/// A source of the bytes of a parquet file, e.g. a file on HDFS or in an object store.
+///
+/// Wrap a source in a [`SourceReader`] to read it with a
+/// [`ParquetReader`](super::ParquetReader). The footer is read from the end of the source
+/// and of every row group only the column chunks of the columns that are decoded are read.
+/// Column chunks that are near each other are read as a single range, so a row group
+/// takes few requests regardless of its number of columns, see
+/// [`ParquetReader::with_coalesce_gap`](super::ParquetReader::with_coalesce_gap).
+pub trait ParquetSource: Send + Sync {
+    /// The length of the file in bytes.
+    fn file_len(&self) -> Result<u64>;
+
+    /// Read the bytes of `offset..offset + len`.
+    fn read_range(&self, offset: u64, len: u64) -> Result<Vec<u8>>;
+}
+
+fn out_of_bounds(offset: u64, len: u64, file_len: u64) -> PolarsError {
+    PolarsError::ComputeError(
+        format!(
+            "byte range {}..{} exceeds the file size of {} bytes",
+            offset,
+            offset.saturating_add(len),
+            file_len
+        )
+        .into(),
+    )
+}
+
+impl ParquetSource for File {
+    fn file_len(&self) -> Result<u64> {
+        Ok(self.metadata()?.len())
+    }
+
+    fn read_range(&self, offset: u64, len: u64) -> Result<Vec<u8>> {
+        // a bogus range fails before its buffer is allocated
+        let file_len = self.file_len()?;
+        if offset.checked_add(len).map_or(true, |end| end > file_len) {
+            return Err(out_of_bounds(offset, len, file_len));
+        }
+        let mut buf = vec![0; len as usize];
+        #[cfg(unix)]
+        std::os::unix::fs::FileExt::read_exact_at(self, &mut buf, offset)?;
+        #[cfg(not(unix))]
+        {
+            let mut file = self;
+            file.seek(SeekFrom::Start(offset))?;
+            file.read_exact(&mut buf)?;
+        }
+        Ok(buf)
+    }
+}
+
+impl<T> ParquetSource for Cursor<T>
+where
+    T: AsRef<[u8]> + Send + Sync,
+{
+    fn file_len(&self) -> Result<u64> {
+        Ok(self.get_ref().as_ref().len() as u64)
+    }
+
+    fn read_range(&self, offset: u64, len: u64) -> Result<Vec<u8>> {
+        let bytes = self.get_ref().as_ref();
+        let start = usize::try_from(offset).ok();
+        let end = start.and_then(|start| start.checked_add(usize::try_from(len).ok()?));
+        match start
+            .zip(end)
+            .and_then(|(start, end)| bytes.get(start..end))
+        {
+            Some(range) => Ok(range.to_vec()),
+            None => Err(out_of_bounds(offset, len, bytes.len() as u64)),
+        }
+    }
+}
+
+/// Reads a [`ParquetSource`] through ranged reads, so that it can be passed to a
+/// [`ParquetReader`](super::ParquetReader) without memory mapping or fetching the whole
+/// file.
+pub struct SourceReader<S: ParquetSource> {
+    source: S,
+    position: u64,
+    len: u64,
+}
+
+impl<S: ParquetSource> SourceReader<S> {
+    pub fn new(source: S) -> Result<Self> {
+        let len = source.file_len()?;
+        Ok(SourceReader {
+            source,
+            position: 0,
+            len,
+        })
+    }
+
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S: ParquetSource> Read for SourceReader<S> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = std::cmp::min(buf.len() as u64, self.len.saturating_sub(self.position));
+        if len == 0 {
+            return Ok(0);
+        }
+        let bytes = self
+            .source
+            .read_range(self.position, len)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
+        buf[..bytes.len()].copy_from_slice(&bytes);
+        self.position += bytes.len() as u64;
+        Ok(bytes.len())
+    }
+}
+
+impl<S: ParquetSource> Seek for SourceReader<S> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => offset as i64,
+            SeekFrom::End(offset) => self.len as i64 + offset,
+            SeekFrom::Current(offset) => self.position as i64 + offset,
+        };
+        if position < 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "seek to a negative position",
+            ));
+        }
+        self.position = position as u64;
+        Ok(self.position)
+    }
+}
+
+impl<S: ParquetSource> MmapBytesReader for SourceReader<S> {
+    fn to_source(&self) -> Option<&dyn ParquetSource> {
+        Some(&self.source)
+    }
+}
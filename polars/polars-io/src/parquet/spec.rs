@@ -0,0 +1,205 @@
+use arrow::io::parquet::read::FileMetaData;
+use polars_core::prelude::*;
+
+/// Where to read an output column of a [`ReadSpec`] from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ColumnSource {
+    /// A top level column by name.
+    Name(String),
+    /// A top level column by its parquet field id.
+    FieldId(i32),
+}
+
+/// What to do with values that can not be cast to the dtype of a [`ReadSpec`] column,
+/// e.g. because they are out of the range of an integer dtype.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OverflowBehavior {
+    /// Return an error.
+    Error,
+    /// Saturate numbers to the bounds of an integer dtype. Other values that can not
+    /// be cast become null.
+    Saturate,
+    /// Replace the values with nulls.
+    Null,
+}
+
+impl Default for OverflowBehavior {
+    fn default() -> Self {
+        OverflowBehavior::Error
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ColumnSpec {
+    source: ColumnSource,
+    name: Option<String>,
+    dtype: Option<DataType>,
+}
+
+/// Describes the columns of the `DataFrame` a read produces: per output column,
+/// the column it is read from and optionally a new name and a dtype to cast to.
+///
+/// This combines projection, renaming and casting. Columns of the file that are
+/// not the source of an output column are not read.
+#[derive(Clone, Debug, Default)]
+pub struct ReadSpec {
+    columns: Vec<ColumnSpec>,
+    overflow: OverflowBehavior,
+}
+
+impl ReadSpec {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an output column read from `source`. If `name` is `None`, the name in the
+    /// file is kept. If `dtype` is `None`, the dtype in the file is kept.
+    #[must_use]
+    pub fn with_column(
+        mut self,
+        source: ColumnSource,
+        name: Option<&str>,
+        dtype: Option<DataType>,
+    ) -> Self {
+        self.columns.push(ColumnSpec {
+            source,
+            name: name.map(|name| name.to_string()),
+            dtype,
+        });
+        self
+    }
+
+    /// Set what to do with values that can not be cast. Defaults to
+    /// [`OverflowBehavior::Error`].
+    #[must_use]
+    pub fn with_overflow(mut self, overflow: OverflowBehavior) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// The indexes of the source columns in the schema, in output order.
+    pub(super) fn to_projection(
+        &self,
+        metadata: &FileMetaData,
+        schema: &ArrowSchema,
+    ) -> Result<Vec<usize>> {
+        self.columns
+            .iter()
+            .map(|spec| {
+                let idx = match &spec.source {
+                    ColumnSource::Name(name) => {
+                        schema.fields.iter().position(|fld| &fld.name == name)
+                    }
+                    // the arrow schema may have a different order than the file
+                    ColumnSource::FieldId(id) => metadata
+                        .schema()
+                        .fields()
+                        .iter()
+                        .find(|fld| fld.get_field_info().id == Some(*id))
+                        .and_then(|fld| {
+                            let name = &fld.get_field_info().name;
+                            schema.fields.iter().position(|fld| &fld.name == name)
+                        }),
+                };
+                idx.ok_or_else(|| PolarsError::NotFound(format!("{:?}", spec.source)))
+            })
+            .collect()
+    }
+
+    /// Rename and cast the columns read with [`ReadSpec::to_projection`]. The first
+    /// `offset` columns are not part of the spec, e.g. a row count.
+    ///
+    /// Returns the number of values that could not be cast.
+    pub(super) fn apply(&self, df: DataFrame, offset: usize) -> Result<(DataFrame, usize)> {
+        let mut columns = df.get_columns().to_vec();
+        let mut n_failed = 0;
+        for (s, spec) in columns[offset..].iter_mut().zip(&self.columns) {
+            if let Some(dtype) = &spec.dtype {
+                if s.dtype() != dtype {
+                    let (out, failed) = self.cast(s, dtype)?;
+                    *s = out;
+                    n_failed += failed;
+                }
+            }
+            if let Some(name) = &spec.name {
+                s.rename(name);
+            }
+        }
+        Ok((DataFrame::new(columns)?, n_failed))
+    }
+
+    fn cast(&self, s: &Series, dtype: &DataType) -> Result<(Series, usize)> {
+        let out = s.cast(dtype)?;
+        // a failed cast results in a null
+        let failed = out.null_count() - s.null_count();
+        if failed == 0 {
+            return Ok((out, 0));
+        }
+        match self.overflow {
+            OverflowBehavior::Error => Err(PolarsError::ComputeError(
+                format!(
+                    "{} values of column '{}' can not be cast to {:?}",
+                    failed,
+                    s.name(),
+                    dtype
+                )
+                .into(),
+            )),
+            OverflowBehavior::Null => Ok((out, failed)),
+            OverflowBehavior::Saturate => Ok((saturate(s, &out)?, failed)),
+        }
+    }
+}
+
+/// Replace the nulls of a failed integer cast of `s` by the bound of the integer dtype
+/// on the side of the original value.
+fn saturate(s: &Series, out: &Series) -> Result<Series> {
+    let (signed, bits) = match out.dtype() {
+        DataType::Int8 => (true, 8),
+        DataType::Int16 => (true, 16),
+        DataType::Int32 => (true, 32),
+        DataType::Int64 => (true, 64),
+        DataType::UInt8 => (false, 8),
+        DataType::UInt16 => (false, 16),
+        DataType::UInt32 => (false, 32),
+        DataType::UInt64 => (false, 64),
+        _ => return Ok(out.clone()),
+    };
+    let original = s.cast(&DataType::Float64)?;
+    let original = original.f64()?;
+
+    // the bounds of all integer dtypes fit in a 64 bit integer of the same signedness
+    let saturated = if signed {
+        let max = i64::MAX >> (64 - bits);
+        let ca = out.cast(&DataType::Int64)?;
+        let ca: Int64Chunked = ca
+            .i64()?
+            .into_iter()
+            .zip(original.into_iter())
+            .map(|(v, original)| saturate_value(v, original, -max - 1, max))
+            .collect();
+        ca.into_series()
+    } else {
+        let max = u64::MAX >> (64 - bits);
+        let ca = out.cast(&DataType::UInt64)?;
+        let ca: UInt64Chunked = ca
+            .u64()?
+            .into_iter()
+            .zip(original.into_iter())
+            .map(|(v, original)| saturate_value(v, original, 0, max))
+            .collect();
+        ca.into_series()
+    };
+    let mut saturated = saturated.cast(out.dtype())?;
+    saturated.rename(s.name());
+    Ok(saturated)
+}
+
+fn saturate_value<T>(v: Option<T>, original: Option<f64>, min: T, max: T) -> Option<T> {
+    match (v, original) {
+        (Some(v), _) => Some(v),
+        (None, Some(original)) if original > 0.0 => Some(max),
+        (None, Some(original)) if original < 0.0 => Some(min),
+        _ => None,
+    }
+}
@@ -0,0 +1,148 @@
+use crate::mmap::{MmapBytesReader, ReaderBytes};
+use crate::parquet::interval::ParquetInterval;
+use crate::parquet::read::infer_schema;
+use crate::parquet::read_impl::column_idx_to_array_iter;
+use arrow::io::parquet::read;
+use polars_core::prelude::*;
+use std::io::Cursor;
+use std::ops::Deref;
+
+const MAGIC: &[u8] = b"PAR1";
+// the magic at the start, and the footer length and magic at the end
+const MIN_FILE_LEN: usize = 12;
+
+/// The outcome of [`validate_parquet`].
+#[derive(Clone, Debug, Default)]
+pub struct ValidationReport {
+    /// Number of row groups declared in the metadata.
+    pub num_row_groups: usize,
+    /// Number of rows declared in the metadata.
+    pub num_rows: usize,
+    /// A description of every problem that was found.
+    pub anomalies: Vec<String>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.anomalies.is_empty()
+    }
+}
+
+/// Check the structure of a parquet file without reading its data.
+///
+/// This checks the magic bytes, the metadata and whether the column chunks of every
+/// row group lie within the file. If `decode_pages` is set, the first page of every
+/// column chunk is decoded as well.
+///
+/// Problems with the file are listed in the report and are not returned as an error.
+pub fn validate_parquet<R: MmapBytesReader>(
+    reader: R,
+    decode_pages: bool,
+) -> Result<ValidationReport> {
+    let reader_bytes = ReaderBytes::try_from(&reader)?;
+    let bytes = reader_bytes.deref();
+    let mut report = ValidationReport::default();
+
+    if bytes.len() < MIN_FILE_LEN {
+        report.anomalies.push(format!(
+            "the file has {} bytes, but a parquet file has at least {} bytes",
+            bytes.len(),
+            MIN_FILE_LEN
+        ));
+        return Ok(report);
+    }
+    if &bytes[..4] != MAGIC {
+        report
+            .anomalies
+            .push("the file does not start with the parquet magic bytes".to_string());
+    }
+    if &bytes[bytes.len() - 4..] != MAGIC {
+        report
+            .anomalies
+            .push("the file does not end with the parquet magic bytes".to_string());
+        return Ok(report);
+    }
+    let footer_len =
+        u32::from_le_bytes(bytes[bytes.len() - 8..bytes.len() - 4].try_into().unwrap());
+    // column chunks are stored between the magic at the start and the footer
+    let data_end = (bytes.len() - 8).saturating_sub(footer_len as usize) as u64;
+
+    let metadata = match read::read_metadata(&mut Cursor::new(bytes)) {
+        Ok(metadata) => metadata,
+        Err(e) => {
+            report
+                .anomalies
+                .push(format!("the metadata can not be read: {}", e));
+            return Ok(report);
+        }
+    };
+    report.num_row_groups = metadata.row_groups.len();
+
+    let n_columns = metadata.schema().columns().len();
+    let mut decodable = Vec::with_capacity(metadata.row_groups.len());
+    for (rg, md) in metadata.row_groups.iter().enumerate() {
+        let n_anomalies = report.anomalies.len();
+        let mut push = |msg: String| report.anomalies.push(format!("row group {}: {}", rg, msg));
+
+        if md.columns().len() != n_columns {
+            push(format!(
+                "expected {} column chunks, got {}",
+                n_columns,
+                md.columns().len()
+            ));
+        }
+        for column in md.columns() {
+            let (start, len) = column.byte_range();
+            if start < MAGIC.len() as u64
+                || start.checked_add(len).map_or(true, |end| end > data_end)
+            {
+                push(format!(
+                    "column chunk at byte range {}..{} is not within the data of the file, which spans {}..{}",
+                    start,
+                    start.saturating_add(len),
+                    MAGIC.len(),
+                    data_end
+                ));
+            }
+            if column.num_values() < 0 || (column.num_values() as u64) < md.num_rows() as u64 {
+                push(format!(
+                    "{} rows, but a column chunk has {} values",
+                    md.num_rows(),
+                    column.num_values()
+                ));
+            }
+        }
+        report.num_rows = report.num_rows.saturating_add(md.num_rows());
+        decodable.push(report.anomalies.len() == n_anomalies);
+    }
+
+    if decode_pages {
+        let schema = match infer_schema(&metadata, true, ParquetInterval::default()) {
+            Ok(schema) => schema,
+            Err(e) => {
+                report
+                    .anomalies
+                    .push(format!("the schema can not be inferred: {}", e));
+                return Ok(report);
+            }
+        };
+        for (rg, md) in metadata.row_groups.iter().enumerate() {
+            // the byte ranges of this row group are not valid
+            if !decodable[rg] {
+                continue;
+            }
+            for (column_i, field) in schema.fields.iter().enumerate() {
+                // only decode a single row, which only requires the first page
+                let first = column_idx_to_array_iter(column_i, md, 1, &schema, bytes.into(), None)
+                    .and_then(|mut iter| iter.next().transpose().map_err(PolarsError::from));
+                if let Err(e) = first {
+                    report.anomalies.push(format!(
+                        "row group {}: column '{}' can not be decoded: {}",
+                        rg, field.name, e
+                    ));
+                }
+            }
+        }
+    }
+    Ok(report)
+}
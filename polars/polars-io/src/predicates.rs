@@ -5,6 +5,12 @@ pub trait PhysicalIoExpr: Send + Sync {
     /// as a predicate mask
     fn evaluate(&self, df: &DataFrame) -> Result<Series>;
 
+    /// A textual representation of the predicate. Equal predicates should have
+    /// equal representations.
+    fn display(&self) -> Option<String> {
+        None
+    }
+
     /// Can take &dyn Statistics and determine of a file should be
     /// read -> `true`
     /// or not -> `false`
@@ -14,9 +20,74 @@ pub trait PhysicalIoExpr: Send + Sync {
     }
 }
 
+/// The outcome of evaluating a predicate against statistics.
+///
+/// Statistics may be incomplete, so besides a definite answer an evaluator
+/// may conclude that the statistics are not sufficient to decide.
+#[cfg(feature = "parquet")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StatsEvaluation {
+    /// No row can evaluate to `true`. Rows may be `false` or `null`.
+    DefinitelyFalse,
+    /// All rows evaluate to `true`.
+    DefinitelyTrue,
+    /// The statistics are not sufficient to decide.
+    Unknown,
+}
+
+#[cfg(feature = "parquet")]
+impl StatsEvaluation {
+    /// Only a `DefinitelyFalse` result allows skipping the data.
+    pub fn should_read(self) -> bool {
+        !matches!(self, StatsEvaluation::DefinitelyFalse)
+    }
+
+    #[must_use]
+    pub fn and(self, other: StatsEvaluation) -> StatsEvaluation {
+        use StatsEvaluation::*;
+        match (self, other) {
+            (DefinitelyFalse, _) | (_, DefinitelyFalse) => DefinitelyFalse,
+            (DefinitelyTrue, DefinitelyTrue) => DefinitelyTrue,
+            _ => Unknown,
+        }
+    }
+
+    #[must_use]
+    pub fn or(self, other: StatsEvaluation) -> StatsEvaluation {
+        use StatsEvaluation::*;
+        match (self, other) {
+            (DefinitelyTrue, _) | (_, DefinitelyTrue) => DefinitelyTrue,
+            (DefinitelyFalse, DefinitelyFalse) => DefinitelyFalse,
+            _ => Unknown,
+        }
+    }
+
+    /// Negate the result.
+    ///
+    /// `DefinitelyFalse` may still contain `null`s, which remain `null` after negation,
+    /// so its negation is `Unknown` and not `DefinitelyTrue`.
+    #[must_use]
+    pub fn not(self) -> StatsEvaluation {
+        use StatsEvaluation::*;
+        match self {
+            DefinitelyTrue => DefinitelyFalse,
+            DefinitelyFalse | Unknown => Unknown,
+        }
+    }
+}
+
 #[cfg(feature = "parquet")]
 pub trait StatsEvaluator {
-    fn should_read(&self, stats: &crate::parquet::predicates::BatchStats) -> Result<bool>;
+    /// Evaluate the predicate against the statistics with three-valued logic.
+    fn evaluate_stats(
+        &self,
+        stats: &crate::parquet::predicates::BatchStats,
+    ) -> Result<StatsEvaluation>;
+
+    /// Determine whether the data described by `stats` must be read.
+    fn should_read(&self, stats: &crate::parquet::predicates::BatchStats) -> Result<bool> {
+        self.evaluate_stats(stats).map(|eval| eval.should_read())
+    }
 }
 
 #[cfg(feature = "parquet")]
@@ -222,4 +222,128 @@ impl PhysicalExpr for ApplyExpr {
     fn to_field(&self, input_schema: &Schema) -> Result<Field> {
         self.inputs[0].to_field(input_schema)
     }
+
+    #[cfg(feature = "parquet")]
+    fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
+        #[cfg(feature = "strings")]
+        if self.starts_with_column().is_some() {
+            return Some(self);
+        }
+        self.nan_column()
+            .map(|_| self as &dyn polars_io::predicates::StatsEvaluator)
+    }
+}
+
+#[cfg(feature = "parquet")]
+mod stats {
+    use super::*;
+    #[cfg(feature = "strings")]
+    use crate::dsl::function_expr::FunctionExpr;
+    #[cfg(feature = "strings")]
+    use polars_io::parquet::predicates::prefix_upper_bound;
+    use polars_io::parquet::predicates::BatchStats;
+    use polars_io::predicates::{StatsEvaluation, StatsEvaluator};
+
+    impl ApplyExpr {
+        /// The column of a `col(name).is_nan()` (`true`) or `col(name).is_not_nan()`
+        /// (`false`) predicate.
+        pub(super) fn nan_column(&self) -> Option<(&str, bool)> {
+            match &self.expr {
+                Expr::AnonymousFunction { input, options, .. } => {
+                    let is_nan = match options.fmt_str {
+                        "is_nan" => true,
+                        "is_not_nan" => false,
+                        _ => return None,
+                    };
+                    match input.as_slice() {
+                        [Expr::Column(name)] => Some((name.as_ref(), is_nan)),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+
+        /// The column and the prefix of a `col(name).str().starts_with(prefix)` predicate.
+        #[cfg(feature = "strings")]
+        pub(super) fn starts_with_column(&self) -> Option<(&str, &str)> {
+            match &self.expr {
+                Expr::Function {
+                    input,
+                    function: FunctionExpr::StringStartsWith(prefix),
+                    ..
+                } => match input.as_slice() {
+                    [Expr::Column(name)] => Some((name.as_ref(), prefix.as_str())),
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+    }
+
+    impl StatsEvaluator for ApplyExpr {
+        fn evaluate_stats(&self, stats: &BatchStats) -> Result<StatsEvaluation> {
+            #[cfg(feature = "strings")]
+            if let Some((name, prefix)) = self.starts_with_column() {
+                return evaluate_starts_with(name, prefix, stats);
+            }
+            match self.nan_column() {
+                Some((name, is_nan)) => evaluate_nan(name, is_nan, stats),
+                None => Ok(StatsEvaluation::Unknown),
+            }
+        }
+    }
+
+    /// The statistics don't record `NaN`s, so only columns that can't hold them, or of
+    /// which all values are null, allow a conclusion.
+    fn evaluate_nan(name: &str, is_nan: bool, stats: &BatchStats) -> Result<StatsEvaluation> {
+        let st = match stats.get_stats(name).ok() {
+            Some(st) => st,
+            None => return Ok(StatsEvaluation::Unknown),
+        };
+        let eval = if st.null_count() == Some(stats.num_rows()) {
+            // null is neither `NaN` nor not `NaN`
+            StatsEvaluation::DefinitelyFalse
+        } else if st.may_contain_nan() {
+            StatsEvaluation::Unknown
+        } else if is_nan {
+            StatsEvaluation::DefinitelyFalse
+        } else {
+            // `is_not_nan` of a column that is not a float column is `true`, also for nulls
+            StatsEvaluation::DefinitelyTrue
+        };
+        Ok(eval)
+    }
+
+    /// The strings that start with a prefix are those in `[prefix, upper_bound)`.
+    #[cfg(feature = "strings")]
+    fn evaluate_starts_with(
+        name: &str,
+        prefix: &str,
+        stats: &BatchStats,
+    ) -> Result<StatsEvaluation> {
+        let st = match stats.get_stats(name).ok() {
+            Some(st) => st,
+            None => return Ok(StatsEvaluation::Unknown),
+        };
+        let min_max = st.to_min_max();
+        let (min, max) = match min_max.as_ref().and_then(|s| s.utf8().ok()) {
+            Some(ca) => match (ca.get(0), ca.get(1)) {
+                (Some(min), Some(max)) => (min, max),
+                _ => return Ok(StatsEvaluation::Unknown),
+            },
+            None => return Ok(StatsEvaluation::Unknown),
+        };
+
+        let upper = prefix_upper_bound(prefix);
+        let eval = if max < prefix || upper.map_or(false, |upper| min >= upper.as_str()) {
+            StatsEvaluation::DefinitelyFalse
+        } else if min.starts_with(prefix) && max.starts_with(prefix) && st.null_count() == Some(0) {
+            // all strings between `min` and `max` share their prefix
+            StatsEvaluation::DefinitelyTrue
+        } else {
+            StatsEvaluation::Unknown
+        };
+        Ok(eval)
+    }
 }
@@ -387,10 +387,21 @@ impl PhysicalExpr for BinaryExpr {
 mod stats {
     use super::*;
     use polars_io::parquet::predicates::BatchStats;
-    use polars_io::predicates::StatsEvaluator;
-
-    fn apply_operator_stats_rhs_lit(min_max: &Series, literal: &Series, op: Operator) -> bool {
-        match op {
+    use polars_io::predicates::{StatsEvaluation, StatsEvaluator};
+
+    /// Apply the comparison between the `[min, max]` statistics and a literal.
+    ///
+    /// If no value in `[min, max]` can satisfy the comparison, no row can.
+    /// If both bounds satisfy the comparison, all non-null rows do, unless they may be
+    /// `NaN`, which satisfies no comparison.
+    fn apply_operator_stats(
+        left: &Series,
+        right: &Series,
+        op: Operator,
+        null_count: Option<usize>,
+        may_contain_nan: bool,
+    ) -> StatsEvaluation {
+        let cmp = match op {
             // col > lit
             // e.g.
             // [min,
@@ -399,88 +410,25 @@ mod stats {
             // [-1,
             // 2] > 0
             //
-            // [false, true] -> true -> read
-            Operator::Gt => {
-                // literal is bigger than max value
-                // selection needs all rows
-                ChunkCompare::<&Series>::gt(min_max, literal)
-                    .ok()
-                    .map(|s| s.any())
-                    == Some(true)
-            }
-            // col >= lit
-            Operator::GtEq => {
-                // literal is bigger than max value
-                // selection needs all rows
-                ChunkCompare::<&Series>::gt_eq(min_max, literal)
-                    .ok()
-                    .map(|ca| ca.any())
-                    == Some(true)
-            }
-            // col < lit
-            Operator::Lt => {
-                // literal is smaller than min value
-                // selection needs all rows
-                ChunkCompare::<&Series>::lt(min_max, literal)
-                    .ok()
-                    .map(|ca| ca.any())
-                    == Some(true)
-            }
-            // col <= lit
-            Operator::LtEq => {
-                // literal is smaller than min value
-                // selection needs all rows
-                ChunkCompare::<&Series>::lt_eq(min_max, literal)
-                    .ok()
-                    .map(|ca| ca.any())
-                    == Some(true)
-            }
+            // [false, true] -> any -> read
+            Operator::Gt => ChunkCompare::<&Series>::gt(left, right),
+            Operator::GtEq => ChunkCompare::<&Series>::gt_eq(left, right),
+            Operator::Lt => ChunkCompare::<&Series>::lt(left, right),
+            Operator::LtEq => ChunkCompare::<&Series>::lt_eq(left, right),
             // default: read the file
-            _ => true,
-        }
-    }
-
-    fn apply_operator_stats_lhs_lit(literal: &Series, min_max: &Series, op: Operator) -> bool {
-        match op {
-            Operator::Gt => {
-                // literal is bigger than max value
-                // selection needs all rows
-                ChunkCompare::<&Series>::gt(literal, min_max)
-                    .ok()
-                    .map(|ca| ca.any())
-                    == Some(true)
+            _ => return StatsEvaluation::Unknown,
+        };
+        match cmp {
+            Ok(ca) if !ca.any() => StatsEvaluation::DefinitelyFalse,
+            Ok(ca) if ca.all() && null_count == Some(0) && !may_contain_nan => {
+                StatsEvaluation::DefinitelyTrue
             }
-            Operator::GtEq => {
-                // literal is bigger than max value
-                // selection needs all rows
-                ChunkCompare::<&Series>::gt_eq(literal, min_max)
-                    .ok()
-                    .map(|ca| ca.any())
-                    == Some(true)
-            }
-            Operator::Lt => {
-                // literal is smaller than min value
-                // selection needs all rows
-                ChunkCompare::<&Series>::lt(literal, min_max)
-                    .ok()
-                    .map(|ca| ca.any())
-                    == Some(true)
-            }
-            Operator::LtEq => {
-                // literal is smaller than min value
-                // selection needs all rows
-                ChunkCompare::<&Series>::lt_eq(literal, min_max)
-                    .ok()
-                    .map(|ca| ca.any())
-                    == Some(true)
-            }
-            // default: read the file
-            _ => true,
+            _ => StatsEvaluation::Unknown,
         }
     }
 
     impl BinaryExpr {
-        fn impl_should_read(&self, stats: &BatchStats) -> Result<bool> {
+        fn impl_evaluate_stats(&self, stats: &BatchStats) -> Result<StatsEvaluation> {
             let schema = stats.schema();
             let fld_l = self.left.to_field(schema)?;
             let fld_r = self.right.to_field(schema)?;
@@ -504,57 +452,84 @@ mod stats {
                 (_, "literal") => {
                     let l = stats.get_stats(fld_l.name())?;
                     match l.to_min_max() {
-                        None => Ok(true),
+                        None => Ok(StatsEvaluation::Unknown),
                         Some(min_max_s) => {
                             // will be incorrect if not
                             debug_assert_eq!(min_max_s.null_count(), 0);
                             let lit_s = self.right.evaluate(&dummy, &state).unwrap();
-                            Ok(apply_operator_stats_rhs_lit(&min_max_s, &lit_s, self.op))
+                            Ok(apply_operator_stats(
+                                &min_max_s,
+                                &lit_s,
+                                self.op,
+                                l.null_count(),
+                                l.may_contain_nan(),
+                            ))
                         }
                     }
                 }
                 ("literal", _) => {
                     let r = stats.get_stats(fld_r.name())?;
                     match r.to_min_max() {
-                        None => Ok(true),
+                        None => Ok(StatsEvaluation::Unknown),
                         Some(min_max_s) => {
                             // will be incorrect if not
                             debug_assert_eq!(min_max_s.null_count(), 0);
                             let lit_s = self.left.evaluate(&dummy, &state).unwrap();
-                            Ok(apply_operator_stats_lhs_lit(&lit_s, &min_max_s, self.op))
+                            Ok(apply_operator_stats(
+                                &lit_s,
+                                &min_max_s,
+                                self.op,
+                                r.null_count(),
+                                r.may_contain_nan(),
+                            ))
                         }
                     }
                 }
                 // default: read the file
-                _ => Ok(true),
+                _ => Ok(StatsEvaluation::Unknown),
             };
-            out.map(|read| {
-                if state.verbose && read {
+            out.map(|eval| {
+                if state.verbose && eval.should_read() {
                     eprintln!("parquet file must be read, statistics not sufficient to for predicate.")
-                } else if state.verbose && !read {
+                } else if state.verbose && !eval.should_read() {
                     eprintln!("parquet file can be skipped, the statistics were sufficient to apply the predicate.")
                 };
-                read
+                eval
             })
         }
     }
 
+    /// Columns without statistics don't allow any conclusion.
+    fn evaluate_or_unknown(
+        evaluator: &dyn StatsEvaluator,
+        stats: &BatchStats,
+    ) -> Result<StatsEvaluation> {
+        match evaluator.evaluate_stats(stats) {
+            Err(PolarsError::NotFound(_)) => Ok(StatsEvaluation::Unknown),
+            out => out,
+        }
+    }
+
     impl StatsEvaluator for BinaryExpr {
-        fn should_read(&self, stats: &BatchStats) -> Result<bool> {
+        fn evaluate_stats(&self, stats: &BatchStats) -> Result<StatsEvaluation> {
             if std::env::var("POLARS_NO_PARQUET_STATISTICS").is_ok() {
-                return Ok(true);
+                return Ok(StatsEvaluation::Unknown);
             }
 
             match (
                 self.left.as_stats_evaluator(),
                 self.right.as_stats_evaluator(),
             ) {
-                (Some(l), Some(r)) => match self.op {
-                    Operator::And => Ok(l.should_read(stats)? && r.should_read(stats)?),
-                    Operator::Or => Ok(l.should_read(stats)? || r.should_read(stats)?),
-                    _ => Ok(true),
-                },
-                _ => self.impl_should_read(stats),
+                (Some(l), Some(r)) => {
+                    let l = evaluate_or_unknown(l, stats)?;
+                    let r = evaluate_or_unknown(r, stats)?;
+                    match self.op {
+                        Operator::And => Ok(l.and(r)),
+                        Operator::Or => Ok(l.or(r)),
+                        _ => Ok(StatsEvaluation::Unknown),
+                    }
+                }
+                _ => self.impl_evaluate_stats(stats),
             }
         }
     }
@@ -4,7 +4,7 @@ use crate::utils::expr_to_root_column_name;
 use polars_core::frame::groupby::GroupsProxy;
 use polars_core::prelude::*;
 #[cfg(feature = "parquet")]
-use polars_io::predicates::StatsEvaluator;
+use polars_io::predicates::{StatsEvaluation, StatsEvaluator};
 #[cfg(feature = "parquet")]
 use polars_io::prelude::predicates::BatchStats;
 use std::sync::Arc;
@@ -61,18 +61,15 @@ impl PhysicalExpr for IsNullExpr {
 
 #[cfg(feature = "parquet")]
 impl StatsEvaluator for IsNullExpr {
-    fn should_read(&self, stats: &BatchStats) -> Result<bool> {
+    fn evaluate_stats(&self, stats: &BatchStats) -> Result<StatsEvaluation> {
         let root = expr_to_root_column_name(&self.expr)?;
 
-        let read = true;
-        let skip = false;
-
         match stats.get_stats(&root).ok() {
             Some(st) => match st.null_count() {
-                Some(0) => Ok(skip),
-                _ => Ok(read),
+                Some(0) => Ok(StatsEvaluation::DefinitelyFalse),
+                _ => Ok(StatsEvaluation::Unknown),
             },
-            None => Ok(read),
+            None => Ok(StatsEvaluation::Unknown),
         }
     }
 }
@@ -2,6 +2,10 @@ use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
 use polars_core::frame::groupby::GroupsProxy;
 use polars_core::prelude::*;
+#[cfg(feature = "parquet")]
+use polars_io::parquet::predicates::BatchStats;
+#[cfg(feature = "parquet")]
+use polars_io::predicates::{StatsEvaluation, StatsEvaluator};
 use std::sync::Arc;
 
 pub struct NotExpr(Arc<dyn PhysicalExpr>, Expr);
@@ -51,6 +55,25 @@ impl PhysicalExpr for NotExpr {
     fn as_partitioned_aggregator(&self) -> Option<&dyn PartitionedAggregation> {
         Some(self)
     }
+
+    #[cfg(feature = "parquet")]
+    fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
+        Some(self)
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl StatsEvaluator for NotExpr {
+    fn evaluate_stats(&self, stats: &BatchStats) -> Result<StatsEvaluation> {
+        match self.0.as_stats_evaluator() {
+            Some(inner) => match inner.evaluate_stats(stats) {
+                Ok(eval) => Ok(eval.not()),
+                Err(PolarsError::NotFound(_)) => Ok(StatsEvaluation::Unknown),
+                Err(e) => Err(e),
+            },
+            None => Ok(StatsEvaluation::Unknown),
+        }
+    }
 }
 
 impl PartitionedAggregation for NotExpr {
@@ -71,6 +71,19 @@ fn test_parquet_statistics_no_skip() {
         .collect()
         .unwrap();
     assert_eq!(out.shape(), (27, 4));
+
+    // Negations of predicates that can prune may not prune
+    let out = scan_foods_parquet(par)
+        .filter(col("calories").is_null().not())
+        .collect()
+        .unwrap();
+    assert_eq!(out.shape(), (27, 4));
+
+    let out = scan_foods_parquet(par)
+        .filter(col("calories").lt(lit(0i32)).not())
+        .collect()
+        .unwrap();
+    assert_eq!(out.shape(), (27, 4));
 }
 
 #[test]
@@ -141,6 +154,106 @@ fn test_parquet_statistics() -> Result<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "strings")]
+fn test_parquet_statistics_prefix() -> Result<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let path = std::env::temp_dir().join("polars_parquet_statistics_prefix.parquet");
+    let mut df = df![
+        "fruit" => ["apple", "apricot", "banana", "blueberry", "cherry", "citrus"]
+    ]?;
+    ParquetWriter::new(std::fs::File::create(&path).unwrap())
+        .with_statistics(true)
+        .with_row_group_size(Some(2))
+        .finish(&mut df)?;
+    let scan = |prefix: &str| {
+        LazyFrame::scan_parquet(path.to_string_lossy().to_string(), Default::default())?
+            .filter(col("fruit").str().starts_with(prefix))
+            .collect()
+    };
+
+    // the prefix straddles the range of a row group
+    let out = scan("ba")?;
+    assert_eq!(Vec::from(out.column("fruit")?.utf8()?), &[Some("banana")]);
+    let out = scan("c")?;
+    assert_eq!(out.shape(), (2, 1));
+
+    // no row group overlaps the prefix
+    std::env::set_var("POLARS_PANIC_IF_PARQUET_PARSED", "1");
+    let out = ["aa", "az", "bz", "d"].iter().map(|prefix| scan(prefix));
+    let heights = out
+        .map(|df| df.map(|df| df.height()))
+        .collect::<Result<Vec<_>>>();
+    std::env::remove_var("POLARS_PANIC_IF_PARQUET_PARSED");
+    assert_eq!(heights?, &[0, 0, 0, 0]);
+
+    std::fs::remove_file(&path).unwrap();
+    Ok(())
+}
+
+#[test]
+fn test_parquet_statistics_nan() -> Result<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let path = std::env::temp_dir().join("polars_parquet_statistics_nan.parquet");
+    // the `NaN`s are at the start and the end of the row groups
+    let mut df = df![
+        "a" => [f64::NAN, 1.0, 2.0, 3.0, 4.0, f64::NAN, f64::NAN, f64::NAN],
+        "b" => [1, 2, 3, 4, 5, 6, 7, 8]
+    ]?;
+    ParquetWriter::new(std::fs::File::create(&path).unwrap())
+        .with_statistics(true)
+        .with_row_group_size(Some(2))
+        .finish(&mut df)?;
+    let scan = |predicate: Expr| {
+        LazyFrame::scan_parquet(path.to_string_lossy().to_string(), Default::default())?
+            .filter(predicate)
+            .collect()
+    };
+
+    // `NaN` compares `false`, so its negation is `true`
+    let out = scan(col("a").lt(lit(5.0)).not())?;
+    assert_eq!(
+        out.column("b")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[1, 6, 7, 8]
+    );
+    let out = scan(col("a").gt(lit(0.5)))?;
+    assert_eq!(
+        out.column("b")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[2, 3, 4, 5]
+    );
+    let out = scan(col("a").is_nan())?;
+    assert_eq!(
+        out.column("b")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[1, 6, 7, 8]
+    );
+    let out = scan(col("a").is_not_nan())?;
+    assert_eq!(
+        out.column("b")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[2, 3, 4, 5]
+    );
+
+    // an integer column has no `NaN`s
+    std::env::set_var("POLARS_PANIC_IF_PARQUET_PARSED", "1");
+    let out = scan(col("b").is_nan());
+    std::env::remove_var("POLARS_PANIC_IF_PARQUET_PARSED");
+    assert_eq!(out?.height(), 0);
+
+    std::fs::remove_file(&path).unwrap();
+    Ok(())
+}
+
 #[test]
 #[cfg(not(target_os = "windows"))]
 fn test_parquet_globbing() -> Result<()> {
@@ -411,3 +524,51 @@ fn scan_anonymous_fn() -> Result<()> {
     assert_eq!(df.shape(), (5, 4));
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_parquet_string_cache() -> Result<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let write = |countries: &[&str]| -> Result<Vec<u8>> {
+        let mut df = df!("country" => countries)?;
+        df.try_apply("country", |s| s.cast(&DataType::Categorical(None)))?;
+        let mut buf = vec![];
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+        Ok(buf)
+    };
+    let file_a = write(&["NL", "BE", "NL"])?;
+    let file_b = write(&["DE", "NL"])?;
+
+    let string_cache = StringCacheHolder::new();
+    let read = |buf: Vec<u8>| -> Result<Series> {
+        let df = ParquetReader::new(Cursor::new(buf))
+            .with_string_cache(Some(string_cache.clone()))
+            .finish()?;
+        Ok(df.column("country")?.clone())
+    };
+    let a = read(file_a)?;
+    let b = read(file_b)?;
+
+    // both files are mapped to the same global categories
+    let cache_id = |s: &Series| match &**s.categorical().unwrap().get_rev_map() {
+        RevMapping::Global(_, _, id) => Some(*id),
+        RevMapping::Local(_) => None,
+    };
+    assert!(cache_id(&a).is_some());
+    assert_eq!(cache_id(&a), cache_id(&b));
+    let codes = |s: &Series| Vec::from(s.categorical().unwrap().logical());
+    assert_eq!(codes(&a)[0], codes(&b)[1]);
+
+    let mut out = a.clone();
+    out.append(&b)?;
+    assert_eq!(
+        codes(&out),
+        codes(&a).into_iter().chain(codes(&b)).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        Vec::from(out.cast(&DataType::Utf8)?.utf8()?),
+        &[Some("NL"), Some("BE"), Some("NL"), Some("DE"), Some("NL")]
+    );
+    drop(string_cache);
+    Ok(())
+}
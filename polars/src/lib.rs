@@ -317,7 +317,7 @@ pub mod export;
 pub mod prelude;
 
 #[cfg(feature = "dtype-categorical")]
-pub use polars_core::toggle_string_cache;
+pub use polars_core::{toggle_string_cache, StringCacheHolder};
 pub use polars_core::{chunked_array, datatypes, doc, error, frame, functions, series, testing};
 #[cfg(feature = "temporal")]
 pub use polars_time as time;
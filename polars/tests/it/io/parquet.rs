@@ -17,3 +17,104 @@ fn test_vstack_empty_3220() -> Result<()> {
     assert!(stacked.frame_equal(&read_df));
     Ok(())
 }
+
+fn round_trip(df: &mut DataFrame, use_arrow_metadata: bool) -> Result<DataFrame> {
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(df)?;
+    buf.set_position(0);
+    ParquetReader::new(buf)
+        .use_arrow_metadata(use_arrow_metadata)
+        .finish()
+}
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_parquet_arrow_metadata_categorical() -> Result<()> {
+    let mut df = df! {
+        "cat" => ["a", "b", "a", "c"]
+    }?;
+    df.try_apply("cat", |s| s.cast(&DataType::Categorical(None)))?;
+
+    let read_df = round_trip(&mut df, true)?;
+    assert_eq!(read_df.column("cat")?.dtype(), &DataType::Categorical(None));
+    assert!(read_df
+        .column("cat")?
+        .cast(&DataType::Utf8)?
+        .series_equal(&df.column("cat")?.cast(&DataType::Utf8)?));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-duration")]
+fn test_parquet_arrow_metadata_duration() -> Result<()> {
+    let mut df = df! {
+        "dur" => [Some(1i64), None, Some(3)]
+    }?;
+    df.try_apply("dur", |s| {
+        s.cast(&DataType::Duration(TimeUnit::Milliseconds))
+    })?;
+
+    let read_df = round_trip(&mut df, true)?;
+    assert_eq!(
+        read_df.column("dur")?.dtype(),
+        &DataType::Duration(TimeUnit::Milliseconds)
+    );
+    assert!(df.frame_equal_missing(&read_df));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-datetime")]
+fn test_parquet_arrow_metadata_datetime_tz() -> Result<()> {
+    let dtype = DataType::Datetime(TimeUnit::Milliseconds, Some("Europe/Paris".into()));
+    let mut df = df! {
+        "dt" => [Some(1_000i64), None, Some(3_000)]
+    }?;
+    df.try_apply("dt", |s| s.cast(&dtype))?;
+
+    let read_df = round_trip(&mut df, true)?;
+    assert_eq!(read_df.column("dt")?.dtype(), &dtype);
+
+    // without the arrow metadata the timezone is lost
+    let read_df = round_trip(&mut df, false)?;
+    assert_ne!(read_df.column("dt")?.dtype(), &dtype);
+    Ok(())
+}
+
+#[test]
+fn test_parquet_nested_lists() -> Result<()> {
+    let ints = |v: &[i32]| Series::new("", v);
+    let list = |v: &[Option<Series>]| Series::new("", v);
+
+    // list<list<i32>> with empty inner lists and nulls at both levels
+    let mut two_levels = list(&[
+        Some(list(&[Some(ints(&[1, 2])), Some(ints(&[])), None])),
+        None,
+        Some(list(&[])),
+        Some(list(&[Some(ints(&[3]))])),
+    ]);
+    // list<list<list<i32>>>
+    let mut three_levels = list(&[
+        Some(list(&[
+            Some(list(&[Some(ints(&[1])), None])),
+            Some(list(&[])),
+        ])),
+        Some(list(&[None])),
+        None,
+        Some(list(&[Some(list(&[Some(ints(&[]))]))])),
+    ]);
+    two_levels.rename("two");
+    three_levels.rename("three");
+    let mut df = DataFrame::new(vec![two_levels, three_levels])?;
+
+    let read_df = round_trip(&mut df, true)?;
+    for name in ["two", "three"] {
+        assert_eq!(read_df.column(name)?.dtype(), df.column(name)?.dtype());
+    }
+    assert!(df.frame_equal_missing(&read_df));
+
+    // the nesting is reconstructed from the parquet schema as well
+    let read_df = round_trip(&mut df, false)?;
+    assert!(df.frame_equal_missing(&read_df));
+    Ok(())
+}
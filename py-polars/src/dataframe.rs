@@ -318,7 +318,8 @@ impl PyDataFrame {
         } else {
             // memmap the file first
             let mmap_bytes_r = get_mmap_bytes_reader(py_f)?;
-            let mmap_read: ReaderBytes = (&mmap_bytes_r).into();
+            let mmap_read = ReaderBytes::try_from(&mmap_bytes_r)
+                .map_err(|e| PyPolarsErr::Other(format!("{:?}", e)))?;
             let bytes = mmap_read.deref();
 
             // Happy path is our column oriented json as that is most performant
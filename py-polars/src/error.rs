@@ -1,6 +1,6 @@
 use polars::prelude::PolarsError;
 use polars_core::error::ArrowError;
-use pyo3::exceptions::{PyIOError, PyValueError};
+use pyo3::exceptions::{PyIOError, PyTimeoutError, PyValueError};
 use pyo3::{
     create_exception,
     exceptions::{PyException, PyRuntimeError},
@@ -35,6 +35,7 @@ impl std::convert::From<PyPolarsErr> for PyErr {
                 PolarsError::InvalidOperation(err) => PyValueError::new_err(err.to_string()),
                 PolarsError::ArrowError(err) => ArrowErrorException::new_err(format!("{:?}", err)),
                 PolarsError::Duplicate(err) => DuplicateError::new_err(err.to_string()),
+                PolarsError::Timeout(err) => PyTimeoutError::new_err(err.to_string()),
             },
             Arrow(err) => ArrowErrorException::new_err(format!("{:?}", err)),
             _ => default(),